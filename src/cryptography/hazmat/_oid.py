@@ -210,6 +210,7 @@ class AuthorityInformationAccessOID:
 
 class SubjectInformationAccessOID:
     CA_REPOSITORY = ObjectIdentifier("1.3.6.1.5.5.7.48.5")
+    TIME_STAMPING = ObjectIdentifier("1.3.6.1.5.5.7.48.3")
 
 
 class CertificatePoliciesOID:
@@ -339,6 +340,7 @@ _OID_NAMES = {
     AuthorityInformationAccessOID.OCSP: "OCSP",
     AuthorityInformationAccessOID.CA_ISSUERS: "caIssuers",
     SubjectInformationAccessOID.CA_REPOSITORY: "caRepository",
+    SubjectInformationAccessOID.TIME_STAMPING: "timeStamping",
     CertificatePoliciesOID.CPS_QUALIFIER: "id-qt-cps",
     CertificatePoliciesOID.CPS_USER_NOTICE: "id-qt-unotice",
     OCSPExtensionOID.NONCE: "OCSPNonce",
@@ -22,6 +22,7 @@ class OCSPRequest:
     def public_bytes(self, encoding: serialization.Encoding) -> bytes: ...
     @property
     def extensions(self) -> x509.Extensions: ...
+    def verify_signature(self) -> x509.Certificate | None: ...
 
 class OCSPResponse:
     @property
@@ -57,6 +58,8 @@ class OCSPResponse:
     @property
     def revocation_reason(self) -> x509.ReasonFlags | None: ...
     @property
+    def revoked_certificate(self) -> x509.RevokedCertificate | None: ...
+    @property
     def this_update(self) -> datetime.datetime: ...
     @property
     def this_update_utc(self) -> datetime.datetime: ...
@@ -77,6 +80,12 @@ class OCSPResponse:
     @property
     def single_extensions(self) -> x509.Extensions: ...
     def public_bytes(self, encoding: serialization.Encoding) -> bytes: ...
+    def verify_single_response(
+        self, certificate: x509.Certificate, issuer: x509.Certificate
+    ) -> OCSPSingleResponse: ...
+    def single_response_for_request(
+        self, request: ocsp.OCSPRequest, issuer: x509.Certificate
+    ) -> OCSPSingleResponse | None: ...
 
 class OCSPSingleResponse:
     @property
@@ -88,6 +97,8 @@ class OCSPSingleResponse:
     @property
     def revocation_reason(self) -> x509.ReasonFlags | None: ...
     @property
+    def revoked_certificate(self) -> x509.RevokedCertificate | None: ...
+    @property
     def this_update(self) -> datetime.datetime: ...
     @property
     def this_update_utc(self) -> datetime.datetime: ...
@@ -104,11 +115,19 @@ class OCSPSingleResponse:
     @property
     def serial_number(self) -> int: ...
 
+def issuer_hashes(
+    issuer: x509.Certificate, hash_algorithm: hashes.HashAlgorithm
+) -> tuple[bytes, bytes]: ...
 def load_der_ocsp_request(data: bytes) -> ocsp.OCSPRequest: ...
 def load_der_ocsp_response(data: bytes) -> ocsp.OCSPResponse: ...
 def create_ocsp_request(
     builder: ocsp.OCSPRequestBuilder,
 ) -> ocsp.OCSPRequest: ...
+def create_ocsp_cert_id(
+    cert: x509.Certificate,
+    issuer: x509.Certificate,
+    hash_algorithm: hashes.HashAlgorithm,
+) -> bytes: ...
 def create_ocsp_response(
     status: ocsp.OCSPResponseStatus,
     builder: ocsp.OCSPResponseBuilder | None,
@@ -4,6 +4,7 @@
 
 import typing
 
+from cryptography.hazmat.bindings._rust import ObjectIdentifier
 from cryptography.hazmat.bindings._rust.openssl import (
     aead,
     ciphers,
@@ -41,6 +42,8 @@ __all__ = [
     "poly1305",
     "raise_openssl_error",
     "rsa",
+    "supported_digest_oids",
+    "supported_signature_algorithms",
     "x448",
     "x25519",
 ]
@@ -63,6 +66,8 @@ def openssl_version_text() -> str: ...
 def raise_openssl_error() -> typing.NoReturn: ...
 def capture_error_stack() -> list[OpenSSLError]: ...
 def is_fips_enabled() -> bool: ...
+def supported_digest_oids() -> frozenset[ObjectIdentifier]: ...
+def supported_signature_algorithms() -> frozenset[str]: ...
 def enable_fips(providers: Providers) -> None: ...
 
 class OpenSSLError:
@@ -4,6 +4,7 @@
 
 import typing
 
+from cryptography.hazmat.primitives.asymmetric.ec import EllipticCurve
 from cryptography.hazmat.primitives.asymmetric.types import (
     PrivateKeyTypes,
     PublicKeyTypes,
@@ -32,3 +33,18 @@ def load_pem_public_key(
     data: bytes,
     backend: typing.Any = None,
 ) -> PublicKeyTypes: ...
+def load_der_public_key_info(data: Buffer) -> PublicKeyInfo: ...
+def load_pkcs8_private_key(
+    data: Buffer,
+    password: bytes | None,
+) -> PrivateKeyTypes: ...
+
+class PublicKeyInfo:
+    @property
+    def algorithm(self) -> str: ...
+    @property
+    def key_size(self) -> int | None: ...
+    @property
+    def curve(self) -> EllipticCurve | None: ...
+    @property
+    def fingerprint(self) -> bytes: ...
@@ -6,6 +6,7 @@ import typing
 from collections.abc import Iterable
 
 from cryptography import x509
+from cryptography.hazmat.primitives import hashes
 from cryptography.hazmat.primitives.asymmetric.types import PrivateKeyTypes
 from cryptography.hazmat.primitives.serialization import (
     KeySerializationEncryption,
@@ -39,6 +40,10 @@ def load_pkcs12(
     password: bytes | None,
     backend: typing.Any = None,
 ) -> PKCS12KeyAndCertificates: ...
+def load_mac_data(
+    data: Buffer,
+) -> tuple[hashes.HashAlgorithm, bytes, int] | None: ...
+def verify_mac(data: Buffer, password: Buffer | None) -> bool | None: ...
 def serialize_key_and_certificates(
     name: bytes | None,
     key: PKCS12PrivateKeyTypes | None,
@@ -42,6 +42,17 @@ def decrypt_smime(
     private_key: rsa.RSAPrivateKey,
     options: Iterable[pkcs7.PKCS7Options],
 ) -> bytes: ...
+def verify_signed_attributes_der(
+    data: bytes,
+    certificate: x509.Certificate,
+    content: bytes | None = None,
+) -> tuple[bool, bool, bool]: ...
+def smime_capabilities_der(
+    data: bytes,
+    certificate: x509.Certificate,
+) -> list[tuple[x509.ObjectIdentifier, bytes | None]] | None: ...
+def decompress_der(data: bytes, max_length: int = ...) -> bytes: ...
+def crls_der(data: bytes) -> list[x509.CertificateRevocationList]: ...
 def load_pem_pkcs7_certificates(
     data: bytes,
 ) -> list[x509.Certificate]: ...
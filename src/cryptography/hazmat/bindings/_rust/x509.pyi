@@ -3,6 +3,7 @@
 # for complete details.
 
 import datetime
+import os
 import typing
 from collections.abc import Iterator
 
@@ -15,7 +16,8 @@ from cryptography.hazmat.primitives.asymmetric.types import (
     CertificatePublicKeyTypes,
     PrivateKeyTypes,
 )
-from cryptography.x509 import certificate_transparency
+from cryptography.x509 import certificate_transparency, ocsp
+from cryptography.x509.name import _ASN1Type
 
 def load_pem_x509_certificate(
     data: bytes, backend: typing.Any = None
@@ -96,11 +98,34 @@ class Certificate:
     def not_valid_after(self) -> datetime.datetime: ...
     @property
     def not_valid_after_utc(self) -> datetime.datetime: ...
+    def time_until_expiry(
+        self, now: datetime.datetime
+    ) -> datetime.timedelta: ...
     @property
     def issuer(self) -> x509.Name: ...
     @property
     def subject(self) -> x509.Name: ...
     @property
+    def issuer_attributes(
+        self,
+    ) -> list[list[tuple[x509.ObjectIdentifier, str | bytes, _ASN1Type]]]: ...
+    @property
+    def subject_attributes(
+        self,
+    ) -> list[list[tuple[x509.ObjectIdentifier, str | bytes, _ASN1Type]]]: ...
+    @property
+    def smime_capabilities(
+        self,
+    ) -> list[tuple[x509.ObjectIdentifier, bytes | None]] | None: ...
+    @property
+    def issuer_bytes(self) -> bytes: ...
+    @property
+    def subject_bytes(self) -> bytes: ...
+    @property
+    def not_valid_before_bytes(self) -> bytes: ...
+    @property
+    def not_valid_after_bytes(self) -> bytes: ...
+    @property
     def signature_hash_algorithm(
         self,
     ) -> hashes.HashAlgorithm | None: ...
@@ -113,15 +138,59 @@ class Certificate:
     @property
     def extensions(self) -> x509.Extensions: ...
     @property
+    def extension_count(self) -> int: ...
+    def has_extension(self, oid: x509.ObjectIdentifier) -> bool: ...
+    @property
     def signature(self) -> bytes: ...
     @property
+    def ecdsa_signature_components(self) -> tuple[int, int] | None: ...
+    @property
     def tbs_certificate_bytes(self) -> bytes: ...
     @property
+    def der_field_spans(self) -> dict[str, tuple[int, int]]: ...
+    @property
     def tbs_precertificate_bytes(self) -> bytes: ...
+    @property
+    def ct_issuer_key_hash(self) -> bytes: ...
+    @property
+    def acme_identifier(self) -> bytes | None: ...
+    @property
+    def is_acme_challenge(self) -> bool: ...
+    @property
+    def tn_auth_list(
+        self,
+    ) -> list[tuple[str, str] | tuple[str, tuple[str, int]]] | None: ...
+    @property
+    def ocsp_responder_urls(self) -> list[str]: ...
+    @property
+    def timestamping_urls(self) -> list[str]: ...
+    @property
+    def general_names(
+        self,
+    ) -> list[tuple[x509.ObjectIdentifier, x509.GeneralName]]: ...
+    @property
+    def capabilities(self) -> frozenset[str]: ...
+    def extension_bytes(
+        self, oid: x509.ObjectIdentifier
+    ) -> tuple[bytes, bytes] | None: ...
+    def unrecognized_extension_oids(self) -> list[x509.ObjectIdentifier]: ...
+    @property
+    def dns_name_patterns(self) -> list[tuple[str, bool, str | None]]: ...
     def __eq__(self, other: object) -> bool: ...
     def __hash__(self) -> int: ...
     def public_bytes(self, encoding: serialization.Encoding) -> bytes: ...
+    def precertificate_signed_data(
+        self, issuer: Certificate, sct: Sct
+    ) -> bytes: ...
+    def signature_algorithm_matches_key(
+        self, public_key: CertificatePublicKeyTypes
+    ) -> bool: ...
+    def to_dict(self) -> dict[str, typing.Any]: ...
+    def issuer_matches(self, name: x509.Name | str) -> bool: ...
+    def is_self_issued(self) -> bool: ...
+    def is_self_signed(self) -> bool: ...
     def verify_directly_issued_by(self, issuer: Certificate) -> None: ...
+    def verify_signature_with_spki(self, spki: bytes) -> None: ...
 
 class RevokedCertificate: ...
 
@@ -131,6 +200,9 @@ class CertificateRevocationList:
     def get_revoked_certificate_by_serial_number(
         self, serial_number: int
     ) -> x509.RevokedCertificate | None: ...
+    def is_revoked(
+        self, serial_number: int | x509.Certificate
+    ) -> x509.RevokedCertificate | None: ...
     @property
     def signature_hash_algorithm(
         self,
@@ -154,6 +226,9 @@ class CertificateRevocationList:
     @property
     def extensions(self) -> x509.Extensions: ...
     @property
+    def extension_count(self) -> int: ...
+    def has_extension(self, oid: x509.ObjectIdentifier) -> bool: ...
+    @property
     def signature(self) -> bytes: ...
     @property
     def tbs_certlist_bytes(self) -> bytes: ...
@@ -167,6 +242,19 @@ class CertificateRevocationList:
     def is_signature_valid(
         self, public_key: CertificateIssuerPublicKeyTypes
     ) -> bool: ...
+    def applies_to(self, certificate: x509.Certificate) -> bool: ...
+    def covered_reasons(
+        self, certificate: x509.Certificate
+    ) -> frozenset[x509.ReasonFlags]: ...
+    def compare_crl_number(
+        self, other: x509.CertificateRevocationList
+    ) -> int | None: ...
+    def revocation_changes(
+        self,
+        newer: x509.CertificateRevocationList,
+        *,
+        require_newer_crl_number: bool = False,
+    ) -> tuple[set[int], set[int]]: ...
 
 class CertificateSigningRequest:
     def __eq__(self, other: object) -> bool: ...
@@ -187,6 +275,9 @@ class CertificateSigningRequest:
     @property
     def extensions(self) -> x509.Extensions: ...
     @property
+    def extension_count(self) -> int: ...
+    def has_extension(self, oid: x509.ObjectIdentifier) -> bool: ...
+    @property
     def attributes(self) -> x509.Attributes: ...
     def public_bytes(self, encoding: serialization.Encoding) -> bytes: ...
     @property
@@ -204,6 +295,43 @@ class PolicyBuilder:
     def extension_policies(
         self, *, ca_policy: ExtensionPolicy, ee_policy: ExtensionPolicy
     ) -> PolicyBuilder: ...
+    def expiry_warning_threshold(
+        self, threshold: datetime.timedelta
+    ) -> PolicyBuilder: ...
+    def warnings_as_errors(self) -> PolicyBuilder: ...
+    def allow_name_only_issuer_matching(self) -> PolicyBuilder: ...
+    def enforce_eku_chaining(self) -> PolicyBuilder: ...
+    def ignore_trust_anchor_validity(self) -> PolicyBuilder: ...
+    def allow_leaf_any_extended_key_usage(self) -> PolicyBuilder: ...
+    def allow_leaf_non_tls_key_usage(self) -> PolicyBuilder: ...
+    def require_nested_validity(self) -> PolicyBuilder: ...
+    def require_shaken_leaf(self) -> PolicyBuilder: ...
+    def revocation_checker(
+        self,
+        checker: typing.Callable[
+            [x509.Certificate, x509.Certificate], ocsp.OCSPCertStatus
+        ],
+        *,
+        fail_closed: bool = False,
+    ) -> PolicyBuilder: ...
+    def max_dns_name_labels(
+        self, max_labels: int, *, all_names: bool = False
+    ) -> PolicyBuilder: ...
+    def permit_signature_algorithms(
+        self, oids: list[x509.ObjectIdentifier]
+    ) -> PolicyBuilder: ...
+    def deny_signature_algorithms(
+        self, oids: list[x509.ObjectIdentifier]
+    ) -> PolicyBuilder: ...
+    def min_signature_strength(
+        self, algorithm: hashes.HashAlgorithm
+    ) -> PolicyBuilder: ...
+    def client_subject_types(
+        self, subject_types: list[type[x509.GeneralName]]
+    ) -> PolicyBuilder: ...
+    def require_subject_organization(
+        self, allowlist: list[str]
+    ) -> PolicyBuilder: ...
     def build_client_verifier(self) -> ClientVerifier: ...
     def build_server_verifier(
         self, subject: x509.verification.Subject
@@ -220,6 +348,14 @@ class Policy:
     def extended_key_usage(self) -> x509.ObjectIdentifier: ...
     @property
     def minimum_rsa_modulus(self) -> int: ...
+    def validity_status(
+        self, certificate: x509.Certificate
+    ) -> ValidityStatus: ...
+
+class ValidityStatus:
+    VALID: ValidityStatus
+    NOT_YET_VALID: ValidityStatus
+    EXPIRED: ValidityStatus
 
 class Criticality:
     CRITICAL: Criticality
@@ -270,6 +406,28 @@ class VerifiedClient:
     def subjects(self) -> list[x509.GeneralName] | None: ...
     @property
     def chain(self) -> list[x509.Certificate]: ...
+    @property
+    def trust_anchor(self) -> x509.Certificate: ...
+    @property
+    def validity_statuses(self) -> list[ValidityStatus]: ...
+
+class VerificationWarning:
+    @property
+    def certificate(self) -> x509.Certificate: ...
+    @property
+    def message(self) -> str: ...
+
+class VerificationStep:
+    @property
+    def kind(self) -> str: ...
+    @property
+    def certificate(self) -> x509.Certificate: ...
+    @property
+    def issuer(self) -> x509.Certificate | None: ...
+    @property
+    def error(self) -> str | None: ...
+    @property
+    def validity_status(self) -> ValidityStatus: ...
 
 class ClientVerifier:
     @property
@@ -283,8 +441,22 @@ class ClientVerifier:
     def verify(
         self,
         leaf: x509.Certificate,
-        intermediates: list[x509.Certificate],
+        intermediates: list[x509.Certificate] | bytes,
+        *,
+        additional_anchors: list[x509.Certificate] | None = None,
     ) -> VerifiedClient: ...
+    def verify_with_warnings(
+        self,
+        leaf: x509.Certificate,
+        intermediates: list[x509.Certificate] | bytes,
+        *,
+        additional_anchors: list[x509.Certificate] | None = None,
+    ) -> tuple[VerifiedClient, list[VerificationWarning]]: ...
+    def trace(
+        self,
+        leaf: x509.Certificate,
+        intermediates: list[x509.Certificate] | bytes,
+    ) -> Iterator[VerificationStep]: ...
 
 class ServerVerifier:
     @property
@@ -300,11 +472,76 @@ class ServerVerifier:
     def verify(
         self,
         leaf: x509.Certificate,
-        intermediates: list[x509.Certificate],
+        intermediates: list[x509.Certificate] | bytes,
+        *,
+        additional_anchors: list[x509.Certificate] | None = None,
     ) -> list[x509.Certificate]: ...
+    def verify_with_warnings(
+        self,
+        leaf: x509.Certificate,
+        intermediates: list[x509.Certificate] | bytes,
+        *,
+        additional_anchors: list[x509.Certificate] | None = None,
+    ) -> tuple[list[x509.Certificate], list[VerificationWarning]]: ...
+    def trace(
+        self,
+        leaf: x509.Certificate,
+        intermediates: list[x509.Certificate] | bytes,
+    ) -> Iterator[VerificationStep]: ...
+    def verify_with_matched_name(
+        self,
+        leaf: x509.Certificate,
+        intermediates: list[x509.Certificate] | bytes,
+        *,
+        additional_anchors: list[x509.Certificate] | None = None,
+    ) -> tuple[list[x509.Certificate], x509.verification.Subject]: ...
 
 class Store:
     def __init__(self, certs: list[x509.Certificate]) -> None: ...
+    @staticmethod
+    def from_directory(path: str | os.PathLike[str]) -> Store: ...
+
+def verify_timestamp_token(
+    token: bytes, data: bytes, store: Store
+) -> datetime.datetime: ...
+def verify_timestamp_token_within_validity(
+    token: bytes, data: bytes, store: Store, certificate: x509.Certificate
+) -> datetime.datetime: ...
+def matching_subjects(
+    leaf: x509.Certificate, subjects: list[x509.verification.Subject]
+) -> list[x509.verification.Subject]: ...
+def verify_tls_server(
+    verifier: ServerVerifier,
+    leaf: x509.Certificate,
+    intermediates: list[x509.Certificate] | bytes,
+    *,
+    ocsp_response: ocsp.OCSPResponse | None = None,
+    ct_log_keys: dict[bytes, CertificateIssuerPublicKeyTypes] | None = None,
+    minimum_scts: int = 0,
+) -> list[x509.Certificate]: ...
+def verify_ocsp_response(
+    response: ocsp.OCSPResponse,
+    certificate: x509.Certificate,
+    issuer: x509.Certificate,
+    store: Store,
+) -> ocsp.OCSPSingleResponse: ...
 
 class VerificationError(Exception):
     pass
+
+class TLSVerificationStage:
+    CHAIN: TLSVerificationStage
+    MUST_STAPLE: TLSVerificationStage
+    OCSP: TLSVerificationStage
+    SCT: TLSVerificationStage
+
+class TLSVerificationError(VerificationError):
+    stage: TLSVerificationStage
+
+class OCSPVerificationStage:
+    SIGNATURE: OCSPVerificationStage
+    EKU: OCSPVerificationStage
+    CHAIN: OCSPVerificationStage
+
+class OCSPVerificationError(VerificationError):
+    stage: OCSPVerificationStage
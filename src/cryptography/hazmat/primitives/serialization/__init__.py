@@ -15,9 +15,11 @@ from cryptography.hazmat.primitives._serialization import (
     _KeySerializationEncryption,
 )
 from cryptography.hazmat.primitives.serialization.base import (
+    PublicKeyInfo,
     load_der_parameters,
     load_der_private_key,
     load_der_public_key,
+    load_der_public_key_info,
     load_pem_parameters,
     load_pem_private_key,
     load_pem_public_key,
@@ -43,6 +45,7 @@ __all__ = [
     "ParameterFormat",
     "PrivateFormat",
     "PublicFormat",
+    "PublicKeyInfo",
     "SSHCertPrivateKeyTypes",
     "SSHCertPublicKeyTypes",
     "SSHCertificate",
@@ -54,6 +57,7 @@ __all__ = [
     "load_der_parameters",
     "load_der_private_key",
     "load_der_public_key",
+    "load_der_public_key_info",
     "load_pem_parameters",
     "load_pem_private_key",
     "load_pem_public_key",
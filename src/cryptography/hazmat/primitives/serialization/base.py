@@ -10,5 +10,8 @@ load_der_private_key = rust_openssl.keys.load_der_private_key
 load_pem_public_key = rust_openssl.keys.load_pem_public_key
 load_der_public_key = rust_openssl.keys.load_der_public_key
 
+load_der_public_key_info = rust_openssl.keys.load_der_public_key_info
+PublicKeyInfo = rust_openssl.keys.PublicKeyInfo
+
 load_pem_parameters = rust_openssl.dh.from_pem_parameters
 load_der_parameters = rust_openssl.dh.from_der_parameters
@@ -7,7 +7,8 @@ from __future__ import annotations
 import typing
 from collections.abc import Iterable
 
-from cryptography import x509
+from cryptography import utils, x509
+from cryptography.hazmat.bindings._rust import openssl as rust_openssl
 from cryptography.hazmat.bindings._rust import pkcs12 as rust_pkcs12
 from cryptography.hazmat.primitives import serialization
 from cryptography.hazmat.primitives._serialization import PBES as PBES
@@ -26,8 +27,10 @@ __all__ = [
     "PKCS12KeyAndCertificates",
     "PKCS12PrivateKeyTypes",
     "load_key_and_certificates",
+    "load_mac_data",
     "load_pkcs12",
     "serialize_key_and_certificates",
+    "verify_mac",
 ]
 
 PKCS12PrivateKeyTypes = typing.Union[
@@ -111,6 +114,8 @@ class PKCS12KeyAndCertificates:
 
 load_key_and_certificates = rust_pkcs12.load_key_and_certificates
 load_pkcs12 = rust_pkcs12.load_pkcs12
+load_mac_data = rust_pkcs12.load_mac_data
+verify_mac = rust_pkcs12.verify_mac
 
 
 _PKCS12CATypes = typing.Union[
@@ -121,11 +126,21 @@ _PKCS12CATypes = typing.Union[
 
 def serialize_key_and_certificates(
     name: bytes | None,
-    key: PKCS12PrivateKeyTypes | None,
+    key: PKCS12PrivateKeyTypes | utils.Buffer | None,
     cert: x509.Certificate | None,
     cas: Iterable[_PKCS12CATypes] | None,
     encryption_algorithm: serialization.KeySerializationEncryption,
+    *,
+    key_password: bytes | None = None,
 ) -> bytes:
+    if isinstance(key, (bytes, bytearray, memoryview)):
+        key = rust_openssl.keys.load_pkcs8_private_key(key, key_password)
+    elif key_password is not None:
+        raise TypeError(
+            "key_password may only be supplied when key is a serialized "
+            "PKCS8 private key."
+        )
+
     if key is not None and not isinstance(
         key,
         (
@@ -308,6 +308,10 @@ class PKCS7EnvelopeBuilder:
 pkcs7_decrypt_der = rust_pkcs7.decrypt_der
 pkcs7_decrypt_pem = rust_pkcs7.decrypt_pem
 pkcs7_decrypt_smime = rust_pkcs7.decrypt_smime
+pkcs7_verify_signed_attributes_der = rust_pkcs7.verify_signed_attributes_der
+pkcs7_smime_capabilities_der = rust_pkcs7.smime_capabilities_der
+pkcs7_decompress_der = rust_pkcs7.decompress_der
+pkcs7_crls_der = rust_pkcs7.crls_der
 
 
 def _smime_signed_encode(
@@ -24,6 +24,25 @@ class UnsupportedGeneralNameType(Exception):
     pass
 
 
+def _decode_a_label(encoded: str) -> str | None:
+    # A-labels are case-insensitive, but punycode preserves the case of the
+    # ASCII characters it carries, so only the lowercase form round-trips.
+    encoded = encoded.lower()
+    try:
+        decoded = encoded.encode("ascii").decode("punycode")
+    except UnicodeError:
+        return None
+    # An A-label must decode to something that needed encoding, and must be
+    # the canonical encoding of what it decodes to.
+    if (
+        decoded.isascii()
+        or not decoded.isprintable()
+        or decoded.encode("punycode").decode("ascii") != encoded
+    ):
+        return None
+    return decoded
+
+
 class GeneralName(metaclass=abc.ABCMeta):
     @property
     @abc.abstractmethod
@@ -104,6 +123,22 @@ class DNSName(GeneralName):
         instance._value = value
         return instance
 
+    def to_unicode(self) -> tuple[str, bool]:
+        """
+        Return the name with any A-labels decoded to U-labels, and whether
+        the decoding succeeded. If any label isn't valid punycode the raw
+        value is returned unchanged, along with ``False``.
+        """
+        labels = []
+        for label in self.value.split("."):
+            if label[:4].lower() == "xn--":
+                decoded = _decode_a_label(label[4:])
+                if decoded is None:
+                    return self.value, False
+                label = decoded
+            labels.append(label)
+        return ".".join(labels), True
+
     def __repr__(self) -> str:
         return f"<DNSName(value={self.value!r})>"
 
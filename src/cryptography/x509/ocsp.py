@@ -375,5 +375,20 @@ class OCSPResponseBuilder:
         return ocsp.create_ocsp_response(response_status, None, None, None)
 
 
+def create_cert_id(
+    cert: x509.Certificate,
+    issuer: x509.Certificate,
+    algorithm: hashes.HashAlgorithm,
+) -> bytes:
+    _verify_algorithm(algorithm)
+    if not isinstance(cert, x509.Certificate) or not isinstance(
+        issuer, x509.Certificate
+    ):
+        raise TypeError("cert and issuer must be a Certificate")
+
+    return ocsp.create_ocsp_cert_id(cert, issuer, algorithm)
+
+
 load_der_ocsp_request = ocsp.load_der_ocsp_request
 load_der_ocsp_response = ocsp.load_der_ocsp_response
+issuer_hashes = ocsp.issuer_hashes
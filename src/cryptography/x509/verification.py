@@ -13,13 +13,25 @@ __all__ = [
     "ClientVerifier",
     "Criticality",
     "ExtensionPolicy",
+    "OCSPVerificationError",
+    "OCSPVerificationStage",
     "Policy",
     "PolicyBuilder",
     "ServerVerifier",
     "Store",
     "Subject",
+    "TLSVerificationError",
+    "TLSVerificationStage",
+    "ValidityStatus",
     "VerificationError",
+    "VerificationStep",
+    "VerificationWarning",
     "VerifiedClient",
+    "matching_subjects",
+    "verify_ocsp_response",
+    "verify_timestamp_token",
+    "verify_timestamp_token_within_validity",
+    "verify_tls_server",
 ]
 
 Store = rust_x509.Store
@@ -31,4 +43,18 @@ PolicyBuilder = rust_x509.PolicyBuilder
 Policy = rust_x509.Policy
 ExtensionPolicy = rust_x509.ExtensionPolicy
 Criticality = rust_x509.Criticality
+ValidityStatus = rust_x509.ValidityStatus
 VerificationError = rust_x509.VerificationError
+VerificationWarning = rust_x509.VerificationWarning
+VerificationStep = rust_x509.VerificationStep
+TLSVerificationError = rust_x509.TLSVerificationError
+TLSVerificationStage = rust_x509.TLSVerificationStage
+OCSPVerificationError = rust_x509.OCSPVerificationError
+OCSPVerificationStage = rust_x509.OCSPVerificationStage
+verify_timestamp_token = rust_x509.verify_timestamp_token
+verify_timestamp_token_within_validity = (
+    rust_x509.verify_timestamp_token_within_validity
+)
+matching_subjects = rust_x509.matching_subjects
+verify_tls_server = rust_x509.verify_tls_server
+verify_ocsp_response = rust_x509.verify_ocsp_response
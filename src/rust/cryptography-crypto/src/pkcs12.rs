@@ -21,6 +21,20 @@ pub fn kdf(
         .flat_map(|v| v.to_be_bytes())
         .collect::<Vec<u8>>();
 
+    kdf_encoded(&pass, salt, id, rounds, key_len, hash_alg)
+}
+
+/// Like `kdf`, but with the password already encoded. This allows the
+/// absent password, an empty string with no NUL trailer, which OpenSSL
+/// uses when a PKCS#12 file is created without one.
+pub fn kdf_encoded(
+    pass: &[u8],
+    salt: &[u8],
+    id: u8,
+    rounds: u64,
+    key_len: usize,
+    hash_alg: openssl::hash::MessageDigest,
+) -> Result<Vec<u8>, openssl::error::ErrorStack> {
     // Comments are borrowed from BoringSSL.
     // In the spec, |block_size| is called "v", but measured in bits.
     let block_size = hash_alg.block_size();
@@ -6,7 +6,7 @@
 
 use cryptography_x509::certificate::Certificate;
 
-pub(crate) fn cert_is_self_issued(cert: &Certificate<'_>) -> bool {
+pub fn cert_is_self_issued(cert: &Certificate<'_>) -> bool {
     cert.issuer() == cert.subject()
 }
 
@@ -40,6 +40,9 @@ pub enum ValidationErrorKind<'chain, B: CryptoOps> {
         reason: &'static str,
     },
     FatalError(&'static str),
+    /// An error raised by a caller-provided check, such as
+    /// `PolicyDefinition::ee_check`. It ends path building immediately.
+    CallbackError(B::Err),
     Other(String),
 }
 
@@ -53,7 +56,7 @@ impl<'chain, B: CryptoOps> ValidationError<'chain, B> {
         ValidationError { kind, cert: None }
     }
 
-    pub(crate) fn set_cert(mut self, cert: VerificationCertificate<'chain, B>) -> Self {
+    pub fn set_cert(mut self, cert: VerificationCertificate<'chain, B>) -> Self {
         self.cert = Some(cert);
         self
     }
@@ -61,6 +64,25 @@ impl<'chain, B: CryptoOps> ValidationError<'chain, B> {
     pub fn certificate(&self) -> Option<&VerificationCertificate<'chain, B>> {
         self.cert.as_ref()
     }
+
+    /// Returns the error raised by a caller-provided check, if that's what
+    /// this is, and otherwise returns this error unchanged.
+    pub fn into_callback_error(self) -> Result<B::Err, Self> {
+        match self.kind {
+            ValidationErrorKind::CallbackError(err) => Ok(err),
+            kind => Err(ValidationError {
+                kind,
+                cert: self.cert,
+            }),
+        }
+    }
+
+    fn is_fatal(&self) -> bool {
+        matches!(
+            self.kind,
+            ValidationErrorKind::FatalError(..) | ValidationErrorKind::CallbackError(..)
+        )
+    }
 }
 
 pub type ValidationResult<'chain, T, B> = Result<T, ValidationError<'chain, B>>;
@@ -91,6 +113,7 @@ impl<B: CryptoOps> Display for ValidationError<'_, B> {
                 write!(f, "invalid extension: {oid}: {reason}")
             }
             ValidationErrorKind::FatalError(err) => write!(f, "fatal error: {err}"),
+            ValidationErrorKind::CallbackError(_) => write!(f, "caller-provided check failed"),
             ValidationErrorKind::Other(err) => write!(f, "{err}"),
         }
     }
@@ -121,14 +144,14 @@ impl Budget {
     }
 }
 
-struct NameChain<'a, 'chain> {
-    child: Option<&'a NameChain<'a, 'chain>>,
+/// The SANs a certificate in the chain contributes to the names that name
+/// constraints are applied to.
+struct NameChain<'chain> {
     sans: SubjectAlternativeName<'chain>,
 }
 
-impl<'a, 'chain> NameChain<'a, 'chain> {
+impl<'chain> NameChain<'chain> {
     fn new<B: CryptoOps>(
-        child: Option<&'a NameChain<'a, 'chain>>,
         extensions: &Extensions<'chain>,
         self_issued_intermediate: bool,
     ) -> ValidationResult<'chain, Self, B> {
@@ -142,7 +165,7 @@ impl<'a, 'chain> NameChain<'a, 'chain> {
             _ => asn1::parse_single(b"\x30\x00")?,
         };
 
-        Ok(Self { child, sans })
+        Ok(Self { sans })
     }
 
     fn evaluate_single_constraint<B: CryptoOps>(
@@ -227,10 +250,6 @@ impl<'a, 'chain> NameChain<'a, 'chain> {
         constraints: &NameConstraints<'chain, Asn1Read>,
         budget: &mut Budget,
     ) -> ValidationResult<'chain, (), B> {
-        if let Some(child) = self.child {
-            child.evaluate_constraints(constraints, budget)?;
-        }
-
         for san in self.sans.clone() {
             // If there are no applicable constraints, the SAN is considered valid so the default is true.
             let mut permit = true;
@@ -270,22 +289,155 @@ impl<'a, 'chain> NameChain<'a, 'chain> {
 
 pub type Chain<'c, B> = Vec<VerificationCertificate<'c, B>>;
 
+/// The kinds of decision recorded by `verify_traced`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StepKind {
+    /// The leaf was checked against the EE policy, including its validity
+    /// period.
+    EePolicy,
+    /// `issuer` was chosen as a candidate issuer of `cert`.
+    Candidate,
+    /// The candidate issuer `cert` was checked against the CA policy,
+    /// including its validity period and basicConstraints.
+    CaPolicy,
+    /// `cert`'s signature, and the key and algorithm used to make it, were
+    /// checked against `issuer`.
+    Signature,
+    /// The name constraints in `cert` were applied to the chain below it.
+    NameConstraints,
+    /// The chain below `cert` exceeded the maximum depth.
+    ChainDepth,
+    /// No candidate issuer of `cert` led to a trust anchor.
+    CandidatesExhausted,
+    /// `cert` is a trust anchor, completing the chain.
+    TrustAnchor,
+}
+
+/// A single path validation decision, as recorded by `verify_traced`.
+pub struct Step<'chain, B: CryptoOps> {
+    pub kind: StepKind,
+    pub cert: VerificationCertificate<'chain, B>,
+    pub issuer: Option<VerificationCertificate<'chain, B>>,
+    /// Why the step failed, or `None` if it succeeded.
+    pub error: Option<String>,
+}
+
 pub fn verify<'chain, B: CryptoOps>(
     leaf: &VerificationCertificate<'chain, B>,
     intermediates: &[VerificationCertificate<'chain, B>],
     policy: &Policy<'_, B>,
     store: &Store<'chain, B>,
 ) -> ValidationResult<'chain, Chain<'chain, B>, B> {
-    let builder = ChainBuilder::new(intermediates, policy, store);
+    let mut builder = ChainBuilder::new(leaf, intermediates, policy, store, false);
+    loop {
+        builder.advance();
+        if let Some(result) = builder.take_result() {
+            return result;
+        }
+    }
+}
+
+/// Like `verify`, but builds the chain one decision at a time, as the
+/// returned `TracedVerification` is iterated.
+pub fn verify_traced<'a, 'chain, B: CryptoOps>(
+    leaf: &'a VerificationCertificate<'chain, B>,
+    intermediates: &'a [VerificationCertificate<'chain, B>],
+    policy: &'a Policy<'a, B>,
+    store: &'a Store<'chain, B>,
+) -> TracedVerification<'a, 'chain, B> {
+    TracedVerification {
+        builder: ChainBuilder::new(leaf, intermediates, policy, store, true),
+    }
+}
+
+/// Path validation in progress, as returned by `verify_traced`. Each call
+/// to `next` makes decisions until one is recorded, and returns it. Once
+/// this is exhausted, `take_result` returns the outcome.
+pub struct TracedVerification<'a, 'chain, B: CryptoOps> {
+    builder: ChainBuilder<'a, 'chain, B>,
+}
 
-    let mut budget = Budget::new();
-    builder.build_chain(leaf, &mut budget)
+impl<'chain, B: CryptoOps> TracedVerification<'_, 'chain, B> {
+    /// Returns the outcome of path validation, if it has finished and the
+    /// outcome wasn't already taken.
+    pub fn take_result(&mut self) -> Option<ValidationResult<'chain, Chain<'chain, B>, B>> {
+        self.builder.take_result()
+    }
 }
 
+impl<'chain, B: CryptoOps> Iterator for TracedVerification<'_, 'chain, B> {
+    type Item = Step<'chain, B>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.builder.is_finished() {
+            self.builder.advance();
+            if let Some(step) = self.builder.step.take() {
+                return Some(step);
+            }
+        }
+        None
+    }
+}
+
+/// A certificate in the chain being built, with the state of the search
+/// for its issuer.
+struct Frame<'a, 'chain, B: CryptoOps> {
+    cert: &'a VerificationCertificate<'chain, B>,
+    depth: u8,
+    extensions: Extensions<'chain>,
+    name_chain: NameChain<'chain>,
+    candidates: vec::IntoIter<&'a VerificationCertificate<'chain, B>>,
+    last_err: Option<ValidationError<'chain, B>>,
+}
+
+/// What `ChainBuilder` will do next. The "working" certificate is the
+/// topmost one in `ChainBuilder::frames`.
+enum State<'a, 'chain, B: CryptoOps> {
+    /// Check the leaf against the EE policy.
+    Start,
+    /// Apply the working certificate's name constraints, if it has any.
+    Reached,
+    /// Check whether the working certificate is a trust anchor.
+    ConstraintsApplied,
+    /// Choose the next candidate issuer of the working certificate.
+    NextCandidate,
+    /// Check the chosen candidate issuer against the CA policy.
+    CandidateChosen {
+        issuer: &'a VerificationCertificate<'chain, B>,
+        extensions: Extensions<'chain>,
+    },
+    /// Check the working certificate's signature against the candidate.
+    IssuerPermitted {
+        issuer: &'a VerificationCertificate<'chain, B>,
+        extensions: Extensions<'chain>,
+    },
+    /// Continue with the candidate as the working certificate.
+    IssuerVerified {
+        issuer: &'a VerificationCertificate<'chain, B>,
+        extensions: Extensions<'chain>,
+    },
+    /// Nothing: path building has finished, with this outcome unless it has
+    /// been taken.
+    Finished(Option<ValidationResult<'chain, Chain<'chain, B>, B>>),
+}
+
+/// Builds a chain by a depth-first search over candidate issuers, one
+/// decision per call to `advance`.
+///
+/// `frames` holds the chain as built so far, from the leaf up. It is only
+/// empty in the `Start` and `Finished` states, which the `unwrap()`s of its
+/// last element below rely on.
 struct ChainBuilder<'a, 'chain, B: CryptoOps> {
+    leaf: &'a VerificationCertificate<'chain, B>,
     intermediates: &'a [VerificationCertificate<'chain, B>],
     policy: &'a Policy<'a, B>,
     store: &'a Store<'chain, B>,
+    budget: Budget,
+    frames: Vec<Frame<'a, 'chain, B>>,
+    state: State<'a, 'chain, B>,
+    tracing: bool,
+    /// The step most recently recorded, if tracing.
+    step: Option<Step<'chain, B>>,
 }
 
 // When applying a name constraint, we need to distinguish between a few different scenarios:
@@ -309,173 +461,318 @@ impl ApplyNameConstraintStatus {
 
 impl<'a, 'chain, B: CryptoOps> ChainBuilder<'a, 'chain, B> {
     fn new(
+        leaf: &'a VerificationCertificate<'chain, B>,
         intermediates: &'a [VerificationCertificate<'chain, B>],
         policy: &'a Policy<'a, B>,
         store: &'a Store<'chain, B>,
+        tracing: bool,
     ) -> Self {
         Self {
+            leaf,
             intermediates,
             policy,
             store,
+            budget: Budget::new(),
+            frames: vec![],
+            state: State::Start,
+            tracing,
+            step: None,
         }
     }
 
+    fn is_finished(&self) -> bool {
+        matches!(self.state, State::Finished(_))
+    }
+
+    fn take_result(&mut self) -> Option<ValidationResult<'chain, Chain<'chain, B>, B>> {
+        match &mut self.state {
+            State::Finished(result) => result.take(),
+            _ => None,
+        }
+    }
+
+    /// Records a step, if tracing, and passes `result` through.
+    fn record<T>(
+        &mut self,
+        kind: StepKind,
+        cert: &VerificationCertificate<'chain, B>,
+        issuer: Option<&VerificationCertificate<'chain, B>>,
+        result: ValidationResult<'chain, T, B>,
+    ) -> ValidationResult<'chain, T, B> {
+        // An exception from a caller-provided check isn't a decision; it
+        // ends path building without one.
+        let is_callback_error = matches!(
+            &result,
+            Err(ValidationError {
+                kind: ValidationErrorKind::CallbackError(_),
+                ..
+            })
+        );
+        if self.tracing && !is_callback_error {
+            self.step = Some(Step {
+                kind,
+                cert: cert.clone(),
+                issuer: issuer.cloned(),
+                error: result.as_ref().err().map(|e| e.to_string()),
+            });
+        }
+        result
+    }
+
+    /// Moves path building on by one state.
+    fn advance(&mut self) {
+        let state = std::mem::replace(&mut self.state, State::Finished(None));
+        self.state = match state {
+            State::Start => self
+                .check_leaf()
+                .unwrap_or_else(|e| State::Finished(Some(Err(e)))),
+            State::Reached => self
+                .apply_name_constraints()
+                .unwrap_or_else(|e| self.working_cert_failed(e)),
+            State::ConstraintsApplied => self
+                .check_trust_anchor()
+                .unwrap_or_else(|e| self.working_cert_failed(e)),
+            State::NextCandidate => self
+                .choose_candidate()
+                .unwrap_or_else(|e| self.working_cert_failed(e)),
+            State::CandidateChosen { issuer, extensions } => self
+                .check_candidate_policy(issuer, extensions)
+                .unwrap_or_else(|e| self.candidate_failed(e)),
+            State::IssuerPermitted { issuer, extensions } => self
+                .check_candidate_signature(issuer, extensions)
+                .unwrap_or_else(|e| self.candidate_failed(e)),
+            State::IssuerVerified { issuer, extensions } => self
+                .push(issuer, extensions)
+                .unwrap_or_else(|e| self.working_cert_failed(e)),
+            finished @ State::Finished(_) => finished,
+        };
+    }
+
+    /// Backtracks after the working certificate failed with `err`, treating
+    /// it as a failed candidate issuer of the certificate below it.
+    fn working_cert_failed(&mut self, err: ValidationError<'chain, B>) -> State<'a, 'chain, B> {
+        self.frames.pop();
+        if self.frames.is_empty() {
+            return State::Finished(Some(Err(err)));
+        }
+        self.candidate_failed(err)
+    }
+
+    /// Moves on to the working certificate's next candidate issuer, after
+    /// the current one failed with `err`.
+    fn candidate_failed(&mut self, err: ValidationError<'chain, B>) -> State<'a, 'chain, B> {
+        // Immediately return on fatal error.
+        if err.is_fatal() {
+            return State::Finished(Some(Err(err)));
+        }
+        self.frames.last_mut().unwrap().last_err = Some(err);
+        State::NextCandidate
+    }
+
     fn potential_issuers(
         &self,
         cert: &'a VerificationCertificate<'chain, B>,
-    ) -> impl Iterator<Item = &'a VerificationCertificate<'chain, B>> + '_ {
+    ) -> vec::IntoIter<&'a VerificationCertificate<'chain, B>> {
+        let store = self.store;
+        let intermediates = self.intermediates;
         // TODO: Optimizations:
         // * Search by AKI and other identifiers?
-        self.store
+        store
             .get_by_subject(&cert.certificate().tbs_cert.issuer)
             .iter()
-            .chain(self.intermediates.iter().filter(|&candidate| {
+            .chain(intermediates.iter().filter(|&candidate| {
                 candidate.certificate().subject() == cert.certificate().issuer()
             }))
+            .collect::<Vec<_>>()
+            .into_iter()
     }
 
-    fn build_chain_inner(
-        &self,
-        working_cert: &VerificationCertificate<'chain, B>,
-        current_depth: u8,
-        working_cert_extensions: &Extensions<'chain>,
-        name_chain: NameChain<'_, 'chain>,
-        budget: &mut Budget,
-    ) -> ValidationResult<'chain, Chain<'chain, B>, B> {
-        if let Some(nc) = working_cert_extensions.get_extension(&NAME_CONSTRAINTS_OID) {
-            name_chain.evaluate_constraints(&nc.value()?, budget)?;
+    /// Makes `cert` the working certificate.
+    fn push(
+        &mut self,
+        cert: &'a VerificationCertificate<'chain, B>,
+        extensions: Extensions<'chain>,
+    ) -> ValidationResult<'chain, State<'a, 'chain, B>, B> {
+        let frame = match self.frames.last() {
+            Some(child) => Frame {
+                cert,
+                // NOTE(ww): According to RFC 5280, we should only
+                // increase the chain depth when the certificate is **not**
+                // self-issued. In practice however, implementations widely
+                // ignore this requirement, and unconditionally increment
+                // the depth with every chain member. We choose to do the same;
+                // see `pathlen::self-issued-certs-pathlen` from x509-limbo
+                // for the testcase we intentionally fail.
+                //
+                // Implementation note for someone looking to change this in the future:
+                // care should be taken to avoid infinite recursion with self-signed
+                // certificates in the intermediate set; changing this behavior will
+                // also require a "is not self-signed" check on intermediate candidates.
+                //
+                // See https://gist.github.com/woodruffw/776153088e0df3fc2f0675c5e835f7b8
+                // for an example of this change.
+                depth: child.depth.checked_add(1).ok_or_else(|| {
+                    ValidationError::new(ValidationErrorKind::Other(
+                        "current depth calculation overflowed".to_string(),
+                    ))
+                })?,
+                // Per RFC 5280 4.2.1.10: Name constraints are not applied
+                // to subjects in self-issued certificates, *unless* the
+                // certificate is the "final" (i.e., leaf) certificate in the path.
+                // We accomplish this by only collecting the SANs when the issuing
+                // candidate (which is a non-leaf by definition) isn't self-issued.
+                name_chain: NameChain::new(&extensions, cert_is_self_issued(cert.certificate()))?,
+                extensions,
+                candidates: self.potential_issuers(cert),
+                last_err: None,
+            },
+            None => Frame {
+                cert,
+                depth: 0,
+                name_chain: NameChain::new(&extensions, false)?,
+                extensions,
+                candidates: self.potential_issuers(cert),
+                last_err: None,
+            },
+        };
+        self.frames.push(frame);
+        Ok(State::Reached)
+    }
+
+    fn check_leaf(&mut self) -> ValidationResult<'chain, State<'a, 'chain, B>, B> {
+        // Before anything else, check whether the given leaf cert
+        // is well-formed according to our policy (and its underlying
+        // certificate profile).
+        //
+        // The leaf must be an EE; a CA cert in the leaf position will be rejected.
+        let leaf = self.leaf;
+        let leaf_extensions = leaf.certificate().extensions()?;
+
+        let result = self
+            .policy
+            .permits_ee(leaf, &leaf_extensions)
+            .map_err(|e| e.set_cert(leaf.clone()));
+        self.record(StepKind::EePolicy, leaf, None, result)?;
+
+        self.push(leaf, leaf_extensions)
+    }
+
+    fn apply_name_constraints(&mut self) -> ValidationResult<'chain, State<'a, 'chain, B>, B> {
+        let working = self.frames.last().unwrap();
+        let working_cert = working.cert;
+        if let Some(nc) = working.extensions.get_extension(&NAME_CONSTRAINTS_OID) {
+            // The constraints apply to the names of each certificate below
+            // the working one, as well as its own.
+            let frames = &self.frames;
+            let budget = &mut self.budget;
+            let result = nc.value().map_err(ValidationError::from).and_then(|nc| {
+                frames
+                    .iter()
+                    .try_for_each(|frame| frame.name_chain.evaluate_constraints(&nc, budget))
+            });
+            self.record(StepKind::NameConstraints, working_cert, None, result)?;
         }
+        Ok(State::ConstraintsApplied)
+    }
+
+    fn check_trust_anchor(&mut self) -> ValidationResult<'chain, State<'a, 'chain, B>, B> {
+        let working = self.frames.last().unwrap();
+        let (working_cert, current_depth) = (working.cert, working.depth);
 
         // Look in the store's root set to see if the working cert is listed.
         // If it is, we've reached the end.
         if self.store.contains(working_cert) {
-            return Ok(vec![working_cert.clone()]);
+            self.record(StepKind::TrustAnchor, working_cert, None, Ok(()))?;
+            let chain = self.frames.iter().map(|frame| frame.cert.clone()).collect();
+            return Ok(State::Finished(Some(Ok(chain))));
         }
 
         // Check that our current depth does not exceed our policy-configured
         // max depth. We do this after the root set check, since the depth
         // only measures the intermediate chain's length, not the root or leaf.
         if current_depth > self.policy.max_chain_depth {
-            return Err(ValidationError::new(ValidationErrorKind::Other(
-                "chain construction exceeds max depth".into(),
-            )));
-        }
-
-        // Otherwise, we collect a list of potential issuers for this cert,
-        // and continue with the first that verifies.
-        let mut last_err: Option<ValidationError<'_, B>> = None;
-        for issuing_cert_candidate in self.potential_issuers(working_cert) {
-            // A candidate issuer is said to verify if it both
-            // signs for the working certificate and conforms to the
-            // policy.
-            let issuer_extensions = issuing_cert_candidate.certificate().extensions()?;
-            match self.policy.valid_issuer(
-                issuing_cert_candidate,
+            return self.record(
+                StepKind::ChainDepth,
                 working_cert,
-                current_depth,
-                &issuer_extensions,
-            ) {
-                Ok(_) => {
-                    match self.build_chain_inner(
-                        issuing_cert_candidate,
-                        // NOTE(ww): According to RFC 5280, we should only
-                        // increase the chain depth when the certificate is **not**
-                        // self-issued. In practice however, implementations widely
-                        // ignore this requirement, and unconditionally increment
-                        // the depth with every chain member. We choose to do the same;
-                        // see `pathlen::self-issued-certs-pathlen` from x509-limbo
-                        // for the testcase we intentionally fail.
-                        //
-                        // Implementation note for someone looking to change this in the future:
-                        // care should be taken to avoid infinite recursion with self-signed
-                        // certificates in the intermediate set; changing this behavior will
-                        // also require a "is not self-signed" check on intermediate candidates.
-                        //
-                        // See https://gist.github.com/woodruffw/776153088e0df3fc2f0675c5e835f7b8
-                        // for an example of this change.
-                        current_depth.checked_add(1).ok_or_else(|| {
-                            ValidationError::new(ValidationErrorKind::Other(
-                                "current depth calculation overflowed".to_string(),
-                            ))
-                        })?,
-                        &issuer_extensions,
-                        NameChain::new(
-                            Some(&name_chain),
-                            &issuer_extensions,
-                            // Per RFC 5280 4.2.1.10: Name constraints are not applied
-                            // to subjects in self-issued certificates, *unless* the
-                            // certificate is the "final" (i.e., leaf) certificate in the path.
-                            // We accomplish this by only collecting the SANs when the issuing
-                            // candidate (which is a non-leaf by definition) isn't self-issued.
-                            cert_is_self_issued(issuing_cert_candidate.certificate()),
-                        )?,
-                        budget,
-                    ) {
-                        Ok(mut chain) => {
-                            chain.push(working_cert.clone());
-                            return Ok(chain);
-                        }
-                        // Immediately return on fatal error.
-                        Err(
-                            e @ ValidationError {
-                                kind: ValidationErrorKind::FatalError(..),
-                                cert: _,
-                            },
-                        ) => return Err(e),
-                        Err(e) => last_err = Some(e),
-                    };
-                }
-                Err(e) => last_err = Some(e),
-            };
+                None,
+                Err(ValidationError::new(ValidationErrorKind::Other(
+                    "chain construction exceeds max depth".into(),
+                ))),
+            );
         }
 
-        // We only reach this if we fail to hit our base case above, or if
-        // a chain building step fails to find a next valid certificate.
-        Err(ValidationError::new(
-            ValidationErrorKind::CandidatesExhausted(last_err.map_or_else(
-                || {
-                    Box::new(ValidationError::new(ValidationErrorKind::Other(
-                        "all candidates exhausted with no interior errors".to_string(),
-                    )))
-                },
-                |e| match e {
-                    // Avoid spamming the user with nested `CandidatesExhausted` errors.
-                    ValidationError {
-                        kind: ValidationErrorKind::CandidatesExhausted(e),
-                        cert: _,
-                    } => e,
-                    _ => Box::new(e),
-                },
-            )),
-        ))
-    }
-
-    fn build_chain(
-        &self,
-        leaf: &VerificationCertificate<'chain, B>,
-        budget: &mut Budget,
-    ) -> ValidationResult<'chain, Chain<'chain, B>, B> {
-        // Before anything else, check whether the given leaf cert
-        // is well-formed according to our policy (and its underlying
-        // certificate profile).
-        //
-        // The leaf must be an EE; a CA cert in the leaf position will be rejected.
-        let leaf_extensions = leaf.certificate().extensions()?;
+        Ok(State::NextCandidate)
+    }
 
-        self.policy
-            .permits_ee(leaf, &leaf_extensions)
-            .map_err(|e| e.set_cert(leaf.clone()))?;
+    fn choose_candidate(&mut self) -> ValidationResult<'chain, State<'a, 'chain, B>, B> {
+        // We collect a list of potential issuers for the working cert, and
+        // continue with the first that verifies.
+        let working = self.frames.last_mut().unwrap();
+        let working_cert = working.cert;
+        let Some(issuing_cert_candidate) = working.candidates.next() else {
+            // We only reach this if we fail to hit our base case above, or if
+            // a chain building step fails to find a next valid certificate.
+            let err = ValidationError::new(ValidationErrorKind::CandidatesExhausted(
+                working.last_err.take().map_or_else(
+                    || {
+                        Box::new(ValidationError::new(ValidationErrorKind::Other(
+                            "all candidates exhausted with no interior errors".to_string(),
+                        )))
+                    },
+                    |e| match e {
+                        // Avoid spamming the user with nested `CandidatesExhausted` errors.
+                        ValidationError {
+                            kind: ValidationErrorKind::CandidatesExhausted(e),
+                            cert: _,
+                        } => e,
+                        _ => Box::new(e),
+                    },
+                ),
+            ));
+            return self.record(StepKind::CandidatesExhausted, working_cert, None, Err(err));
+        };
 
-        let mut chain = self.build_chain_inner(
-            leaf,
-            0,
-            &leaf_extensions,
-            NameChain::new(None, &leaf_extensions, false)?,
-            budget,
+        // A candidate issuer is said to verify if it both
+        // signs for the working certificate and conforms to the
+        // policy.
+        let issuer_extensions = issuing_cert_candidate.certificate().extensions()?;
+        self.record(
+            StepKind::Candidate,
+            working_cert,
+            Some(issuing_cert_candidate),
+            Ok(()),
         )?;
-        // We build the chain in reverse order, fix it now.
-        chain.reverse();
-        Ok(chain)
+        Ok(State::CandidateChosen {
+            issuer: issuing_cert_candidate,
+            extensions: issuer_extensions,
+        })
+    }
+
+    fn check_candidate_policy(
+        &mut self,
+        issuer: &'a VerificationCertificate<'chain, B>,
+        extensions: Extensions<'chain>,
+    ) -> ValidationResult<'chain, State<'a, 'chain, B>, B> {
+        let current_depth = self.frames.last().unwrap().depth;
+        let result = self.policy.permits_issuer(
+            issuer,
+            current_depth,
+            &extensions,
+            self.store.contains(issuer),
+        );
+        self.record(StepKind::CaPolicy, issuer, None, result)?;
+        Ok(State::IssuerPermitted { issuer, extensions })
+    }
+
+    fn check_candidate_signature(
+        &mut self,
+        issuer: &'a VerificationCertificate<'chain, B>,
+        extensions: Extensions<'chain>,
+    ) -> ValidationResult<'chain, State<'a, 'chain, B>, B> {
+        let working_cert = self.frames.last().unwrap().cert;
+        let result = self.policy.valid_issuer(issuer, working_cert, &extensions);
+        self.record(StepKind::Signature, working_cert, Some(issuer), result)?;
+        Ok(State::IssuerVerified { issuer, extensions })
     }
 }
 
@@ -172,6 +172,71 @@ impl<'cb, B: CryptoOps + 'cb> ExtensionPolicy<'cb, B> {
         }
     }
 
+    pub fn new_default_timestamping_ee() -> Self {
+        // RFC 3161 2.3: a TSA certificate need not carry a subjectAltName,
+        // but its EKU extension MUST be present, critical, and contain
+        // `id-kp-timeStamping`.
+        ExtensionPolicy {
+            subject_alternative_name: ExtensionValidator::maybe_present(
+                SUBJECT_ALTERNATIVE_NAME_OID,
+                Criticality::Agnostic,
+                None,
+            ),
+            extended_key_usage: ExtensionValidator::present(
+                EXTENDED_KEY_USAGE_OID,
+                Criticality::Critical,
+                Some(Arc::new(ee::timestamping_extended_key_usage)),
+            ),
+            ..Self::new_default_webpki_ee()
+        }
+    }
+
+    pub fn new_default_ocsp_responder_ee() -> Self {
+        // RFC 6960 4.2.2.2: a CA may sign responses for the certificates it
+        // issued directly, so the "EE" here may be a CA certificate, with
+        // basicConstraints.cA and keyCertSign asserted. Its EKU only matters
+        // for a delegated responder, which the caller checks against the
+        // issuing CA.
+        ExtensionPolicy {
+            key_usage: ExtensionValidator::maybe_present(
+                KEY_USAGE_OID,
+                Criticality::Agnostic,
+                None,
+            ),
+            subject_alternative_name: ExtensionValidator::maybe_present(
+                SUBJECT_ALTERNATIVE_NAME_OID,
+                Criticality::Agnostic,
+                None,
+            ),
+            basic_constraints: ExtensionValidator::maybe_present(
+                BASIC_CONSTRAINTS_OID,
+                Criticality::Agnostic,
+                None,
+            ),
+            extended_key_usage: ExtensionValidator::maybe_present(
+                EXTENDED_KEY_USAGE_OID,
+                Criticality::Agnostic,
+                None,
+            ),
+            ..Self::new_default_webpki_ee()
+        }
+    }
+
+    pub fn new_default_ocsp_responder_ca() -> Self {
+        // RFC 6960 4.2.2.2: only a delegated responder needs
+        // `id-kp-OCSPSigning`, which the caller checks. The CAs above it are
+        // typically restricted to other EKUs, such as serverAuth, so theirs
+        // aren't checked against the policy's EKU.
+        ExtensionPolicy {
+            extended_key_usage: ExtensionValidator::maybe_present(
+                EXTENDED_KEY_USAGE_OID,
+                Criticality::NonCritical,
+                None,
+            ),
+            ..Self::new_default_webpki_ca()
+        }
+    }
+
     pub(crate) fn permits<'chain>(
         &self,
         policy: &Policy<'_, B>,
@@ -434,6 +499,7 @@ impl<'cb, B: CryptoOps> ExtensionValidator<'cb, B> {
 
 mod ee {
     use cryptography_x509::extensions::{BasicConstraints, ExtendedKeyUsage, Extension, KeyUsage};
+    use cryptography_x509::oid::EKU_ANY_KEY_USAGE_OID;
 
     use crate::ops::{CryptoOps, VerificationCertificate};
     use crate::policy::{Policy, ValidationError, ValidationErrorKind, ValidationResult};
@@ -495,9 +561,13 @@ mod ee {
             // by implementations (which treat a missing EKU as "any EKU").
             // On the other hand, if the EKU is present, it **must** be
             // the one specified in the policy (e.g., `serverAuth`) and
-            // **must not** be the explicit `anyExtendedKeyUsage` EKU.
+            // **must not** be the explicit `anyExtendedKeyUsage` EKU, unless
+            // the policy opts into accepting it.
             // See: CABF 7.1.2.7.10.
-            if ekus.any(|eku| eku == policy.extended_key_usage) {
+            if ekus.any(|eku| {
+                eku == policy.extended_key_usage
+                    || (policy.ee_any_extended_key_usage && eku == EKU_ANY_KEY_USAGE_OID)
+            }) {
                 Ok(())
             } else {
                 Err(ValidationError::new(ValidationErrorKind::Other(
@@ -509,8 +579,26 @@ mod ee {
         }
     }
 
+    pub(crate) fn timestamping_extended_key_usage<'chain, B: CryptoOps>(
+        policy: &Policy<'_, B>,
+        _cert: &VerificationCertificate<'chain, B>,
+        extn: &Extension<'_>,
+    ) -> ValidationResult<'chain, (), B> {
+        let mut ekus: ExtendedKeyUsage<'_> = extn.value()?;
+
+        // RFC 3161 2.3: the TSA's EKU extension must contain only
+        // `id-kp-timeStamping`.
+        if ekus.len() == 1 && ekus.all(|eku| eku == policy.extended_key_usage) {
+            Ok(())
+        } else {
+            Err(ValidationError::new(ValidationErrorKind::Other(
+                "EKU must contain only id-kp-timeStamping".to_string(),
+            )))
+        }
+    }
+
     pub(crate) fn key_usage<'chain, B: CryptoOps>(
-        _policy: &Policy<'_, B>,
+        policy: &Policy<'_, B>,
         _cert: &VerificationCertificate<'chain, B>,
         extn: Option<&Extension<'_>>,
     ) -> ValidationResult<'chain, (), B> {
@@ -522,6 +610,19 @@ mod ee {
                     "EE keyUsage must not assert keyCertSign".to_string(),
                 )));
             }
+
+            // A TLS server key must either sign the handshake or, with RSA
+            // key exchange, decrypt the premaster secret. A key that only
+            // asserts keyAgreement (or similar) can do neither.
+            if policy.ee_tls_key_usage
+                && !key_usage.digital_signature()
+                && !key_usage.key_encipherment()
+            {
+                return Err(ValidationError::new(ValidationErrorKind::Other(
+                    "EE keyUsage must assert digitalSignature or keyEncipherment for TLS"
+                        .to_string(),
+                )));
+            }
         }
 
         Ok(())
@@ -561,8 +662,8 @@ mod ca {
             let aki: AuthorityKeyIdentifier<'_, Asn1Read> = extn.value()?;
             // 7.1.2.11.1 Authority Key Identifier:
 
-            // keyIdentifier MUST be present.
-            // TODO: Check that keyIdentifier matches subjectKeyIdentifier.
+            // keyIdentifier MUST be present. Whether it matches the issuer's
+            // subjectKeyIdentifier is checked during chain building.
             if aki.key_identifier.is_none() {
                 return Err(ValidationError::new(ValidationErrorKind::Other(
                     "authorityKeyIdentifier must contain keyIdentifier".to_string(),
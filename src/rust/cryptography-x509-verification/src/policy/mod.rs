@@ -12,15 +12,19 @@ use asn1::ObjectIdentifier;
 use cryptography_key_parsing::rsa::Pkcs1RsaPublicKey;
 use cryptography_x509::certificate::Certificate;
 use cryptography_x509::common::{
-    AlgorithmIdentifier, AlgorithmParameters, EcParameters, RsaPssParameters, Time,
-    PSS_SHA256_HASH_ALG, PSS_SHA256_MASK_GEN_ALG, PSS_SHA384_HASH_ALG, PSS_SHA384_MASK_GEN_ALG,
-    PSS_SHA512_HASH_ALG, PSS_SHA512_MASK_GEN_ALG,
+    AlgorithmIdentifier, AlgorithmParameters, Asn1Read, EcParameters, RsaPssParameters, Time,
+    PSS_SHA1_HASH_ALG, PSS_SHA256_HASH_ALG, PSS_SHA256_MASK_GEN_ALG, PSS_SHA384_HASH_ALG,
+    PSS_SHA384_MASK_GEN_ALG, PSS_SHA512_HASH_ALG, PSS_SHA512_MASK_GEN_ALG,
+};
+use cryptography_x509::extensions::{
+    AuthorityKeyIdentifier, BasicConstraints, ExtendedKeyUsage, Extensions, SubjectAlternativeName,
 };
-use cryptography_x509::extensions::{BasicConstraints, Extensions, SubjectAlternativeName};
 use cryptography_x509::name::GeneralName;
 use cryptography_x509::oid::{
-    BASIC_CONSTRAINTS_OID, EC_SECP256R1, EC_SECP384R1, EC_SECP521R1, EKU_CLIENT_AUTH_OID,
-    EKU_SERVER_AUTH_OID, SUBJECT_ALTERNATIVE_NAME_OID,
+    AUTHORITY_KEY_IDENTIFIER_OID, BASIC_CONSTRAINTS_OID, EC_SECP256R1, EC_SECP384R1, EC_SECP521R1,
+    EKU_ANY_KEY_USAGE_OID, EKU_CLIENT_AUTH_OID, EKU_OCSP_SIGNING_OID, EKU_SERVER_AUTH_OID,
+    EKU_TIME_STAMPING_OID, EXTENDED_KEY_USAGE_OID, SUBJECT_ALTERNATIVE_NAME_OID,
+    SUBJECT_KEY_IDENTIFIER_OID,
 };
 use once_cell::sync::Lazy;
 
@@ -197,8 +201,38 @@ impl Subject<'_> {
     pub fn matches(&self, san: &SubjectAlternativeName<'_>) -> bool {
         san.clone().any(|gn| self.subject_alt_name_matches(&gn))
     }
+
+    /// Returns the first name in the given `SubjectAlternativeName` that
+    /// matches this `Subject`, e.g. the wildcard pattern for a wildcard match.
+    pub fn matching_name<'b>(&self, san: &SubjectAlternativeName<'b>) -> Option<GeneralName<'b>> {
+        san.clone().find(|gn| self.subject_alt_name_matches(gn))
+    }
 }
 
+/// A caller-provided check of an EE certificate.
+pub type EeCheckCallback<'cb, B> = Arc<
+    dyn for<'chain> Fn(
+            &Policy<'_, B>,
+            &VerificationCertificate<'chain, B>,
+        ) -> ValidationResult<'chain, (), B>
+        + Send
+        + Sync
+        + 'cb,
+>;
+
+/// A caller-provided check of a candidate issuer (the first certificate)
+/// against the certificate it signed for (the second).
+pub type IssuerCheckCallback<'cb, B> = Arc<
+    dyn for<'chain> Fn(
+            &Policy<'_, B>,
+            &VerificationCertificate<'chain, B>,
+            &VerificationCertificate<'chain, B>,
+        ) -> ValidationResult<'chain, (), B>
+        + Send
+        + Sync
+        + 'cb,
+>;
+
 /// A `PolicyDefinition` describes user-configurable aspects of X.509 path validation.
 pub struct PolicyDefinition<'a, B: CryptoOps> {
     pub ops: B,
@@ -233,6 +267,52 @@ pub struct PolicyDefinition<'a, B: CryptoOps> {
     /// algorithm identifiers.
     pub permitted_signature_algorithms: Arc<HashSet<AlgorithmIdentifier<'a>>>,
 
+    /// Signature algorithms permitted in addition to
+    /// `permitted_signature_algorithms`, identified by their exact OIDs
+    /// regardless of parameters.
+    pub additional_signature_algorithms: Vec<ObjectIdentifier>,
+
+    /// Signature algorithms that are rejected even if otherwise permitted,
+    /// identified by their exact OIDs.
+    pub denied_signature_algorithms: Vec<ObjectIdentifier>,
+
+    /// Whether a child's authorityKeyIdentifier must match its issuer's
+    /// subjectKeyIdentifier when both are present. When disabled, issuers
+    /// are matched by name alone.
+    pub require_key_identifier_match: bool,
+
+    /// Whether EKUs are intersected down the path, requiring every CA
+    /// with an extendedKeyUsage extension to permit `extended_key_usage`.
+    /// This is enforced regardless of the configured CA extension policy.
+    pub eku_chaining: bool,
+
+    /// Whether the validity period of the trust anchor is ignored. The
+    /// validity of every other certificate in the chain is still checked.
+    pub ignore_trust_anchor_validity: bool,
+
+    /// Whether `anyExtendedKeyUsage` in an EE certificate's EKU extension
+    /// satisfies `extended_key_usage`. It always does in CA certificates.
+    pub ee_any_extended_key_usage: bool,
+
+    /// The minimum output size, in bytes, of the digest used by each
+    /// signature in the chain. EdDSA signatures, which have no separately
+    /// selectable digest, always satisfy the minimum.
+    pub minimum_signature_digest_size: Option<usize>,
+
+    /// Whether an EE certificate's keyUsage extension, when present, must
+    /// permit its use in TLS, by asserting digitalSignature or
+    /// keyEncipherment. Enabled by default for server policies.
+    pub ee_tls_key_usage: bool,
+
+    /// An additional check made on EE certificates, after the EE extension
+    /// policy.
+    pub ee_check: Option<EeCheckCallback<'a, B>>,
+
+    /// An additional check made on each candidate issuer once it's known
+    /// to have signed for its child. A candidate failing it is rejected
+    /// like any other, and path building moves on to the next candidate.
+    pub issuer_check: Option<IssuerCheckCallback<'a, B>>,
+
     ca_extension_policy: ExtensionPolicy<'a, B>,
     ee_extension_policy: ExtensionPolicy<'a, B>,
 }
@@ -256,6 +336,16 @@ impl<'a, B: CryptoOps + 'a> PolicyDefinition<'a, B> {
             minimum_rsa_modulus: WEBPKI_MINIMUM_RSA_MODULUS,
             permitted_public_key_algorithms: Arc::clone(&*WEBPKI_PERMITTED_SPKI_ALGORITHMS),
             permitted_signature_algorithms: Arc::clone(&*WEBPKI_PERMITTED_SIGNATURE_ALGORITHMS),
+            additional_signature_algorithms: vec![],
+            denied_signature_algorithms: vec![],
+            require_key_identifier_match: true,
+            eku_chaining: false,
+            ignore_trust_anchor_validity: false,
+            ee_any_extended_key_usage: false,
+            minimum_signature_digest_size: None,
+            ee_tls_key_usage: false,
+            ee_check: None,
+            issuer_check: None,
             ca_extension_policy: ca_extension_policy
                 .unwrap_or_else(ExtensionPolicy::new_default_webpki_ca),
             ee_extension_policy: ee_extension_policy
@@ -324,7 +414,7 @@ impl<'a, B: CryptoOps + 'a> PolicyDefinition<'a, B> {
         ca_extension_policy: Option<ExtensionPolicy<'a, B>>,
         ee_extension_policy: Option<ExtensionPolicy<'a, B>>,
     ) -> Result<Self, &'static str> {
-        Self::new(
+        let mut definition = Self::new(
             ops,
             Some(subject),
             time,
@@ -332,6 +422,45 @@ impl<'a, B: CryptoOps + 'a> PolicyDefinition<'a, B> {
             EKU_SERVER_AUTH_OID.clone(),
             ca_extension_policy,
             ee_extension_policy,
+        )?;
+        definition.ee_tls_key_usage = true;
+        Ok(definition)
+    }
+
+    /// Create a new policy for validating the certificate of an RFC 3161
+    /// Time Stamping Authority.
+    pub fn timestamping(
+        ops: B,
+        time: asn1::DateTime,
+        max_chain_depth: Option<u8>,
+    ) -> Result<Self, &'static str> {
+        Self::new(
+            ops,
+            None,
+            time,
+            max_chain_depth,
+            EKU_TIME_STAMPING_OID.clone(),
+            None,
+            Some(ExtensionPolicy::new_default_timestamping_ee()),
+        )
+    }
+
+    /// Create a new policy for validating the certificate of an OCSP
+    /// responder, which may be either the issuing CA itself or a responder
+    /// it delegated to.
+    pub fn ocsp_responder(
+        ops: B,
+        time: asn1::DateTime,
+        max_chain_depth: Option<u8>,
+    ) -> Result<Self, &'static str> {
+        Self::new(
+            ops,
+            None,
+            time,
+            max_chain_depth,
+            EKU_OCSP_SIGNING_OID.clone(),
+            Some(ExtensionPolicy::new_default_ocsp_responder_ca()),
+            Some(ExtensionPolicy::new_default_ocsp_responder_ee()),
         )
     }
 }
@@ -354,7 +483,11 @@ impl<'a, B: CryptoOps> Policy<'a, B> {
         Self { definition, extra }
     }
 
-    fn permits_basic<'chain>(&self, cert: &Certificate<'_>) -> ValidationResult<'chain, (), B> {
+    fn permits_basic<'chain>(
+        &self,
+        cert: &Certificate<'_>,
+        check_validity: bool,
+    ) -> ValidationResult<'chain, (), B> {
         // CA/B 7.1.1:
         // Certificates MUST be of type X.509 v3.
         if cert.tbs_cert.version != 2 {
@@ -407,7 +540,9 @@ impl<'a, B: CryptoOps> Policy<'a, B> {
         let not_after = cert.tbs_cert.validity.not_after.as_datetime();
         permits_validity_date(&cert.tbs_cert.validity.not_before)?;
         permits_validity_date(&cert.tbs_cert.validity.not_after)?;
-        if &self.validation_time < not_before || &self.validation_time > not_after {
+        if check_validity
+            && (&self.validation_time < not_before || &self.validation_time > not_after)
+        {
             return Err(ValidationError::new(ValidationErrorKind::Other(
                 "cert is not valid at validation time".to_string(),
             )));
@@ -422,8 +557,9 @@ impl<'a, B: CryptoOps> Policy<'a, B> {
         cert: &VerificationCertificate<'chain, B>,
         current_depth: u8,
         extensions: &Extensions<'_>,
+        check_validity: bool,
     ) -> ValidationResult<'chain, (), B> {
-        self.permits_basic(cert.certificate())?;
+        self.permits_basic(cert.certificate(), check_validity)?;
 
         // 5280 4.1.2.6: Subject
         // CA certificates MUST have a subject populated with a non-empty distinguished name.
@@ -459,6 +595,21 @@ impl<'a, B: CryptoOps> Policy<'a, B> {
 
         self.ca_extension_policy.permits(self, cert, extensions)?;
 
+        // A CA without an EKU extension doesn't constrain its subordinates,
+        // and anyExtendedKeyUsage permits every EKU, so the intersection
+        // along the path keeps the required EKU iff every constraining CA
+        // lists it.
+        if self.eku_chaining {
+            if let Some(extn) = extensions.get_extension(&EXTENDED_KEY_USAGE_OID) {
+                let mut ekus: ExtendedKeyUsage<'_> = extn.value()?;
+                if !ekus.any(|eku| eku == self.extended_key_usage || eku == EKU_ANY_KEY_USAGE_OID) {
+                    return Err(ValidationError::new(ValidationErrorKind::Other(
+                        "EKU chaining: CA does not permit the required EKU".to_string(),
+                    )));
+                }
+            }
+        }
+
         Ok(())
     }
 
@@ -468,7 +619,7 @@ impl<'a, B: CryptoOps> Policy<'a, B> {
         cert: &VerificationCertificate<'chain, B>,
         extensions: &Extensions<'chain>,
     ) -> ValidationResult<'chain, (), B> {
-        self.permits_basic(cert.certificate())?;
+        self.permits_basic(cert.certificate(), true)?;
 
         if let Some(ref subject) = self.subject {
             let san: Option<SubjectAlternativeName<'chain>> =
@@ -481,32 +632,70 @@ impl<'a, B: CryptoOps> Policy<'a, B> {
 
         self.ee_extension_policy.permits(self, cert, extensions)?;
 
+        if let Some(check) = &self.ee_check {
+            check(self, cert)?;
+        }
+
         Ok(())
     }
 
-    /// Checks whether `issuer` is a valid issuing CA for `child` at a
-    /// path-building depth of `current_depth`.
-    ///
-    /// This checks that `issuer` is permitted under this policy and that
-    /// it was used to sign for `child`.
+    /// Checks whether `issuer` is permitted under this policy as an issuing
+    /// CA at a path-building depth of `current_depth`.
+    pub(crate) fn permits_issuer<'chain>(
+        &self,
+        issuer: &VerificationCertificate<'chain, B>,
+        current_depth: u8,
+        issuer_extensions: &Extensions<'_>,
+        issuer_is_trust_anchor: bool,
+    ) -> ValidationResult<'chain, (), B> {
+        // The issuer needs to be a valid CA at the current depth.
+        let check_validity = !(issuer_is_trust_anchor && self.ignore_trust_anchor_validity);
+        self.permits_ca(issuer, current_depth, issuer_extensions, check_validity)
+            .map_err(|e| e.set_cert(issuer.clone()))
+    }
+
+    /// Checks whether `issuer` is a valid issuing CA for `child`, i.e. that
+    /// its key is acceptable and was used to sign for `child`.
     ///
     /// As a precondition, the caller must have already checked that
-    /// `issuer.subject() == child.issuer()`.
-    ///
-    /// On success, this function returns the new path-building depth. This
-    /// may or may not be a higher number than the original depth, depending
-    /// on the kind of validation performed (e.g., whether the issuer was
-    /// self-issued).
+    /// `issuer.subject() == child.issuer()` and that `issuer` passes
+    /// `permits_issuer`.
     pub(crate) fn valid_issuer<'chain>(
         &self,
         issuer: &VerificationCertificate<'chain, B>,
         child: &VerificationCertificate<'chain, B>,
-        current_depth: u8,
         issuer_extensions: &Extensions<'_>,
     ) -> ValidationResult<'chain, (), B> {
-        // The issuer needs to be a valid CA at the current depth.
-        self.permits_ca(issuer, current_depth, issuer_extensions)
-            .map_err(|e| e.set_cert(issuer.clone()))?;
+        // 5280 4.2.1.1: Authority Key Identifier
+        // The AKI identifies the key that signed the child. Matching is
+        // only possible when both the child's keyIdentifier and the
+        // issuer's SKI are present; otherwise we fall back to name matching.
+        if self.require_key_identifier_match {
+            let child_aki = match child
+                .certificate()
+                .extensions()?
+                .get_extension(&AUTHORITY_KEY_IDENTIFIER_OID)
+            {
+                Some(extn) => {
+                    extn.value::<AuthorityKeyIdentifier<'_, Asn1Read>>()?
+                        .key_identifier
+                }
+                None => None,
+            };
+            let issuer_ski = match issuer_extensions.get_extension(&SUBJECT_KEY_IDENTIFIER_OID) {
+                Some(extn) => Some(extn.value::<&[u8]>()?),
+                None => None,
+            };
+            if let (Some(aki), Some(ski)) = (child_aki, issuer_ski) {
+                if aki != ski {
+                    return Err(ValidationError::new(ValidationErrorKind::Other(
+                        "authorityKeyIdentifier does not match the issuer's \
+                         subjectKeyIdentifier"
+                            .to_string(),
+                    )));
+                }
+            }
+        }
 
         // CA/B 7.1.3.1 SubjectPublicKeyInfo
         // NOTE: We check the issuer's SPKI here, since the issuer is
@@ -527,15 +716,38 @@ impl<'a, B: CryptoOps> Policy<'a, B> {
         // if it's a root). This works out transitively, as any non root-issuer
         // will be checked in its recursive step (where it'll be in the child
         // position).
-        if !self
-            .permitted_signature_algorithms
-            .contains(&child.certificate().signature_alg)
+        let signature_alg = &child.certificate().signature_alg;
+        if self
+            .denied_signature_algorithms
+            .contains(signature_alg.oid())
+        {
+            return Err(ValidationError::new(ValidationErrorKind::Other(format!(
+                "Denied signature algorithm: {:?}",
+                signature_alg
+            ))));
+        }
+        if !self.permitted_signature_algorithms.contains(signature_alg)
+            && !self
+                .additional_signature_algorithms
+                .contains(signature_alg.oid())
         {
             return Err(ValidationError::new(ValidationErrorKind::Other(format!(
                 "Forbidden signature algorithm: {:?}",
-                &child.certificate().signature_alg
+                signature_alg
             ))));
         }
+        if let Some(minimum) = self.minimum_signature_digest_size {
+            let permitted = match &signature_alg.params {
+                AlgorithmParameters::Ed25519 | AlgorithmParameters::Ed448 => true,
+                params => signature_digest_size(params).map_or(false, |size| size >= minimum),
+            };
+            if !permitted {
+                return Err(ValidationError::new(ValidationErrorKind::Other(format!(
+                    "Signature algorithm is weaker than the minimum signature strength: {:?}",
+                    signature_alg
+                ))));
+            }
+        }
 
         // We do this before checking the RSA key size so that if parsing the
         // key fails, we get a nice error message.
@@ -569,10 +781,60 @@ impl<'a, B: CryptoOps> Policy<'a, B> {
             )));
         }
 
+        if let Some(check) = &self.issuer_check {
+            check(self, issuer, child)?;
+        }
+
         Ok(())
     }
 }
 
+/// Returns the output size, in bytes, of the digest used by a signature
+/// algorithm, or `None` if the algorithm has no known digest.
+fn signature_digest_size(params: &AlgorithmParameters<'_>) -> Option<usize> {
+    match params {
+        AlgorithmParameters::RsaWithSha1(_)
+        | AlgorithmParameters::RsaWithSha1Alt(_)
+        | AlgorithmParameters::EcDsaWithSha1(_)
+        | AlgorithmParameters::Sha1(_) => Some(20),
+        AlgorithmParameters::RsaWithSha224(_)
+        | AlgorithmParameters::RsaWithSha3_224(_)
+        | AlgorithmParameters::EcDsaWithSha224(_)
+        | AlgorithmParameters::EcDsaWithSha3_224
+        | AlgorithmParameters::DsaWithSha224(_)
+        | AlgorithmParameters::Sha224(_)
+        | AlgorithmParameters::Sha3_224(_) => Some(28),
+        AlgorithmParameters::RsaWithSha256(_)
+        | AlgorithmParameters::RsaWithSha3_256(_)
+        | AlgorithmParameters::EcDsaWithSha256(_)
+        | AlgorithmParameters::EcDsaWithSha3_256
+        | AlgorithmParameters::DsaWithSha256(_)
+        | AlgorithmParameters::Sha256(_)
+        | AlgorithmParameters::Sha3_256(_) => Some(32),
+        AlgorithmParameters::RsaWithSha384(_)
+        | AlgorithmParameters::RsaWithSha3_384(_)
+        | AlgorithmParameters::EcDsaWithSha384(_)
+        | AlgorithmParameters::EcDsaWithSha3_384
+        | AlgorithmParameters::DsaWithSha384(_)
+        | AlgorithmParameters::Sha384(_)
+        | AlgorithmParameters::Sha3_384(_) => Some(48),
+        AlgorithmParameters::RsaWithSha512(_)
+        | AlgorithmParameters::RsaWithSha3_512(_)
+        | AlgorithmParameters::EcDsaWithSha512(_)
+        | AlgorithmParameters::EcDsaWithSha3_512
+        | AlgorithmParameters::DsaWithSha512(_)
+        | AlgorithmParameters::Sha512(_)
+        | AlgorithmParameters::Sha3_512(_) => Some(64),
+        AlgorithmParameters::RsaPss(pss_params) => signature_digest_size(
+            &pss_params
+                .as_ref()
+                .map_or(&PSS_SHA1_HASH_ALG, |p| &p.hash_algorithm)
+                .params,
+        ),
+        _ => None,
+    }
+}
+
 fn permits_validity_date<'chain, B: CryptoOps>(
     validity_date: &Time,
 ) -> ValidationResult<'chain, (), B> {
@@ -615,14 +877,14 @@ mod tests {
     use std::ops::Deref;
 
     use asn1::{DateTime, SequenceOfWriter};
-    use cryptography_x509::common::Time;
+    use cryptography_x509::common::{AlgorithmParameters, Time};
     use cryptography_x509::extensions::SubjectAlternativeName;
     use cryptography_x509::name::{GeneralName, UnvalidatedIA5String};
 
     use super::{
-        permits_validity_date, ECDSA_SHA256, ECDSA_SHA384, ECDSA_SHA512, RSASSA_PKCS1V15_SHA256,
-        RSASSA_PKCS1V15_SHA384, RSASSA_PKCS1V15_SHA512, RSASSA_PSS_SHA256, RSASSA_PSS_SHA384,
-        RSASSA_PSS_SHA512, WEBPKI_PERMITTED_SIGNATURE_ALGORITHMS,
+        permits_validity_date, signature_digest_size, ECDSA_SHA256, ECDSA_SHA384, ECDSA_SHA512,
+        RSASSA_PKCS1V15_SHA256, RSASSA_PKCS1V15_SHA384, RSASSA_PKCS1V15_SHA512, RSASSA_PSS_SHA256,
+        RSASSA_PSS_SHA384, RSASSA_PSS_SHA512, WEBPKI_PERMITTED_SIGNATURE_ALGORITHMS,
     };
     use crate::certificate::tests::PublicKeyErrorOps;
     use crate::policy::{
@@ -770,6 +1032,16 @@ mod tests {
 
             assert!(domain_sub.matches(&any_cryptography_io_or_localhost));
             assert!(ip_sub.matches(&any_cryptography_io_or_localhost));
+            assert!(matches!(
+                domain_sub.matching_name(&any_cryptography_io_or_localhost),
+                Some(GeneralName::DNSName(UnvalidatedIA5String(
+                    "*.cryptography.io"
+                )))
+            ));
+            assert!(matches!(
+                ip_sub.matching_name(&any_cryptography_io_or_localhost),
+                Some(GeneralName::IPAddress(&[127, 0, 0, 1]))
+            ));
         }
 
         // Single SAN, invalid domain pattern.
@@ -780,9 +1052,29 @@ mod tests {
                 asn1::parse_single::<SubjectAlternativeName<'_>>(&san_der).unwrap();
 
             assert!(!domain_sub.matches(&any_cryptography_io));
+            assert!(domain_sub.matching_name(&any_cryptography_io).is_none());
         }
     }
 
+    #[test]
+    fn test_signature_digest_size() {
+        assert_eq!(
+            signature_digest_size(&RSASSA_PKCS1V15_SHA256.params),
+            Some(32)
+        );
+        assert_eq!(signature_digest_size(&ECDSA_SHA384.params), Some(48));
+        assert_eq!(signature_digest_size(&RSASSA_PSS_SHA512.params), Some(64));
+        assert_eq!(
+            signature_digest_size(&AlgorithmParameters::RsaPss(None)),
+            Some(20)
+        );
+        assert_eq!(
+            signature_digest_size(&AlgorithmParameters::RsaWithSha1(Some(()))),
+            Some(20)
+        );
+        assert_eq!(signature_digest_size(&AlgorithmParameters::Ed25519), None);
+    }
+
     #[test]
     fn test_validity_date() {
         {
@@ -26,6 +26,22 @@ impl<'a, B: CryptoOps> Store<'a, B> {
         Store { by_subject }
     }
 
+    /// Returns a new `Store` containing this store's certificates, along with
+    /// the given additional trusted certificates.
+    pub fn with_additional(
+        &self,
+        trusted: impl IntoIterator<Item = VerificationCertificate<'a, B>>,
+    ) -> Self {
+        let mut by_subject = self.by_subject.clone();
+        for cert in trusted {
+            by_subject
+                .entry(cert.certificate().tbs_cert.subject.clone())
+                .or_default()
+                .push(cert);
+        }
+        Store { by_subject }
+    }
+
     /// Returns whether this store contains the given certificate.
     pub fn contains(&self, cert: &VerificationCertificate<'a, B>) -> bool {
         self.get_by_subject(&cert.certificate().tbs_cert.subject)
@@ -58,4 +74,19 @@ mod tests {
 
         assert!(store.contains(&cert2));
     }
+
+    #[test]
+    fn test_store_with_additional() {
+        let cert_pem = v1_cert_pem();
+        let c1 = cert(&cert_pem);
+        let c2 = cert(&cert_pem);
+        let cert1 = VerificationCertificate::new(&c1, ());
+        let cert2 = VerificationCertificate::new(&c2, ());
+        let store = Store::<'_, PublicKeyErrorOps>::new([]);
+        assert!(!store.contains(&cert2));
+
+        let extended = store.with_additional([cert1]);
+        assert!(extended.contains(&cert2));
+        assert!(!store.contains(&cert2));
+    }
 }
@@ -61,6 +61,8 @@ pub enum AlgorithmParameters<'a> {
     // but Java 11 (up to at least 11.0.19) encodes them
     // with NULL parameters. The JDK team is looking to
     // backport the fix as of June 2023.
+    #[defined_by(oid::ECDSA_WITH_SHA1_OID)]
+    EcDsaWithSha1(Option<asn1::Null>),
     #[defined_by(oid::ECDSA_WITH_SHA224_OID)]
     EcDsaWithSha224(Option<asn1::Null>),
     #[defined_by(oid::ECDSA_WITH_SHA256_OID)]
@@ -158,6 +160,10 @@ pub enum AlgorithmParameters<'a> {
     #[defined_by(oid::DES_EDE3_CBC_OID)]
     DesEde3Cbc([u8; 8]),
 
+    // RFC 3274 Section 2: the parameters field MUST be absent.
+    #[defined_by(oid::ZLIB_COMPRESS_OID)]
+    ZlibCompress,
+
     #[defined_by(oid::PBES1_WITH_SHA_AND_3KEY_TRIPLEDES_CBC)]
     Pbes1WithShaAnd3KeyTripleDesCbc(PBES1Params),
     #[defined_by(oid::PBES1_WITH_SHA_AND_40_BIT_RC2_CBC)]
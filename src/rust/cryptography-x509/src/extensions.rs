@@ -301,6 +301,25 @@ pub struct Admissions<'a, Op: Asn1Operation> {
     pub contents_of_admissions: Op::SequenceOfVec<'a, Admission<'a, Op>>,
 }
 
+// RFC 8226 Section 9: TNAuthorizationList ::= SEQUENCE SIZE (1..MAX) OF TNEntry
+pub type TnAuthList<'a> = asn1::SequenceOf<'a, TnEntry<'a>, 1>;
+
+#[derive(asn1::Asn1Read, asn1::Asn1Write)]
+pub enum TnEntry<'a> {
+    #[explicit(0)]
+    Spc(asn1::IA5String<'a>),
+    #[explicit(1)]
+    Range(TelephoneNumberRange<'a>),
+    #[explicit(2)]
+    One(asn1::IA5String<'a>),
+}
+
+#[derive(asn1::Asn1Read, asn1::Asn1Write)]
+pub struct TelephoneNumberRange<'a> {
+    pub start: asn1::IA5String<'a>,
+    pub count: u64,
+}
+
 #[cfg(test)]
 mod tests {
     use super::{BasicConstraints, Extension, Extensions, KeyUsage};
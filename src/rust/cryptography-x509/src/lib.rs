@@ -18,3 +18,4 @@ pub mod oid;
 pub mod pkcs12;
 pub mod pkcs7;
 pub mod pkcs8;
+pub mod tsp;
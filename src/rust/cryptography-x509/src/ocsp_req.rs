@@ -2,7 +2,7 @@
 // 2.0, and the BSD License. See the LICENSE file in the root of this repository
 // for complete details.
 
-use crate::{common, extensions, name};
+use crate::{certificate, common, extensions, name};
 
 #[derive(asn1::Asn1Read, asn1::Asn1Write)]
 pub struct TBSRequest<'a> {
@@ -38,8 +38,17 @@ pub struct CertID<'a> {
 pub struct OCSPRequest<'a> {
     pub tbs_request: TBSRequest<'a>,
     // Parsing out the full structure, which includes the entirety of a
-    // certificate is more trouble than it's worth, since it's not in the
-    // Python API.
+    // certificate, is only done when verifying the signature, so that a
+    // malformed signature doesn't prevent the rest of the request from
+    // being loaded.
     #[explicit(0)]
     pub optional_signature: Option<asn1::Sequence<'a>>,
 }
+
+#[derive(asn1::Asn1Read, asn1::Asn1Write)]
+pub struct Signature<'a> {
+    pub signature_algorithm: common::AlgorithmIdentifier<'a>,
+    pub signature: asn1::BitString<'a>,
+    #[explicit(0)]
+    pub certs: Option<asn1::SequenceOf<'a, certificate::Certificate<'a>>>,
+}
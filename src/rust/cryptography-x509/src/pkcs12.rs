@@ -28,6 +28,23 @@ pub struct MacData<'a> {
     pub iterations: u64,
 }
 
+/// A view of a `Pfx` that only decodes its `MacData`. Unlike `MacData`, an
+/// explicitly encoded default iteration count, as written by older versions
+/// of OpenSSL, is accepted.
+#[derive(asn1::Asn1Read)]
+pub struct PfxMacView<'a> {
+    pub version: u8,
+    pub auth_safe: asn1::Tlv<'a>,
+    pub mac_data: Option<MacDataView<'a>>,
+}
+
+#[derive(asn1::Asn1Read)]
+pub struct MacDataView<'a> {
+    pub mac: pkcs7::DigestInfo<'a>,
+    pub salt: &'a [u8],
+    pub iterations: Option<u64>,
+}
+
 #[derive(asn1::Asn1Write)]
 pub struct SafeBag<'a> {
     pub _bag_id: asn1::DefinedByMarker<asn1::ObjectIdentifier>,
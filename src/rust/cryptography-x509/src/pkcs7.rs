@@ -8,6 +8,10 @@ pub const PKCS7_DATA_OID: asn1::ObjectIdentifier = asn1::oid!(1, 2, 840, 113549,
 pub const PKCS7_SIGNED_DATA_OID: asn1::ObjectIdentifier = asn1::oid!(1, 2, 840, 113549, 1, 7, 2);
 pub const PKCS7_ENVELOPED_DATA_OID: asn1::ObjectIdentifier = asn1::oid!(1, 2, 840, 113549, 1, 7, 3);
 pub const PKCS7_ENCRYPTED_DATA_OID: asn1::ObjectIdentifier = asn1::oid!(1, 2, 840, 113549, 1, 7, 6);
+pub const PKCS7_TST_INFO_OID: asn1::ObjectIdentifier =
+    asn1::oid!(1, 2, 840, 113549, 1, 9, 16, 1, 4);
+pub const PKCS7_COMPRESSED_DATA_OID: asn1::ObjectIdentifier =
+    asn1::oid!(1, 2, 840, 113549, 1, 9, 16, 1, 9);
 
 #[derive(asn1::Asn1Write, asn1::Asn1Read)]
 pub struct ContentInfo<'a> {
@@ -27,6 +31,10 @@ pub enum Content<'a> {
     Data(Option<asn1::Explicit<&'a [u8], 0>>),
     #[defined_by(PKCS7_ENCRYPTED_DATA_OID)]
     EncryptedData(asn1::Explicit<EncryptedData<'a>, 0>),
+    #[defined_by(PKCS7_TST_INFO_OID)]
+    TstInfo(Option<asn1::Explicit<&'a [u8], 0>>),
+    #[defined_by(PKCS7_COMPRESSED_DATA_OID)]
+    CompressedData(asn1::Explicit<CompressedData<'a>, 0>),
 }
 
 #[derive(asn1::Asn1Write, asn1::Asn1Read)]
@@ -75,6 +83,13 @@ pub struct SignerInfo<'a> {
     pub unauthenticated_attributes: Option<csr::Attributes<'a>>,
 }
 
+// RFC 8551 Section 2.5.2
+#[derive(asn1::Asn1Write, asn1::Asn1Read)]
+pub struct SMIMECapability<'a> {
+    pub capability_id: asn1::ObjectIdentifier,
+    pub parameters: Option<asn1::Tlv<'a>>,
+}
+
 #[derive(asn1::Asn1Write, asn1::Asn1Read)]
 pub struct EnvelopedData<'a> {
     pub version: u8,
@@ -113,6 +128,22 @@ pub struct EncryptedContentInfo<'a> {
     pub encrypted_content: Option<&'a [u8]>,
 }
 
+// RFC 3274 Section 1.1
+#[derive(asn1::Asn1Write, asn1::Asn1Read)]
+pub struct CompressedData<'a> {
+    pub version: u8,
+    pub compression_algorithm: common::AlgorithmIdentifier<'a>,
+    pub encap_content_info: EncapsulatedContentInfo<'a>,
+}
+
+// RFC 5652 Section 5.2
+#[derive(asn1::Asn1Write, asn1::Asn1Read)]
+pub struct EncapsulatedContentInfo<'a> {
+    pub content_type: asn1::ObjectIdentifier,
+    #[explicit(0)]
+    pub content: Option<&'a [u8]>,
+}
+
 #[derive(asn1::Asn1Write, asn1::Asn1Read)]
 pub struct DigestInfo<'a> {
     pub algorithm: common::AlgorithmIdentifier<'a>,
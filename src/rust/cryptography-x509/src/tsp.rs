@@ -0,0 +1,38 @@
+// This file is dual licensed under the terms of the Apache License, Version
+// 2.0, and the BSD License. See the LICENSE file in the root of this repository
+// for complete details.
+
+use crate::{common, extensions, name};
+
+// RFC 3161 Section 2.4.2
+#[derive(asn1::Asn1Read, asn1::Asn1Write)]
+pub struct TSTInfo<'a> {
+    pub version: u8,
+    pub policy: asn1::ObjectIdentifier,
+    pub message_imprint: MessageImprint<'a>,
+    pub serial_number: asn1::BigUint<'a>,
+    pub gen_time: asn1::GeneralizedTime,
+    pub accuracy: Option<Accuracy>,
+    #[default(false)]
+    pub ordering: bool,
+    pub nonce: Option<asn1::BigUint<'a>>,
+    #[explicit(0)]
+    pub tsa: Option<name::GeneralName<'a>>,
+    #[implicit(1)]
+    pub extensions: Option<extensions::RawExtensions<'a>>,
+}
+
+#[derive(asn1::Asn1Read, asn1::Asn1Write)]
+pub struct MessageImprint<'a> {
+    pub hash_algorithm: common::AlgorithmIdentifier<'a>,
+    pub hashed_message: &'a [u8],
+}
+
+#[derive(asn1::Asn1Read, asn1::Asn1Write)]
+pub struct Accuracy {
+    pub seconds: Option<u64>,
+    #[implicit(0)]
+    pub millis: Option<u16>,
+    #[implicit(1)]
+    pub micros: Option<u16>,
+}
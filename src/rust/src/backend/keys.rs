@@ -2,6 +2,7 @@
 // 2.0, and the BSD License. See the LICENSE file in the root of this repository
 // for complete details.
 
+use pyo3::types::PyAnyMethods;
 use pyo3::IntoPyObject;
 
 use crate::backend::utils;
@@ -74,6 +75,65 @@ fn load_pem_private_key<'p>(
     private_key_from_pkey(py, &pkey, unsafe_skip_rsa_key_validation)
 }
 
+/// Loads a PEM or DER encoded PKCS#8 private key, which may be encrypted.
+/// Unlike `load_der_private_key` and `load_pem_private_key`, keys in their
+/// traditional formats are rejected.
+#[pyo3::pyfunction]
+fn load_pkcs8_private_key<'p>(
+    py: pyo3::Python<'p>,
+    data: CffiBuf<'_>,
+    password: Option<CffiBuf<'_>>,
+) -> CryptographyResult<pyo3::Bound<'p, pyo3::PyAny>> {
+    let password = password.as_ref().map(CffiBuf::as_bytes);
+    let not_pkcs8 = || {
+        CryptographyError::from(pyo3::exceptions::PyValueError::new_err(
+            "key must be a PEM or DER encoded PKCS8 private key.",
+        ))
+    };
+    let pem_block;
+    let der = match pem::parse(data.as_bytes()) {
+        Ok(block) if block.tag() == "PRIVATE KEY" || block.tag() == "ENCRYPTED PRIVATE KEY" => {
+            pem_block = block;
+            pem_block.contents()
+        }
+        Ok(_) => return Err(not_pkcs8()),
+        Err(_) => data.as_bytes(),
+    };
+
+    // The key is either an unencrypted PrivateKeyInfo...
+    if let Ok(pkey) = openssl::pkey::PKey::private_key_from_pkcs8(der) {
+        if password.is_some() {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyTypeError::new_err(
+                    "Password was given but private key is not encrypted.",
+                ),
+            ));
+        }
+        return private_key_from_pkey(py, &pkey, false);
+    }
+
+    // ...or an EncryptedPrivateKeyInfo. Anything else fails to parse before
+    // a password is asked for.
+    let mut status = utils::PasswordCallbackStatus::Unused;
+    let pkey = openssl::pkey::PKey::private_key_from_pkcs8_callback(
+        der,
+        utils::password_callback(&mut status, password),
+    );
+    match (&pkey, &status, password) {
+        (Err(_), utils::PasswordCallbackStatus::Unused, _) => return Err(not_pkcs8()),
+        (Err(_), utils::PasswordCallbackStatus::Used, Some(p)) if !p.is_empty() => {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyValueError::new_err(
+                    "Could not load the PKCS8 private key. The key_password may be incorrect or the key may be malformed.",
+                ),
+            ))
+        }
+        _ => {}
+    }
+    let pkey = utils::handle_key_load_result(py, pkey, status, password)?;
+    private_key_from_pkey(py, &pkey, false)
+}
+
 fn private_key_from_pkey<'p>(
     py: pyo3::Python<'p>,
     pkey: &openssl::pkey::PKeyRef<openssl::pkey::Private>,
@@ -204,6 +264,76 @@ fn load_pem_public_key<'p>(
     public_key_from_pkey(py, &pkey, pkey.id())
 }
 
+/// The algorithm, size and fingerprint of a SubjectPublicKeyInfo.
+#[pyo3::pyclass(frozen, module = "cryptography.hazmat.bindings._rust.openssl.keys")]
+pub(crate) struct PublicKeyInfo {
+    #[pyo3(get)]
+    algorithm: &'static str,
+    #[pyo3(get)]
+    key_size: Option<u32>,
+    #[pyo3(get)]
+    curve: Option<pyo3::PyObject>,
+    #[pyo3(get)]
+    fingerprint: pyo3::Py<pyo3::types::PyBytes>,
+}
+
+#[pyo3::pymethods]
+impl PublicKeyInfo {
+    fn __repr__(&self) -> String {
+        match self.key_size {
+            Some(key_size) => format!(
+                "<PublicKeyInfo(algorithm={}, key_size={key_size})>",
+                self.algorithm
+            ),
+            None => format!("<PublicKeyInfo(algorithm={})>", self.algorithm),
+        }
+    }
+}
+
+#[pyo3::pyfunction]
+fn load_der_public_key_info(
+    py: pyo3::Python<'_>,
+    data: CffiBuf<'_>,
+) -> CryptographyResult<PublicKeyInfo> {
+    let pkey = cryptography_key_parsing::spki::parse_public_key(data.as_bytes())?;
+    let (algorithm, key_size) = match pkey.id() {
+        openssl::pkey::Id::RSA => ("RSA", Some(pkey.bits())),
+        openssl::pkey::Id::DSA => ("DSA", Some(pkey.bits())),
+        openssl::pkey::Id::EC => ("EC", Some(pkey.bits())),
+        openssl::pkey::Id::DH => ("DH", Some(pkey.bits())),
+        #[cfg(all(not(CRYPTOGRAPHY_IS_LIBRESSL), not(CRYPTOGRAPHY_IS_BORINGSSL)))]
+        openssl::pkey::Id::DHX => ("DH", Some(pkey.bits())),
+        openssl::pkey::Id::ED25519 => ("Ed25519", None),
+        #[cfg(all(not(CRYPTOGRAPHY_IS_LIBRESSL), not(CRYPTOGRAPHY_IS_BORINGSSL)))]
+        openssl::pkey::Id::ED448 => ("Ed448", None),
+        openssl::pkey::Id::X25519 => ("X25519", None),
+        #[cfg(all(not(CRYPTOGRAPHY_IS_LIBRESSL), not(CRYPTOGRAPHY_IS_BORINGSSL)))]
+        openssl::pkey::Id::X448 => ("X448", None),
+        _ => {
+            return Err(CryptographyError::from(
+                exceptions::UnsupportedAlgorithm::new_err("Unsupported key type."),
+            ))
+        }
+    };
+    let curve = match pkey.id() {
+        openssl::pkey::Id::EC => Some(
+            public_key_from_pkey(py, &pkey, pkey.id())?
+                .getattr(pyo3::intern!(py, "curve"))?
+                .unbind(),
+        ),
+        _ => None,
+    };
+    // Re-encoding the key gives the same fingerprint for every encoding of
+    // it that we accept.
+    let fingerprint = openssl::sha::sha256(&pkey.public_key_to_der()?);
+    Ok(PublicKeyInfo {
+        algorithm,
+        key_size,
+        curve,
+        fingerprint: pyo3::types::PyBytes::new(py, &fingerprint).unbind(),
+    })
+}
+
 fn public_key_from_pkey<'p>(
     py: pyo3::Python<'p>,
     pkey: &openssl::pkey::PKeyRef<openssl::pkey::Public>,
@@ -256,7 +386,8 @@ fn public_key_from_pkey<'p>(
 pub(crate) mod keys {
     #[pymodule_export]
     use super::{
-        load_der_private_key, load_der_public_key, load_pem_private_key, load_pem_public_key,
+        load_der_private_key, load_der_public_key, load_der_public_key_info, load_pem_private_key,
+        load_pem_public_key, load_pkcs8_private_key, PublicKeyInfo,
     };
 }
 
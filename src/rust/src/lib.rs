@@ -54,6 +54,77 @@ fn is_fips_enabled() -> bool {
     cryptography_openssl::fips::is_enabled()
 }
 
+// Digests that may be named by a signature algorithm, along with the name
+// OpenSSL knows them by.
+const DIGEST_ALGORITHMS: &[(::asn1::ObjectIdentifier, &str)] = &[
+    (cryptography_x509::oid::SHA1_OID, "SHA1"),
+    (cryptography_x509::oid::SHA224_OID, "SHA224"),
+    (cryptography_x509::oid::SHA256_OID, "SHA256"),
+    (cryptography_x509::oid::SHA384_OID, "SHA384"),
+    (cryptography_x509::oid::SHA512_OID, "SHA512"),
+    (cryptography_x509::oid::NIST_SHA3_224_OID, "SHA3-224"),
+    (cryptography_x509::oid::NIST_SHA3_256_OID, "SHA3-256"),
+    (cryptography_x509::oid::NIST_SHA3_384_OID, "SHA3-384"),
+    (cryptography_x509::oid::NIST_SHA3_512_OID, "SHA3-512"),
+];
+
+fn digest_available(name: &str) -> bool {
+    cfg_if::cfg_if! {
+        if #[cfg(CRYPTOGRAPHY_OPENSSL_300_OR_GREATER)] {
+            // Unlike a name lookup, fetching only succeeds if one of the
+            // loaded providers implements the digest.
+            openssl::md::Md::fetch(None, name, None).is_ok()
+        } else {
+            openssl::hash::MessageDigest::from_name(name).is_some()
+        }
+    }
+}
+
+fn signature_key_type_available(id: openssl::pkey::Id) -> bool {
+    openssl::pkey_ctx::PkeyCtx::new_id(id)
+        .and_then(|mut ctx| ctx.keygen_init())
+        .is_ok()
+}
+
+#[pyo3::pyfunction]
+fn supported_digest_oids(
+    py: pyo3::Python<'_>,
+) -> pyo3::PyResult<pyo3::Bound<'_, pyo3::types::PyFrozenSet>> {
+    let oids = DIGEST_ALGORITHMS
+        .iter()
+        .filter(|(_, name)| digest_available(name))
+        .map(|(oid, _)| crate::asn1::oid_to_py_oid(py, oid))
+        .collect::<pyo3::PyResult<Vec<_>>>()?;
+    pyo3::types::PyFrozenSet::new(py, &oids)
+}
+
+#[pyo3::pyfunction]
+fn supported_signature_algorithms(
+    py: pyo3::Python<'_>,
+) -> pyo3::PyResult<pyo3::Bound<'_, pyo3::types::PyFrozenSet>> {
+    let mut schemes = vec![];
+    if signature_key_type_available(openssl::pkey::Id::RSA) {
+        schemes.push("RSA");
+    }
+    if signature_key_type_available(openssl::pkey::Id::RSA_PSS) {
+        schemes.push("RSA-PSS");
+    }
+    if signature_key_type_available(openssl::pkey::Id::DSA) {
+        schemes.push("DSA");
+    }
+    if signature_key_type_available(openssl::pkey::Id::EC) {
+        schemes.push("ECDSA");
+    }
+    if signature_key_type_available(openssl::pkey::Id::ED25519) {
+        schemes.push("ED25519");
+    }
+    #[cfg(all(not(CRYPTOGRAPHY_IS_LIBRESSL), not(CRYPTOGRAPHY_IS_BORINGSSL)))]
+    if signature_key_type_available(openssl::pkey::Id::ED448) {
+        schemes.push("ED448");
+    }
+    pyo3::types::PyFrozenSet::new(py, &schemes)
+}
+
 #[cfg(CRYPTOGRAPHY_OPENSSL_300_OR_GREATER)]
 fn _initialize_providers(py: pyo3::Python<'_>) -> CryptographyResult<LoadedProviders> {
     // As of OpenSSL 3.0.0 we must register a legacy cipher provider
@@ -139,15 +210,23 @@ mod _rust {
         use crate::x509::sct::Sct;
         #[pymodule_export]
         use crate::x509::verify::{
-            PolicyBuilder, PyClientVerifier, PyCriticality, PyExtensionPolicy, PyPolicy,
-            PyServerVerifier, PyStore, PyVerifiedClient, VerificationError,
+            matching_subjects, verify_ocsp_response, verify_timestamp_token,
+            verify_timestamp_token_within_validity, verify_tls_server, OCSPVerificationError,
+            PolicyBuilder, PyClientVerifier, PyCriticality, PyExtensionPolicy,
+            PyOCSPVerificationStage, PyPolicy, PyServerVerifier, PyStore, PyTLSVerificationStage,
+            PyValidityStatus, PyVerificationStep, PyVerificationWarning, PyVerifiedClient,
+            TLSVerificationError, VerificationError,
         };
     }
 
     #[pyo3::pymodule]
     mod ocsp {
         #[pymodule_export]
-        use crate::x509::ocsp_req::{create_ocsp_request, load_der_ocsp_request, OCSPRequest};
+        use crate::x509::ocsp::py_issuer_hashes;
+        #[pymodule_export]
+        use crate::x509::ocsp_req::{
+            create_ocsp_cert_id, create_ocsp_request, load_der_ocsp_request, OCSPRequest,
+        };
         #[pymodule_export]
         use crate::x509::ocsp_resp::{
             create_ocsp_response, load_der_ocsp_response, OCSPResponse, OCSPSingleResponse,
@@ -162,7 +241,10 @@ mod _rust {
         #[pymodule_export]
         use super::super::enable_fips;
         #[pymodule_export]
-        use super::super::{is_fips_enabled, openssl_version, openssl_version_text};
+        use super::super::{
+            is_fips_enabled, openssl_version, openssl_version_text, supported_digest_oids,
+            supported_signature_algorithms,
+        };
         #[pymodule_export]
         use crate::backend::aead::aead;
         #[pymodule_export]
@@ -791,10 +791,110 @@ fn load_pkcs12<'p>(
         .call1((private_key, cert, additional_certs))?)
 }
 
+// (hash algorithm, salt, iterations)
+type PyMacData<'p> = (
+    pyo3::Bound<'p, pyo3::PyAny>,
+    pyo3::Bound<'p, pyo3::types::PyBytes>,
+    u64,
+);
+
+#[pyo3::pyfunction]
+fn load_mac_data<'p>(
+    py: pyo3::Python<'p>,
+    data: CffiBuf<'_>,
+) -> CryptographyResult<Option<PyMacData<'p>>> {
+    let pfx = asn1::parse_single::<cryptography_x509::pkcs12::PfxMacView<'_>>(data.as_bytes())
+        .map_err(|_| {
+            pyo3::exceptions::PyValueError::new_err("Could not deserialize PKCS12 data")
+        })?;
+
+    match pfx.mac_data {
+        Some(mac_data) => Ok(Some((
+            crate::pkcs7::hash_algorithm_for(py, &mac_data.mac.algorithm)?,
+            pyo3::types::PyBytes::new(py, mac_data.salt),
+            mac_data.iterations.unwrap_or(1),
+        ))),
+        None => Ok(None),
+    }
+}
+
+#[pyo3::pyfunction]
+fn verify_mac(
+    py: pyo3::Python<'_>,
+    data: CffiBuf<'_>,
+    password: Option<CffiBuf<'_>>,
+) -> CryptographyResult<Option<bool>> {
+    let pfx = asn1::parse_single::<cryptography_x509::pkcs12::PfxMacView<'_>>(data.as_bytes())
+        .map_err(|_| {
+            pyo3::exceptions::PyValueError::new_err("Could not deserialize PKCS12 data")
+        })?;
+    let Some(mac_data) = pfx.mac_data else {
+        return Ok(None);
+    };
+    let auth_safe_content = match pfx
+        .auth_safe
+        .parse::<cryptography_x509::pkcs7::ContentInfo<'_>>()
+        .map(|content_info| content_info.content)
+    {
+        Ok(cryptography_x509::pkcs7::Content::Data(Some(content))) => content.into_inner(),
+        _ => {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyValueError::new_err("Could not deserialize PKCS12 data"),
+            ))
+        }
+    };
+
+    let password = match password.as_ref() {
+        Some(p) => std::str::from_utf8(p.as_bytes())
+            .map_err(|_| pyo3::exceptions::PyUnicodeDecodeError::new_err(()))?,
+        None => "",
+    };
+    let mac_algorithm = crate::pkcs7::hash_algorithm_for(py, &mac_data.mac.algorithm)?;
+    let mac_algorithm_md = hashes::message_digest_from_algorithm(py, &mac_algorithm)?;
+    let iterations = mac_data.iterations.unwrap_or(1);
+    let id = cryptography_crypto::pkcs12::KDF_MAC_KEY_ID;
+    let mut mac_keys = vec![cryptography_crypto::pkcs12::kdf(
+        password,
+        mac_data.salt,
+        id,
+        iterations,
+        mac_algorithm_md.size(),
+        mac_algorithm_md,
+    )?];
+    // Like OpenSSL when loading, an empty password (or `password=None`) also
+    // matches a MAC computed with no password at all.
+    if password.is_empty() {
+        mac_keys.push(cryptography_crypto::pkcs12::kdf_encoded(
+            &[],
+            mac_data.salt,
+            id,
+            iterations,
+            mac_algorithm_md.size(),
+            mac_algorithm_md,
+        )?);
+    }
+
+    for mac_key in mac_keys {
+        let mac_digest = {
+            let mut h = hmac::Hmac::new_bytes(py, &mac_key, &mac_algorithm)?;
+            h.update_bytes(auth_safe_content)?;
+            h.finalize(py)?
+        };
+        let mac_digest = mac_digest.as_bytes();
+        if mac_digest.len() == mac_data.mac.digest.len()
+            && openssl::memcmp::eq(mac_digest, mac_data.mac.digest)
+        {
+            return Ok(Some(true));
+        }
+    }
+    Ok(Some(false))
+}
+
 #[pyo3::pymodule]
 pub(crate) mod pkcs12 {
     #[pymodule_export]
     use super::{
-        load_key_and_certificates, load_pkcs12, serialize_key_and_certificates, PKCS12Certificate,
+        load_key_and_certificates, load_mac_data, load_pkcs12, serialize_key_and_certificates,
+        verify_mac, PKCS12Certificate,
     };
 }
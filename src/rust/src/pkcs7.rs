@@ -17,8 +17,8 @@ use pyo3::types::{PyAnyMethods, PyBytesMethods, PyListMethods};
 #[cfg(not(CRYPTOGRAPHY_IS_BORINGSSL))]
 use pyo3::PyTypeInfo;
 
-use crate::asn1::encode_der_data;
-use crate::backend::ciphers;
+use crate::asn1::{encode_der_data, oid_to_py_oid};
+use crate::backend::{ciphers, keys};
 use crate::buf::CffiBuf;
 use crate::error::{CryptographyError, CryptographyResult};
 use crate::padding::PKCS7UnpaddingContext;
@@ -29,10 +29,13 @@ use crate::utils::cstr_from_literal;
 use crate::x509::certificate::load_der_x509_certificate;
 use crate::{exceptions, types, x509};
 
-const PKCS7_CONTENT_TYPE_OID: asn1::ObjectIdentifier = asn1::oid!(1, 2, 840, 113549, 1, 9, 3);
-const PKCS7_MESSAGE_DIGEST_OID: asn1::ObjectIdentifier = asn1::oid!(1, 2, 840, 113549, 1, 9, 4);
+pub(crate) const PKCS7_CONTENT_TYPE_OID: asn1::ObjectIdentifier =
+    asn1::oid!(1, 2, 840, 113549, 1, 9, 3);
+pub(crate) const PKCS7_MESSAGE_DIGEST_OID: asn1::ObjectIdentifier =
+    asn1::oid!(1, 2, 840, 113549, 1, 9, 4);
 const PKCS7_SIGNING_TIME_OID: asn1::ObjectIdentifier = asn1::oid!(1, 2, 840, 113549, 1, 9, 5);
-const PKCS7_SMIME_CAP_OID: asn1::ObjectIdentifier = asn1::oid!(1, 2, 840, 113549, 1, 9, 15);
+pub(crate) const PKCS7_SMIME_CAP_OID: asn1::ObjectIdentifier =
+    asn1::oid!(1, 2, 840, 113549, 1, 9, 15);
 
 static OIDS_TO_MIC_NAME: Lazy<HashMap<&asn1::ObjectIdentifier, &str>> = Lazy::new(|| {
     let mut h = HashMap::new();
@@ -455,6 +458,325 @@ pub(crate) fn symmetric_decrypt(
     Ok(unpadded_data)
 }
 
+pub(crate) fn hash_algorithm_for<'p>(
+    py: pyo3::Python<'p>,
+    algorithm: &AlgorithmIdentifier<'_>,
+) -> CryptographyResult<pyo3::Bound<'p, pyo3::PyAny>> {
+    match x509::ocsp::ALGORITHM_PARAMETERS_TO_HASH.get(&algorithm.params) {
+        Some(alg_name) => Ok(types::HASHES_MODULE.get(py)?.getattr(*alg_name)?.call0()?),
+        None => Err(CryptographyError::from(
+            exceptions::UnsupportedAlgorithm::new_err(format!(
+                "Hash algorithm OID: {} not recognized",
+                algorithm.oid()
+            )),
+        )),
+    }
+}
+
+/// CMS signers commonly identify RSA signatures with a bare `rsaEncryption`
+/// algorithm, leaving the digest to the SignerInfo's `digestAlgorithm`.
+pub(crate) fn signer_signature_algorithm<'a>(
+    digest_algorithm: &AlgorithmIdentifier<'a>,
+    signature_algorithm: &AlgorithmIdentifier<'a>,
+) -> CryptographyResult<AlgorithmIdentifier<'a>> {
+    let params = match (&signature_algorithm.params, &digest_algorithm.params) {
+        (AlgorithmParameters::Rsa(_), AlgorithmParameters::Sha224(_)) => {
+            AlgorithmParameters::RsaWithSha224(Some(()))
+        }
+        (AlgorithmParameters::Rsa(_), AlgorithmParameters::Sha256(_)) => {
+            AlgorithmParameters::RsaWithSha256(Some(()))
+        }
+        (AlgorithmParameters::Rsa(_), AlgorithmParameters::Sha384(_)) => {
+            AlgorithmParameters::RsaWithSha384(Some(()))
+        }
+        (AlgorithmParameters::Rsa(_), AlgorithmParameters::Sha512(_)) => {
+            AlgorithmParameters::RsaWithSha512(Some(()))
+        }
+        (AlgorithmParameters::Rsa(_), _) => {
+            return Err(CryptographyError::from(
+                exceptions::UnsupportedAlgorithm::new_err(format!(
+                    "Digest algorithm OID: {} not supported for RSA signatures",
+                    digest_algorithm.oid()
+                )),
+            ))
+        }
+        _ => return Ok(signature_algorithm.clone()),
+    };
+    Ok(AlgorithmIdentifier {
+        oid: asn1::DefinedByMarker::marker(),
+        params,
+    })
+}
+
+fn parse_signed_data(data: &[u8]) -> CryptographyResult<Box<pkcs7::SignedData<'_>>> {
+    match asn1::parse_single::<pkcs7::ContentInfo<'_>>(data)?.content {
+        pkcs7::Content::SignedData(signed_data) => Ok(signed_data.into_inner()),
+        _ => Err(CryptographyError::from(
+            pyo3::exceptions::PyValueError::new_err(
+                "The PKCS7 data is not a SignedData structure.",
+            ),
+        )),
+    }
+}
+
+/// Finds the SignerInfo whose issuer and serial number identify
+/// `certificate`.
+fn find_signer_info<'a>(
+    signed_data: &pkcs7::SignedData<'a>,
+    certificate: &cryptography_x509::certificate::Certificate<'_>,
+) -> CryptographyResult<pkcs7::SignerInfo<'a>> {
+    signed_data
+        .signer_infos
+        .unwrap_read()
+        .clone()
+        .find(|info| {
+            info.issuer_and_serial_number.serial_number == certificate.tbs_cert.serial
+                && info.issuer_and_serial_number.issuer == certificate.tbs_cert.issuer
+        })
+        .ok_or_else(|| {
+            CryptographyError::from(pyo3::exceptions::PyValueError::new_err(
+                "No signer found that matches the given certificate.",
+            ))
+        })
+}
+
+/// Parses a DER encoded SMIMECapabilities as a list of
+/// `(capability_id, parameters)` tuples, where `parameters` is the DER
+/// encoding of the capability's parameters, or `None`.
+pub(crate) fn parse_smime_capabilities<'p>(
+    py: pyo3::Python<'p>,
+    data: &[u8],
+) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyList>> {
+    let py_capabilities = pyo3::types::PyList::empty(py);
+    for capability in asn1::parse_single::<asn1::SequenceOf<'_, pkcs7::SMIMECapability<'_>>>(data)?
+    {
+        let py_oid = oid_to_py_oid(py, &capability.capability_id)?;
+        let py_parameters = capability
+            .parameters
+            .map(|parameters| pyo3::types::PyBytes::new(py, parameters.full_data()));
+        py_capabilities.append((py_oid, py_parameters))?;
+    }
+    Ok(py_capabilities)
+}
+
+#[pyo3::pyfunction]
+fn smime_capabilities_der<'p>(
+    py: pyo3::Python<'p>,
+    data: &[u8],
+    certificate: pyo3::Bound<'p, x509::certificate::Certificate>,
+) -> CryptographyResult<Option<pyo3::Bound<'p, pyo3::types::PyList>>> {
+    let signed_data = parse_signed_data(data)?;
+    let signer_info = find_signer_info(&signed_data, certificate.get().raw.borrow_dependent())?;
+    let capabilities = signer_info
+        .authenticated_attributes
+        .as_ref()
+        .and_then(|attributes| {
+            attributes
+                .unwrap_read()
+                .clone()
+                .find(|attribute| attribute.type_id == PKCS7_SMIME_CAP_OID)
+        })
+        .and_then(|attribute| attribute.values.unwrap_read().clone().next());
+    match capabilities {
+        Some(value) => Ok(Some(parse_smime_capabilities(py, value.full_data())?)),
+        None => Ok(None),
+    }
+}
+
+// A ContentInfo whose content is already DER encoded.
+#[derive(asn1::Asn1Write)]
+struct RawContentInfo<'a> {
+    content_type: asn1::ObjectIdentifier,
+    content: asn1::Explicit<asn1::Tlv<'a>, 0>,
+}
+
+// The default limit on the size of decompressed CompressedData content, so
+// that a small message can't expand into an unbounded amount of memory.
+const DEFAULT_MAX_DECOMPRESSED_LENGTH: usize = 16 * 1024 * 1024;
+
+#[pyo3::pyfunction]
+#[pyo3(signature = (data, max_length=DEFAULT_MAX_DECOMPRESSED_LENGTH))]
+fn decompress_der<'p>(
+    py: pyo3::Python<'p>,
+    data: &[u8],
+    max_length: usize,
+) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyBytes>> {
+    let compressed_data = match asn1::parse_single::<pkcs7::ContentInfo<'_>>(data)?.content {
+        pkcs7::Content::CompressedData(compressed_data) => compressed_data.into_inner(),
+        _ => {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyValueError::new_err(
+                    "The PKCS7 data is not a CompressedData structure.",
+                ),
+            ))
+        }
+    };
+    if compressed_data.compression_algorithm.params != AlgorithmParameters::ZlibCompress {
+        return Err(CryptographyError::from(
+            exceptions::UnsupportedAlgorithm::new_err(format!(
+                "Only zlib compression is supported, not OID {}",
+                compressed_data.compression_algorithm.oid()
+            )),
+        ));
+    }
+    let encap_content_info = &compressed_data.encap_content_info;
+    let Some(compressed) = encap_content_info.content else {
+        return Err(CryptographyError::from(
+            pyo3::exceptions::PyValueError::new_err(
+                "The CompressedData does not contain any content.",
+            ),
+        ));
+    };
+
+    // Ask for one byte more than the limit, so that content of exactly
+    // max_length bytes can be told apart from content that exceeds it.
+    let decompressor = types::ZLIB_DECOMPRESSOBJ.get(py)?.call0()?;
+    let content = match decompressor.call_method1(
+        pyo3::intern!(py, "decompress"),
+        (
+            pyo3::types::PyBytes::new(py, compressed),
+            max_length.saturating_add(1),
+        ),
+    ) {
+        Ok(content) => content.extract::<pyo3::Bound<'p, pyo3::types::PyBytes>>()?,
+        Err(e) if e.is_instance(py, &types::ZLIB_ERROR.get(py)?) => {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyValueError::new_err(
+                    "The CompressedData content could not be decompressed.",
+                ),
+            ))
+        }
+        Err(e) => return Err(e.into()),
+    };
+    if content.as_bytes().len() > max_length {
+        return Err(CryptographyError::from(
+            pyo3::exceptions::PyValueError::new_err(format!(
+                "The CompressedData content decompresses to more than {max_length} bytes."
+            )),
+        ));
+    }
+    if !decompressor
+        .getattr(pyo3::intern!(py, "eof"))?
+        .extract::<bool>()?
+    {
+        return Err(CryptographyError::from(
+            pyo3::exceptions::PyValueError::new_err("The CompressedData content is truncated."),
+        ));
+    }
+    if !decompressor
+        .getattr(pyo3::intern!(py, "unused_data"))?
+        .extract::<&[u8]>()?
+        .is_empty()
+    {
+        return Err(CryptographyError::from(
+            pyo3::exceptions::PyValueError::new_err(
+                "The CompressedData content has trailing data.",
+            ),
+        ));
+    }
+    if encap_content_info.content_type == PKCS7_DATA_OID {
+        return Ok(content);
+    }
+
+    // Any other content, such as SignedData, is wrapped in a ContentInfo so
+    // that it can be passed on to the other PKCS7 functions.
+    let content_info = RawContentInfo {
+        content_type: encap_content_info.content_type.clone(),
+        content: asn1::Explicit::new(asn1::parse_single::<asn1::Tlv<'_>>(content.as_bytes())?),
+    };
+    Ok(pyo3::types::PyBytes::new(
+        py,
+        &asn1::write_single(&content_info)?,
+    ))
+}
+
+#[pyo3::pyfunction]
+fn crls_der<'p>(
+    py: pyo3::Python<'p>,
+    data: &[u8],
+) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyList>> {
+    let signed_data = parse_signed_data(data)?;
+    let result = pyo3::types::PyList::empty(py);
+    if let Some(crls) = &signed_data.crls {
+        for crl in crls.unwrap_read().clone() {
+            let crl_der = pyo3::types::PyBytes::new(py, &asn1::write_single(&crl)?).unbind();
+            result.append(x509::crl::load_der_x509_crl(py, crl_der, None)?)?;
+        }
+    }
+    Ok(result)
+}
+
+#[pyo3::pyfunction]
+#[pyo3(signature = (data, certificate, content=None))]
+fn verify_signed_attributes_der<'p>(
+    py: pyo3::Python<'p>,
+    data: &[u8],
+    certificate: pyo3::Bound<'p, x509::certificate::Certificate>,
+    content: Option<CffiBuf<'p>>,
+) -> CryptographyResult<(bool, bool, bool)> {
+    let signed_data = parse_signed_data(data)?;
+    let content = match (&content, &signed_data.content_info.content) {
+        (Some(content), _) => content.as_bytes(),
+        (None, pkcs7::Content::Data(Some(embedded))) => embedded.as_inner(),
+        (None, _) => {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyValueError::new_err(
+                    "The SignedData structure does not contain data, so the signed content \
+                     must be provided.",
+                ),
+            ));
+        }
+    };
+
+    let signer_certificate = certificate.get().raw.borrow_dependent();
+    let signer_info = find_signer_info(&signed_data, signer_certificate)?;
+
+    // When signed attributes are present the signature covers their DER
+    // encoding, and the content is only bound through messageDigest.
+    let (has_signed_attributes, digest_matches, signed_bytes) =
+        match &signer_info.authenticated_attributes {
+            Some(attributes) => {
+                let message_digest = attributes
+                    .unwrap_read()
+                    .clone()
+                    .find(|attribute| attribute.type_id == PKCS7_MESSAGE_DIGEST_OID)
+                    .and_then(|attribute| attribute.values.unwrap_read().clone().next())
+                    .map(|value| value.parse::<&[u8]>())
+                    .transpose()?;
+                let py_digest_alg = hash_algorithm_for(py, &signer_info.digest_algorithm)?;
+                let content_digest = x509::ocsp::hash_data(py, &py_digest_alg, content)?;
+                (
+                    true,
+                    message_digest == Some(content_digest.as_bytes()),
+                    Cow::Owned(asn1::write_single(attributes)?),
+                )
+            }
+            None => (false, false, Cow::Borrowed(content)),
+        };
+
+    let signer_key =
+        keys::load_der_public_key_bytes(py, signer_certificate.tbs_cert.spki.tlv().full_data())?;
+    let signature_algorithm = signer_signature_algorithm(
+        &signer_info.digest_algorithm,
+        &signer_info.digest_encryption_algorithm,
+    )?;
+    let signature_valid = match x509::sign::verify_signature_with_signature_algorithm(
+        py,
+        signer_key,
+        &signature_algorithm,
+        signer_info.encrypted_digest,
+        &signed_bytes,
+    ) {
+        Ok(()) => true,
+        Err(CryptographyError::Py(e)) if e.is_instance_of::<exceptions::InvalidSignature>(py) => {
+            false
+        }
+        Err(e) => return Err(e),
+    };
+
+    Ok((has_signed_attributes, digest_matches, signature_valid))
+}
+
 #[pyo3::pyfunction]
 fn sign_and_serialize<'p>(
     py: pyo3::Python<'p>,
@@ -742,7 +1064,7 @@ fn load_pkcs7_certificates(
 }
 
 #[pyo3::pyfunction]
-fn load_pem_pkcs7_certificates<'p>(
+pub(crate) fn load_pem_pkcs7_certificates<'p>(
     py: pyo3::Python<'p>,
     data: &[u8],
 ) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyList>> {
@@ -772,7 +1094,7 @@ fn load_pem_pkcs7_certificates<'p>(
 }
 
 #[pyo3::pyfunction]
-fn load_der_pkcs7_certificates<'p>(
+pub(crate) fn load_der_pkcs7_certificates<'p>(
     py: pyo3::Python<'p>,
     data: &[u8],
 ) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyList>> {
@@ -809,9 +1131,9 @@ fn load_der_pkcs7_certificates<'p>(
 pub(crate) mod pkcs7_mod {
     #[pymodule_export]
     use super::{
-        decrypt_der, decrypt_pem, decrypt_smime, encrypt_and_serialize,
+        crls_der, decompress_der, decrypt_der, decrypt_pem, decrypt_smime, encrypt_and_serialize,
         load_der_pkcs7_certificates, load_pem_pkcs7_certificates, serialize_certificates,
-        sign_and_serialize,
+        sign_and_serialize, smime_capabilities_der, verify_signed_attributes_der,
     };
 }
 
@@ -33,11 +33,16 @@ impl LazyPyImport {
 }
 
 pub static DATETIME_DATETIME: LazyPyImport = LazyPyImport::new("datetime", &["datetime"]);
+pub static DATETIME_TIMEDELTA: LazyPyImport = LazyPyImport::new("datetime", &["timedelta"]);
 pub static DATETIME_TIMEZONE_UTC: LazyPyImport =
     LazyPyImport::new("datetime", &["timezone", "utc"]);
 pub static IPADDRESS_IPADDRESS: LazyPyImport = LazyPyImport::new("ipaddress", &["ip_address"]);
 pub static IPADDRESS_IPNETWORK: LazyPyImport = LazyPyImport::new("ipaddress", &["ip_network"]);
+pub static IPADDRESS_BASE: LazyPyImport = LazyPyImport::new("ipaddress", &["_IPAddressBase"]);
+pub static ENUM_ENUM: LazyPyImport = LazyPyImport::new("enum", &["Enum"]);
 pub static OS_URANDOM: LazyPyImport = LazyPyImport::new("os", &["urandom"]);
+pub static ZLIB_DECOMPRESSOBJ: LazyPyImport = LazyPyImport::new("zlib", &["decompressobj"]);
+pub static ZLIB_ERROR: LazyPyImport = LazyPyImport::new("zlib", &["error"]);
 
 pub static DEPRECATED_IN_36: LazyPyImport =
     LazyPyImport::new("cryptography.utils", &["DeprecatedIn36"]);
@@ -195,6 +200,8 @@ pub static ISSUING_DISTRIBUTION_POINT: LazyPyImport =
     LazyPyImport::new("cryptography.x509", &["IssuingDistributionPoint"]);
 pub static FRESHEST_CRL: LazyPyImport = LazyPyImport::new("cryptography.x509", &["FreshestCRL"]);
 pub static CRL_REASON: LazyPyImport = LazyPyImport::new("cryptography.x509", &["CRLReason"]);
+pub static REVOKED_CERTIFICATE_BUILDER: LazyPyImport =
+    LazyPyImport::new("cryptography.x509", &["RevokedCertificateBuilder"]);
 pub static CERTIFICATE_ISSUER: LazyPyImport =
     LazyPyImport::new("cryptography.x509", &["CertificateIssuer"]);
 pub static INVALIDITY_DATE: LazyPyImport =
@@ -221,6 +228,8 @@ pub static UNRECOGNIZED_EXTENSION: LazyPyImport =
 pub static EXTENSION: LazyPyImport = LazyPyImport::new("cryptography.x509", &["Extension"]);
 pub static EXTENSIONS: LazyPyImport = LazyPyImport::new("cryptography.x509", &["Extensions"]);
 pub static NAME: LazyPyImport = LazyPyImport::new("cryptography.x509", &["Name"]);
+pub static NAME_OID_ORGANIZATION_NAME: LazyPyImport =
+    LazyPyImport::new("cryptography.x509.oid", &["NameOID", "ORGANIZATION_NAME"]);
 pub static RELATIVE_DISTINGUISHED_NAME: LazyPyImport =
     LazyPyImport::new("cryptography.x509", &["RelativeDistinguishedName"]);
 pub static NAME_ATTRIBUTE: LazyPyImport =
@@ -264,6 +273,7 @@ pub static DNS_NAME: LazyPyImport = LazyPyImport::new("cryptography.x509", &["DN
 pub static IP_ADDRESS: LazyPyImport = LazyPyImport::new("cryptography.x509", &["IPAddress"]);
 pub static RFC822_NAME: LazyPyImport = LazyPyImport::new("cryptography.x509", &["RFC822Name"]);
 pub static OTHER_NAME: LazyPyImport = LazyPyImport::new("cryptography.x509", &["OtherName"]);
+pub static GENERAL_NAME: LazyPyImport = LazyPyImport::new("cryptography.x509", &["GeneralName"]);
 pub static CERTIFICATE_VERSION_V1: LazyPyImport =
     LazyPyImport::new("cryptography.x509", &["Version", "v1"]);
 pub static CERTIFICATE_VERSION_V3: LazyPyImport =
@@ -294,6 +304,8 @@ pub static OCSP_CERT_STATUS: LazyPyImport =
     LazyPyImport::new("cryptography.x509.ocsp", &["OCSPCertStatus"]);
 pub static OCSP_CERT_STATUS_GOOD: LazyPyImport =
     LazyPyImport::new("cryptography.x509.ocsp", &["OCSPCertStatus", "GOOD"]);
+pub static OCSP_CERT_STATUS_REVOKED: LazyPyImport =
+    LazyPyImport::new("cryptography.x509.ocsp", &["OCSPCertStatus", "REVOKED"]);
 pub static OCSP_CERT_STATUS_UNKNOWN: LazyPyImport =
     LazyPyImport::new("cryptography.x509.ocsp", &["OCSPCertStatus", "UNKNOWN"]);
 pub static OCSP_RESPONDER_ENCODING_HASH: LazyPyImport =
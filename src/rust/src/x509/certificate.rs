@@ -13,14 +13,17 @@ use cryptography_x509::extensions::{
     Extension, IssuerAlternativeName, KeyUsage, MSCertificateTemplate, NameConstraints,
     NamingAuthority, PolicyConstraints, PolicyInformation, PolicyQualifierInfo, ProfessionInfo,
     Qualifier, RawExtensions, SequenceOfAccessDescriptions, SequenceOfSubtrees,
-    SubjectAlternativeName, UserNotice,
+    SubjectAlternativeName, TnAuthList, TnEntry, UserNotice,
 };
-use cryptography_x509::{common, oid};
+use cryptography_x509::{common, name, oid};
+use cryptography_x509_verification::certificate::cert_is_self_issued;
 use cryptography_x509_verification::ops::CryptoOps;
-use pyo3::types::{PyAnyMethods, PyListMethods};
+use cryptography_x509_verification::types::DNSPattern;
+use pyo3::types::{PyAnyMethods, PyDictMethods, PyListMethods, PyTypeMethods};
 
 use crate::asn1::{
-    big_byte_slice_to_py_int, encode_der_data, oid_to_py_oid, py_uint_to_big_endian_bytes,
+    big_byte_slice_to_py_int, encode_der_data, oid_to_py_oid, py_oid_to_oid,
+    py_uint_to_big_endian_bytes,
 };
 use crate::backend::{hashes, keys};
 use crate::error::{CryptographyError, CryptographyResult};
@@ -44,6 +47,84 @@ pub(crate) struct Certificate {
     pub(crate) cached_extensions: pyo3::sync::GILOnceCell<pyo3::PyObject>,
 }
 
+impl Certificate {
+    pub(crate) fn tbs_without_extensions(
+        &self,
+        py: pyo3::Python<'_>,
+        remove: &[asn1::ObjectIdentifier],
+        missing_message: &'static str,
+    ) -> CryptographyResult<Vec<u8>> {
+        let val = self.raw.borrow_dependent();
+        let mut tbs_precert = val.tbs_cert.clone();
+        match val.extensions() {
+            Ok(extensions) => {
+                let ext_count = extensions
+                    .as_raw()
+                    .as_ref()
+                    .map_or(0, |raw| raw.unwrap_read().len());
+                let filtered_extensions: Vec<Extension<'_>> = extensions
+                    .iter()
+                    .filter(|x| !remove.contains(&x.extn_id))
+                    .collect();
+                if filtered_extensions.len() == ext_count {
+                    return Err(CryptographyError::from(
+                        pyo3::exceptions::PyValueError::new_err(missing_message),
+                    ));
+                }
+                let filtered_extensions: RawExtensions<'_> = Asn1ReadableOrWritable::new_write(
+                    asn1::SequenceOfWriter::new(filtered_extensions),
+                );
+
+                tbs_precert.raw_extensions = Some(filtered_extensions);
+                Ok(asn1::write_single(&tbs_precert)?)
+            }
+            Err(DuplicateExtensionsError(oid)) => {
+                let oid_obj = oid_to_py_oid(py, &oid)?;
+                Err(exceptions::DuplicateExtension::new_err((
+                    format!("Duplicate {} extension found", &oid),
+                    oid_obj.unbind(),
+                ))
+                .into())
+            }
+        }
+    }
+
+    fn check_signature_algorithms_match(&self) -> CryptographyResult<()> {
+        if self.raw.borrow_dependent().tbs_cert.signature_alg
+            != self.raw.borrow_dependent().signature_alg
+        {
+            return Err(CryptographyError::from(pyo3::exceptions::PyValueError::new_err(
+                "Inner and outer signature algorithms do not match. This is an invalid certificate."
+            )));
+        };
+        Ok(())
+    }
+
+    pub(crate) fn get_extension(
+        &self,
+        py: pyo3::Python<'_>,
+        extn_id: &asn1::ObjectIdentifier,
+    ) -> CryptographyResult<Option<Extension<'_>>> {
+        match self.raw.borrow_dependent().extensions() {
+            Ok(extensions) => Ok(extensions.get_extension(extn_id)),
+            Err(DuplicateExtensionsError(oid)) => {
+                let oid_obj = oid_to_py_oid(py, &oid)?;
+                Err(exceptions::DuplicateExtension::new_err((
+                    format!("Duplicate {} extension found", &oid),
+                    oid_obj.unbind(),
+                ))
+                .into())
+            }
+        }
+    }
+
+    /// The SHA-256 hash of the certificate's SubjectPublicKeyInfo, which is
+    /// how RFC 6962 identifies a precertificate's issuer.
+    pub(crate) fn ct_issuer_key_hash_bytes(&self) -> [u8; 32] {
+        openssl::sha::sha256(self.raw.borrow_dependent().tbs_cert.spki.tlv().full_data())
+    }
+}
+
 #[pyo3::pymethods]
 impl Certificate {
     fn __hash__(&self) -> u64 {
@@ -140,6 +221,80 @@ impl Certificate {
             .map_err(|e| e.add_location(asn1::ParseLocation::Field("subject")))?)
     }
 
+    #[getter]
+    fn issuer_attributes<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+    ) -> pyo3::PyResult<pyo3::Bound<'p, pyo3::types::PyList>> {
+        Ok(
+            x509::parse_name_attributes(py, self.raw.borrow_dependent().issuer())
+                .map_err(|e| e.add_location(asn1::ParseLocation::Field("issuer")))?,
+        )
+    }
+
+    #[getter]
+    fn subject_attributes<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+    ) -> pyo3::PyResult<pyo3::Bound<'p, pyo3::types::PyList>> {
+        Ok(
+            x509::parse_name_attributes(py, self.raw.borrow_dependent().subject())
+                .map_err(|e| e.add_location(asn1::ParseLocation::Field("subject")))?,
+        )
+    }
+
+    #[getter]
+    fn smime_capabilities<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+    ) -> CryptographyResult<Option<pyo3::Bound<'p, pyo3::types::PyList>>> {
+        match self.get_extension(py, &crate::pkcs7::PKCS7_SMIME_CAP_OID)? {
+            Some(ext) => Ok(Some(crate::pkcs7::parse_smime_capabilities(
+                py,
+                ext.extn_value,
+            )?)),
+            None => Ok(None),
+        }
+    }
+
+    #[getter]
+    fn issuer_bytes<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+    ) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyBytes>> {
+        let result = asn1::write_single(self.raw.borrow_dependent().issuer())?;
+        Ok(pyo3::types::PyBytes::new(py, &result))
+    }
+
+    #[getter]
+    fn subject_bytes<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+    ) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyBytes>> {
+        let result = asn1::write_single(self.raw.borrow_dependent().subject())?;
+        Ok(pyo3::types::PyBytes::new(py, &result))
+    }
+
+    #[getter]
+    fn not_valid_before_bytes<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+    ) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyBytes>> {
+        let validity = &self.raw.borrow_dependent().tbs_cert.validity;
+        let result = asn1::write_single(&validity.not_before)?;
+        Ok(pyo3::types::PyBytes::new(py, &result))
+    }
+
+    #[getter]
+    fn not_valid_after_bytes<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+    ) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyBytes>> {
+        let validity = &self.raw.borrow_dependent().tbs_cert.validity;
+        let result = asn1::write_single(&validity.not_after)?;
+        Ok(pyo3::types::PyBytes::new(py, &result))
+    }
+
     #[getter]
     fn tbs_certificate_bytes<'p>(
         &self,
@@ -149,48 +304,47 @@ impl Certificate {
         Ok(pyo3::types::PyBytes::new(py, &result))
     }
 
+    #[getter]
+    fn der_field_spans<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+    ) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyDict>> {
+        let data = self.raw.borrow_owner().as_bytes(py);
+        let certificate = asn1::parse_single::<asn1::Tlv<'_>>(data)?;
+        let fields = asn1::parse(certificate.data(), |p| {
+            Ok::<_, asn1::ParseError>([
+                p.read_element::<asn1::Tlv<'_>>()?,
+                p.read_element::<asn1::Tlv<'_>>()?,
+                p.read_element::<asn1::Tlv<'_>>()?,
+            ])
+        })?;
+
+        let spans = pyo3::types::PyDict::new(py);
+        spans.set_item(pyo3::intern!(py, "certificate"), (0, data.len()))?;
+        // The fields follow the outer SEQUENCE's tag and length.
+        let mut offset = certificate.full_data().len() - certificate.data().len();
+        for (name, field) in ["tbs_certificate", "signature_algorithm", "signature"]
+            .into_iter()
+            .zip(fields)
+        {
+            let length = field.full_data().len();
+            spans.set_item(name, (offset, length))?;
+            offset += length;
+        }
+        Ok(spans)
+    }
+
     #[getter]
     fn tbs_precertificate_bytes<'p>(
         &self,
         py: pyo3::Python<'p>,
     ) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyBytes>> {
-        let val = self.raw.borrow_dependent();
-        let mut tbs_precert = val.tbs_cert.clone();
-        // Remove the SCT list extension
-        match val.extensions() {
-            Ok(extensions) => {
-                let ext_count = extensions
-                    .as_raw()
-                    .as_ref()
-                    .map_or(0, |raw| raw.unwrap_read().len());
-                let filtered_extensions: Vec<Extension<'_>> = extensions
-                    .iter()
-                    .filter(|x| x.extn_id != oid::PRECERT_SIGNED_CERTIFICATE_TIMESTAMPS_OID)
-                    .collect();
-                if filtered_extensions.len() == ext_count {
-                    return Err(CryptographyError::from(
-                        pyo3::exceptions::PyValueError::new_err(
-                            "Could not find pre-certificate SCT list extension",
-                        ),
-                    ));
-                }
-                let filtered_extensions: RawExtensions<'_> = Asn1ReadableOrWritable::new_write(
-                    asn1::SequenceOfWriter::new(filtered_extensions),
-                );
-
-                tbs_precert.raw_extensions = Some(filtered_extensions);
-                let result = asn1::write_single(&tbs_precert)?;
-                Ok(pyo3::types::PyBytes::new(py, &result))
-            }
-            Err(DuplicateExtensionsError(oid)) => {
-                let oid_obj = oid_to_py_oid(py, &oid)?;
-                Err(exceptions::DuplicateExtension::new_err((
-                    format!("Duplicate {} extension found", &oid),
-                    oid_obj.unbind(),
-                ))
-                .into())
-            }
-        }
+        let result = self.tbs_without_extensions(
+            py,
+            &[oid::PRECERT_SIGNED_CERTIFICATE_TIMESTAMPS_OID],
+            "Could not find pre-certificate SCT list extension",
+        )?;
+        Ok(pyo3::types::PyBytes::new(py, &result))
     }
 
     #[getter]
@@ -198,6 +352,27 @@ impl Certificate {
         pyo3::types::PyBytes::new(py, self.raw.borrow_dependent().signature.as_bytes())
     }
 
+    #[getter]
+    fn ecdsa_signature_components<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+    ) -> CryptographyResult<Option<(pyo3::Bound<'p, pyo3::PyAny>, pyo3::Bound<'p, pyo3::PyAny>)>>
+    {
+        let cert = self.raw.borrow_dependent();
+        if !matches!(
+            sign::identify_key_type_for_algorithm_params(&cert.signature_alg.params),
+            Ok(sign::KeyType::Ec)
+        ) {
+            return Ok(None);
+        }
+
+        let sig = asn1::parse_single::<common::DssSignature<'_>>(cert.signature.as_bytes())?;
+        Ok(Some((
+            big_byte_slice_to_py_int(py, sig.r.as_bytes())?,
+            big_byte_slice_to_py_int(py, sig.s.as_bytes())?,
+        )))
+    }
+
     #[getter]
     fn not_valid_before<'p>(
         &self,
@@ -264,6 +439,25 @@ impl Certificate {
         x509::datetime_to_py_utc(py, dt)
     }
 
+    fn time_until_expiry<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+        now: pyo3::Bound<'p, pyo3::PyAny>,
+    ) -> pyo3::PyResult<pyo3::Bound<'p, pyo3::PyAny>> {
+        // Naive datetimes are treated as UTC, matching `PolicyBuilder.time`.
+        let now = if now.getattr(pyo3::intern!(py, "tzinfo"))?.is_none() {
+            let kwargs = pyo3::types::PyDict::new(py);
+            kwargs.set_item(
+                pyo3::intern!(py, "tzinfo"),
+                types::DATETIME_TIMEZONE_UTC.get(py)?,
+            )?;
+            now.call_method(pyo3::intern!(py, "replace"), (), Some(&kwargs))?
+        } else {
+            now
+        };
+        self.not_valid_after_utc(py)?.sub(now)
+    }
+
     #[getter]
     fn signature_hash_algorithm<'p>(
         &self,
@@ -297,36 +491,487 @@ impl Certificate {
             py,
             &self.cached_extensions,
             &self.raw.borrow_dependent().tbs_cert.raw_extensions,
-            |ext| match ext.extn_id {
-                oid::PRECERT_POISON_OID => {
-                    ext.value::<()>()?;
-                    Ok(Some(types::PRECERT_POISON.get(py)?.call0()?))
+            |ext| parse_cert_or_precert_ext(py, ext),
+        )
+    }
+
+    #[getter]
+    fn extension_count(&self) -> usize {
+        x509::raw_extension_oids(&self.raw.borrow_dependent().tbs_cert.raw_extensions).len()
+    }
+
+    fn has_extension(&self, oid: pyo3::Bound<'_, pyo3::PyAny>) -> pyo3::PyResult<bool> {
+        let oid = py_oid_to_oid(oid)?;
+        Ok(
+            x509::raw_extension_oids(&self.raw.borrow_dependent().tbs_cert.raw_extensions)
+                .contains(&oid),
+        )
+    }
+
+    fn extension_bytes<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+        oid: pyo3::Bound<'p, pyo3::PyAny>,
+    ) -> CryptographyResult<
+        Option<(
+            pyo3::Bound<'p, pyo3::types::PyBytes>,
+            pyo3::Bound<'p, pyo3::types::PyBytes>,
+        )>,
+    > {
+        let oid = py_oid_to_oid(oid)?;
+        let Some(ext) = self.get_extension(py, &oid)? else {
+            return Ok(None);
+        };
+        // extnValue is an OCTET STRING wrapping the extension's DER.
+        let wrapped = asn1::write_single(&ext.extn_value)?;
+        Ok(Some((
+            pyo3::types::PyBytes::new(py, &wrapped),
+            pyo3::types::PyBytes::new(py, ext.extn_value),
+        )))
+    }
+
+    fn unrecognized_extension_oids<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+    ) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyList>> {
+        let oids = pyo3::types::PyList::empty(py);
+        for ext in self.extensions(py)?.bind(py).try_iter()? {
+            let ext = ext?;
+            if ext
+                .getattr(pyo3::intern!(py, "value"))?
+                .is_instance(&types::UNRECOGNIZED_EXTENSION.get(py)?)?
+            {
+                oids.append(ext.getattr(pyo3::intern!(py, "oid"))?)?;
+            }
+        }
+        Ok(oids)
+    }
+
+    #[getter]
+    fn acme_identifier<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+    ) -> CryptographyResult<Option<pyo3::Bound<'p, pyo3::types::PyBytes>>> {
+        let Some(ext) = self.get_extension(py, &oid::ACME_IDENTIFIER_OID)? else {
+            return Ok(None);
+        };
+        // RFC 8737 Section 3: Authorization ::= OCTET STRING (SIZE (32))
+        let digest = ext.value::<&[u8]>()?;
+        if digest.len() != 32 {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyValueError::new_err(
+                    "The acmeIdentifier extension must contain a 32 byte SHA-256 digest.",
+                ),
+            ));
+        }
+        Ok(Some(pyo3::types::PyBytes::new(py, digest)))
+    }
+
+    #[getter]
+    fn is_acme_challenge(&self, py: pyo3::Python<'_>) -> CryptographyResult<bool> {
+        Ok(self.get_extension(py, &oid::ACME_IDENTIFIER_OID)?.is_some())
+    }
+
+    #[getter]
+    fn tn_auth_list<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+    ) -> CryptographyResult<Option<pyo3::Bound<'p, pyo3::types::PyList>>> {
+        let Some(ext) = self.get_extension(py, &oid::TN_AUTH_LIST_OID)? else {
+            return Ok(None);
+        };
+        let entries = pyo3::types::PyList::empty(py);
+        for entry in ext.value::<TnAuthList<'_>>()? {
+            match entry {
+                TnEntry::Spc(spc) => entries.append(("spc", spc.as_str()))?,
+                TnEntry::Range(range) => {
+                    entries.append(("range", (range.start.as_str(), range.count)))?
                 }
-                oid::PRECERT_SIGNED_CERTIFICATE_TIMESTAMPS_OID => {
-                    let contents = ext.value::<&[u8]>()?;
-                    let scts = sct::parse_scts(py, contents, sct::LogEntryType::PreCertificate)?;
-                    Ok(Some(
-                        types::PRECERTIFICATE_SIGNED_CERTIFICATE_TIMESTAMPS
-                            .get(py)?
-                            .call1((scts,))?,
-                    ))
+                TnEntry::One(number) => entries.append(("one", number.as_str()))?,
+            }
+        }
+        Ok(Some(entries))
+    }
+
+    #[getter]
+    fn ocsp_responder_urls<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+    ) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyList>> {
+        // The AIA is where RFC 5280 puts the responders, but some CA
+        // certificates only advertise them in their SIA.
+        let urls = pyo3::types::PyList::empty(py);
+        for extn_id in [
+            oid::AUTHORITY_INFORMATION_ACCESS_OID,
+            oid::SUBJECT_INFORMATION_ACCESS_OID,
+        ] {
+            let Some(ext) = self.get_extension(py, &extn_id)? else {
+                continue;
+            };
+            let ads = ext.value::<SequenceOfAccessDescriptions<'_, Asn1Read>>()?;
+            for ad in ads {
+                match ad.access_location {
+                    name::GeneralName::UniformResourceIdentifier(uri)
+                        if ad.access_method == oid::AD_OCSP_OID =>
+                    {
+                        urls.append(pyo3::types::PyString::new(py, uri.0))?;
+                    }
+                    _ => {}
                 }
-                _ => parse_cert_ext(py, ext),
-            },
-        )
+            }
+            if !urls.is_empty() {
+                break;
+            }
+        }
+        Ok(urls)
+    }
+
+    #[getter]
+    fn timestamping_urls<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+    ) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyList>> {
+        let urls = pyo3::types::PyList::empty(py);
+        if let Some(ext) = self.get_extension(py, &oid::SUBJECT_INFORMATION_ACCESS_OID)? {
+            let ads = ext.value::<SequenceOfAccessDescriptions<'_, Asn1Read>>()?;
+            for ad in ads {
+                match ad.access_location {
+                    name::GeneralName::UniformResourceIdentifier(uri)
+                        if ad.access_method == oid::AD_TIME_STAMPING_OID =>
+                    {
+                        urls.append(pyo3::types::PyString::new(py, uri.0))?;
+                    }
+                    _ => {}
+                }
+            }
+        }
+        Ok(urls)
+    }
+
+    #[getter]
+    fn general_names<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+    ) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyList>> {
+        let extensions = match self.raw.borrow_dependent().extensions() {
+            Ok(extensions) => extensions,
+            Err(DuplicateExtensionsError(oid)) => {
+                let oid_obj = oid_to_py_oid(py, &oid)?;
+                return Err(exceptions::DuplicateExtension::new_err((
+                    format!("Duplicate {} extension found", &oid),
+                    oid_obj.unbind(),
+                ))
+                .into());
+            }
+        };
+
+        let names = pyo3::types::PyList::empty(py);
+        for ext in extensions.iter() {
+            let gns: Vec<name::GeneralName<'_>> = match ext.extn_id {
+                oid::SUBJECT_ALTERNATIVE_NAME_OID => {
+                    ext.value::<SubjectAlternativeName<'_>>()?.collect()
+                }
+                oid::ISSUER_ALTERNATIVE_NAME_OID => {
+                    ext.value::<IssuerAlternativeName<'_>>()?.collect()
+                }
+                oid::CRL_DISTRIBUTION_POINTS_OID | oid::FRESHEST_CRL_OID => {
+                    let mut gns = vec![];
+                    for dp in
+                        ext.value::<asn1::SequenceOf<'_, DistributionPoint<'_, Asn1Read>>>()?
+                    {
+                        if let Some(DistributionPointName::FullName(full_name)) =
+                            dp.distribution_point
+                        {
+                            gns.extend(full_name);
+                        }
+                        if let Some(crl_issuer) = dp.crl_issuer {
+                            gns.extend(crl_issuer);
+                        }
+                    }
+                    gns
+                }
+                oid::AUTHORITY_INFORMATION_ACCESS_OID | oid::SUBJECT_INFORMATION_ACCESS_OID => ext
+                    .value::<SequenceOfAccessDescriptions<'_, Asn1Read>>()?
+                    .map(|ad| ad.access_location)
+                    .collect(),
+                oid::NAME_CONSTRAINTS_OID => {
+                    let nc = ext.value::<NameConstraints<'_, Asn1Read>>()?;
+                    nc.permitted_subtrees
+                        .into_iter()
+                        .chain(nc.excluded_subtrees)
+                        .flatten()
+                        .map(|subtree| subtree.base)
+                        .collect()
+                }
+                _ => continue,
+            };
+            let py_oid = oid_to_py_oid(py, &ext.extn_id)?;
+            for gn in gns {
+                names.append((py_oid.clone(), x509::parse_general_name(py, gn)?))?;
+            }
+        }
+        Ok(names)
+    }
+
+    #[getter]
+    fn capabilities<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+    ) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyFrozenSet>> {
+        let is_ca = match self.get_extension(py, &oid::BASIC_CONSTRAINTS_OID)? {
+            Some(ext) => ext.value::<BasicConstraints>()?.ca,
+            None => false,
+        };
+        let key_usage = self
+            .get_extension(py, &oid::KEY_USAGE_OID)?
+            .map(|ext| ext.value::<KeyUsage<'_>>())
+            .transpose()?;
+        let ekus = match self.get_extension(py, &oid::EXTENDED_KEY_USAGE_OID)? {
+            Some(ext) => ext.value::<ExtendedKeyUsage<'_>>()?.collect(),
+            None => vec![],
+        };
+        // An absent keyUsage doesn't restrict the key, but an absent
+        // extendedKeyUsage doesn't assert any purpose either.
+        let ku_allows = |f: fn(&KeyUsage<'_>) -> bool| key_usage.as_ref().map_or(true, f);
+        let has_eku = |eku: &asn1::ObjectIdentifier| ekus.contains(eku);
+
+        let mut capabilities = vec![];
+        // RFC 5280 requires CA certificates to carry a keyUsage, so these
+        // are only granted when it is present.
+        if is_ca && key_usage.as_ref().map_or(false, |ku| ku.key_cert_sign()) {
+            capabilities.push("cert_sign");
+        }
+        if key_usage.as_ref().map_or(false, |ku| ku.crl_sign()) {
+            capabilities.push("crl_sign");
+        }
+        if !is_ca {
+            // Matches what server verification requires of a leaf's
+            // keyUsage: a key that only asserts keyAgreement can't
+            // authenticate a TLS handshake.
+            if has_eku(&oid::EKU_SERVER_AUTH_OID)
+                && ku_allows(|ku| ku.digital_signature() || ku.key_encipherment())
+            {
+                capabilities.push("tls_server");
+            }
+            if has_eku(&oid::EKU_CLIENT_AUTH_OID)
+                && ku_allows(|ku| ku.digital_signature() || ku.key_agreement())
+            {
+                capabilities.push("tls_client");
+            }
+            if has_eku(&oid::EKU_EMAIL_PROTECTION_OID)
+                && ku_allows(|ku| {
+                    ku.digital_signature()
+                        || ku.content_commitment()
+                        || ku.key_encipherment()
+                        || ku.key_agreement()
+                })
+            {
+                capabilities.push("email_protection");
+            }
+        }
+        Ok(pyo3::types::PyFrozenSet::new(py, capabilities)?)
+    }
+
+    #[getter]
+    fn dns_name_patterns<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+    ) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyList>> {
+        let patterns = pyo3::types::PyList::empty(py);
+        let san = match self.get_extension(py, &oid::SUBJECT_ALTERNATIVE_NAME_OID)? {
+            Some(ext) => ext.value::<SubjectAlternativeName<'_>>()?,
+            None => return Ok(patterns),
+        };
+        for gn in san {
+            if let name::GeneralName::DNSName(dns_name) = gn {
+                let value = dns_name.0;
+                // Patterns are validated the same way as during path
+                // validation: only a single leading "*." wildcard label is
+                // permitted, so anything else has no base domain.
+                let base_domain = DNSPattern::new(value).map(|p| p.inner_name().as_str());
+                patterns.append((value, value.contains('*'), base_domain))?;
+            }
+        }
+        Ok(patterns)
+    }
+
+    #[getter]
+    fn ct_issuer_key_hash<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+    ) -> pyo3::Bound<'p, pyo3::types::PyBytes> {
+        pyo3::types::PyBytes::new(py, &self.ct_issuer_key_hash_bytes())
+    }
+
+    fn precertificate_signed_data<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+        issuer: pyo3::PyRef<'_, Certificate>,
+        sct: pyo3::PyRef<'_, sct::Sct>,
+    ) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyBytes>> {
+        // Either a precertificate carrying the poison extension or a final
+        // certificate with embedded SCTs; a log signs over the TBSCertificate
+        // with those extensions removed.
+        let tbs_precert = self.tbs_without_extensions(
+            py,
+            &[
+                oid::PRECERT_POISON_OID,
+                oid::PRECERT_SIGNED_CERTIFICATE_TIMESTAMPS_OID,
+            ],
+            "Could not find pre-certificate poison or SCT list extension",
+        )?;
+        let result =
+            sct.precertificate_signed_data(&issuer.ct_issuer_key_hash_bytes(), &tbs_precert)?;
+        Ok(pyo3::types::PyBytes::new(py, &result))
+    }
+
+    fn signature_algorithm_matches_key(
+        &self,
+        py: pyo3::Python<'_>,
+        public_key: pyo3::Bound<'_, pyo3::PyAny>,
+    ) -> CryptographyResult<bool> {
+        let key_type = sign::identify_public_key_type(py, public_key)?;
+        let sig_key_type = sign::identify_key_type_for_algorithm_params(
+            &self.raw.borrow_dependent().signature_alg.params,
+        )?;
+        Ok(key_type == sig_key_type)
+    }
+
+    fn to_dict<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+    ) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyDict>> {
+        let extensions = match self.raw.borrow_dependent().extensions() {
+            Ok(extensions) => extensions,
+            Err(DuplicateExtensionsError(oid)) => {
+                let oid_obj = oid_to_py_oid(py, &oid)?;
+                return Err(exceptions::DuplicateExtension::new_err((
+                    format!("Duplicate {} extension found", &oid),
+                    oid_obj.unbind(),
+                ))
+                .into());
+            }
+        };
+        let py_extensions = pyo3::types::PyList::empty(py);
+        for ext in extensions.iter() {
+            // Extensions that aren't modeled, or that fail to parse, are
+            // represented by the hex of their DER encoded value.
+            let parsed = match parse_cert_or_precert_ext(py, &ext) {
+                Ok(parsed) => parsed,
+                Err(CryptographyError::Asn1Parse(_)) => None,
+                Err(CryptographyError::Py(e))
+                    if e.is_instance_of::<pyo3::exceptions::PyValueError>(py) =>
+                {
+                    None
+                }
+                Err(e) => return Err(e),
+            };
+            let py_value = match parsed {
+                Some(value) => json_extension(py, &ext.extn_id, &value)?,
+                None => None,
+            };
+            let py_value = match py_value {
+                Some(py_value) => py_value,
+                None => pyo3::types::PyBytes::new(py, ext.extn_value)
+                    .call_method0(pyo3::intern!(py, "hex"))?,
+            };
+            let ext_oid = oid_to_py_oid(py, &ext.extn_id)?;
+            let py_ext = pyo3::types::PyDict::new(py);
+            py_ext.set_item("oid", json_value(py, &ext_oid)?)?;
+            py_ext.set_item("name", ext_oid.getattr(pyo3::intern!(py, "_name"))?)?;
+            py_ext.set_item("critical", ext.critical)?;
+            py_ext.set_item("value", py_value)?;
+            py_extensions.append(py_ext)?;
+        }
+
+        let result = pyo3::types::PyDict::new(py);
+        result.set_item(
+            "version",
+            self.version(py)?.getattr(pyo3::intern!(py, "name"))?,
+        )?;
+        result.set_item("serial_number", self.serial_number(py)?)?;
+        result.set_item(
+            "signature_algorithm",
+            json_value(py, &self.signature_algorithm_oid(py)?)?,
+        )?;
+        result.set_item(
+            "issuer",
+            json_name_attributes(py, &self.issuer_attributes(py)?)?,
+        )?;
+        result.set_item(
+            "not_valid_before",
+            json_value(py, &self.not_valid_before_utc(py)?)?,
+        )?;
+        result.set_item(
+            "not_valid_after",
+            json_value(py, &self.not_valid_after_utc(py)?)?,
+        )?;
+        result.set_item(
+            "subject",
+            json_name_attributes(py, &self.subject_attributes(py)?)?,
+        )?;
+        result.set_item(
+            "public_key_algorithm",
+            json_value(py, &self.public_key_algorithm_oid(py)?)?,
+        )?;
+        result.set_item("extensions", py_extensions)?;
+        Ok(result)
+    }
+
+    fn issuer_matches(
+        &self,
+        py: pyo3::Python<'_>,
+        name: pyo3::Bound<'_, pyo3::PyAny>,
+    ) -> CryptographyResult<bool> {
+        let py_name = if name.is_instance_of::<pyo3::types::PyString>() {
+            types::NAME
+                .get(py)?
+                .call_method1(pyo3::intern!(py, "from_rfc4514_string"), (name,))?
+        } else if name.is_instance(&types::NAME.get(py)?)? {
+            name
+        } else {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyTypeError::new_err(
+                    "name must be a Name or an RFC 4514 string.",
+                ),
+            ));
+        };
+        let ka = cryptography_keepalive::KeepAlive::new();
+        let encoded = asn1::write_single(&x509::common::encode_name(py, &ka, &py_name)?)?;
+        let expected = asn1::parse_single::<name::NameReadable<'_>>(&encoded)?;
+        Ok(x509::common::names_match(
+            self.raw.borrow_dependent().issuer(),
+            &expected,
+        ))
+    }
+
+    fn is_self_issued(&self) -> bool {
+        cert_is_self_issued(self.raw.borrow_dependent())
+    }
+
+    fn is_self_signed(&self, py: pyo3::Python<'_>) -> CryptographyResult<bool> {
+        if !self.is_self_issued() || self.check_signature_algorithms_match().is_err() {
+            return Ok(false);
+        }
+
+        let ops = PyCryptoOps {};
+        let key = ops.public_key(self.raw.borrow_dependent())?;
+        match ops.verify_signed_by(self.raw.borrow_dependent(), &key) {
+            Ok(()) => Ok(true),
+            Err(CryptographyError::Py(e))
+                if e.is_instance_of::<exceptions::InvalidSignature>(py) =>
+            {
+                Ok(false)
+            }
+            Err(e) => Err(e),
+        }
     }
 
     fn verify_directly_issued_by(
         &self,
         issuer: pyo3::PyRef<'_, Certificate>,
     ) -> CryptographyResult<()> {
-        if self.raw.borrow_dependent().tbs_cert.signature_alg
-            != self.raw.borrow_dependent().signature_alg
-        {
-            return Err(CryptographyError::from(pyo3::exceptions::PyValueError::new_err(
-                "Inner and outer signature algorithms do not match. This is an invalid certificate."
-            )));
-        };
+        self.check_signature_algorithms_match()?;
         if self.raw.borrow_dependent().tbs_cert.issuer
             != issuer.raw.borrow_dependent().tbs_cert.subject
         {
@@ -341,6 +986,260 @@ impl Certificate {
         let issuer_key = ops.public_key(issuer.raw.borrow_dependent())?;
         ops.verify_signed_by(self.raw.borrow_dependent(), &issuer_key)
     }
+
+    fn verify_signature_with_spki(
+        &self,
+        py: pyo3::Python<'_>,
+        spki: &[u8],
+    ) -> CryptographyResult<()> {
+        self.check_signature_algorithms_match()?;
+        let issuer_key = keys::load_der_public_key_bytes(py, spki)?;
+        PyCryptoOps {}.verify_signed_by(self.raw.borrow_dependent(), &issuer_key.unbind())
+    }
+}
+
+/// Converts the output of `parse_name_attributes` into lists of dicts.
+fn json_name_attributes<'p>(
+    py: pyo3::Python<'p>,
+    rdns: &pyo3::Bound<'p, pyo3::types::PyList>,
+) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyList>> {
+    let py_rdns = pyo3::types::PyList::empty(py);
+    for rdn in rdns {
+        let py_rdn = pyo3::types::PyList::empty(py);
+        for attribute in rdn.try_iter()? {
+            let (attr_oid, value, asn1_type) = attribute?.extract::<(
+                pyo3::Bound<'_, pyo3::PyAny>,
+                pyo3::Bound<'_, pyo3::PyAny>,
+                pyo3::Bound<'_, pyo3::PyAny>,
+            )>()?;
+            let py_attribute = pyo3::types::PyDict::new(py);
+            py_attribute.set_item("oid", json_value(py, &attr_oid)?)?;
+            py_attribute.set_item("name", attr_oid.getattr(pyo3::intern!(py, "_name"))?)?;
+            py_attribute.set_item("value", json_value(py, &value)?)?;
+            py_attribute.set_item("type", asn1_type.getattr(pyo3::intern!(py, "name"))?)?;
+            py_rdn.append(py_attribute)?;
+        }
+        py_rdns.append(py_rdn)?;
+    }
+    Ok(py_rdns)
+}
+
+/// The attributes of the objects nested in parsed extension values that
+/// `json_value` converts into dicts.
+static JSON_OBJECT_FIELDS: [(&types::LazyPyImport, &[&str]); 8] = [
+    (
+        &types::ACCESS_DESCRIPTION,
+        &["access_method", "access_location"],
+    ),
+    (
+        &types::DISTRIBUTION_POINT,
+        &["full_name", "relative_name", "reasons", "crl_issuer"],
+    ),
+    (
+        &types::POLICY_INFORMATION,
+        &["policy_identifier", "policy_qualifiers"],
+    ),
+    (&types::USER_NOTICE, &["notice_reference", "explicit_text"]),
+    (
+        &types::NOTICE_REFERENCE,
+        &["organization", "notice_numbers"],
+    ),
+    (
+        &types::ADMISSION,
+        &[
+            "admission_authority",
+            "naming_authority",
+            "profession_infos",
+        ],
+    ),
+    (&types::NAMING_AUTHORITY, &["id", "url", "text"]),
+    (
+        &types::PROFESSION_INFO,
+        &[
+            "naming_authority",
+            "profession_items",
+            "profession_oids",
+            "registration_number",
+            "add_profession_info",
+        ],
+    ),
+];
+
+const SCT_FIELDS: &[&str] = &[
+    "version",
+    "log_id",
+    "timestamp",
+    "entry_type",
+    "signature_hash_algorithm",
+    "signature_algorithm",
+    "signature",
+    "extension_bytes",
+];
+
+/// Converts the parsed value of the extension `oid`, as returned by
+/// `parse_cert_or_precert_ext`, into nested dicts and lists suitable for
+/// `json.dumps`. Returns `None` for extensions without a mapping.
+fn json_extension<'p>(
+    py: pyo3::Python<'p>,
+    oid: &asn1::ObjectIdentifier,
+    value: &pyo3::Bound<'p, pyo3::PyAny>,
+) -> CryptographyResult<Option<pyo3::Bound<'p, pyo3::PyAny>>> {
+    let fields: &[&str] = match *oid {
+        oid::SUBJECT_ALTERNATIVE_NAME_OID
+        | oid::ISSUER_ALTERNATIVE_NAME_OID
+        | oid::TLS_FEATURE_OID
+        | oid::EXTENDED_KEY_USAGE_OID
+        | oid::AUTHORITY_INFORMATION_ACCESS_OID
+        | oid::SUBJECT_INFORMATION_ACCESS_OID
+        | oid::CERTIFICATE_POLICIES_OID
+        | oid::CRL_DISTRIBUTION_POINTS_OID
+        | oid::FRESHEST_CRL_OID
+        | oid::PRECERT_SIGNED_CERTIFICATE_TIMESTAMPS_OID => {
+            return Ok(Some(json_list(py, value)?));
+        }
+        oid::SUBJECT_KEY_IDENTIFIER_OID => &["digest"],
+        // `encipher_only` and `decipher_only` are only defined when
+        // `key_agreement` is asserted.
+        oid::KEY_USAGE_OID
+            if value
+                .getattr(pyo3::intern!(py, "key_agreement"))?
+                .is_truthy()? =>
+        {
+            &[
+                "digital_signature",
+                "content_commitment",
+                "key_encipherment",
+                "data_encipherment",
+                "key_agreement",
+                "key_cert_sign",
+                "crl_sign",
+                "encipher_only",
+                "decipher_only",
+            ]
+        }
+        oid::KEY_USAGE_OID => &[
+            "digital_signature",
+            "content_commitment",
+            "key_encipherment",
+            "data_encipherment",
+            "key_agreement",
+            "key_cert_sign",
+            "crl_sign",
+        ],
+        oid::POLICY_CONSTRAINTS_OID => &["require_explicit_policy", "inhibit_policy_mapping"],
+        oid::OCSP_NO_CHECK_OID | oid::PRECERT_POISON_OID => &[],
+        oid::INHIBIT_ANY_POLICY_OID => &["skip_certs"],
+        oid::BASIC_CONSTRAINTS_OID => &["ca", "path_length"],
+        oid::AUTHORITY_KEY_IDENTIFIER_OID => &[
+            "key_identifier",
+            "authority_cert_issuer",
+            "authority_cert_serial_number",
+        ],
+        oid::NAME_CONSTRAINTS_OID => &["permitted_subtrees", "excluded_subtrees"],
+        oid::MS_CERTIFICATE_TEMPLATE => &["template_id", "major_version", "minor_version"],
+        oid::ADMISSIONS_OID => {
+            let py_dict = json_fields(py, value, &["authority"])?;
+            py_dict.set_item("admissions", json_list(py, value)?)?;
+            return Ok(Some(py_dict.into_any()));
+        }
+        _ => return Ok(None),
+    };
+    Ok(Some(json_fields(py, value, fields)?.into_any()))
+}
+
+/// Converts the `fields` attributes of `value` into a dict.
+fn json_fields<'p>(
+    py: pyo3::Python<'p>,
+    value: &pyo3::Bound<'p, pyo3::PyAny>,
+    fields: &[&str],
+) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyDict>> {
+    let py_dict = pyo3::types::PyDict::new(py);
+    for field in fields {
+        py_dict.set_item(*field, json_value(py, &value.getattr(*field)?)?)?;
+    }
+    Ok(py_dict)
+}
+
+/// Converts a value nested in a parsed extension, or one of the
+/// certificate's fields, into strings, integers, booleans, `None`, and
+/// lists and dicts of them, suitable for `json.dumps`. General names also
+/// record their type.
+fn json_value<'p>(
+    py: pyo3::Python<'p>,
+    value: &pyo3::Bound<'p, pyo3::PyAny>,
+) -> CryptographyResult<pyo3::Bound<'p, pyo3::PyAny>> {
+    if value.is_none()
+        || value.is_instance_of::<pyo3::types::PyBool>()
+        || value.is_instance_of::<pyo3::types::PyInt>()
+        || value.is_instance_of::<pyo3::types::PyString>()
+    {
+        return Ok(value.clone());
+    }
+    if value.is_instance_of::<pyo3::types::PyBytes>() {
+        return Ok(value.call_method0(pyo3::intern!(py, "hex"))?);
+    }
+    if value.is_instance_of::<crate::oid::ObjectIdentifier>() {
+        return Ok(value.getattr(pyo3::intern!(py, "dotted_string"))?);
+    }
+    if value.is_instance(&types::DATETIME_DATETIME.get(py)?)? {
+        return Ok(value.call_method0(pyo3::intern!(py, "isoformat"))?);
+    }
+    if value.is_instance(&types::NAME.get(py)?)?
+        || value.is_instance(&types::RELATIVE_DISTINGUISHED_NAME.get(py)?)?
+    {
+        return Ok(value.call_method0(pyo3::intern!(py, "rfc4514_string"))?);
+    }
+    if value.is_instance(&types::HASH_ALGORITHM.get(py)?)? {
+        return Ok(value.getattr(pyo3::intern!(py, "name"))?);
+    }
+    if value.is_instance(&types::ENUM_ENUM.get(py)?)? {
+        return json_value(py, &value.getattr(pyo3::intern!(py, "value"))?);
+    }
+    if value.is_instance(&types::IPADDRESS_BASE.get(py)?)? {
+        return Ok(value.str()?.into_any());
+    }
+    if value.is_instance_of::<pyo3::types::PyList>()
+        || value.is_instance_of::<pyo3::types::PyTuple>()
+        || value.is_instance_of::<pyo3::types::PyFrozenSet>()
+        || value.is_instance_of::<pyo3::types::PySet>()
+    {
+        return json_list(py, value);
+    }
+    if value.is_instance(&types::GENERAL_NAME.get(py)?)? {
+        let fields: &[&str] = if value.is_instance(&types::OTHER_NAME.get(py)?)? {
+            &["type_id", "value"]
+        } else {
+            &["value"]
+        };
+        let py_dict = json_fields(py, value, fields)?;
+        py_dict.set_item("type", value.get_type().name()?)?;
+        return Ok(py_dict.into_any());
+    }
+    if value.is_instance_of::<sct::Sct>() {
+        return Ok(json_fields(py, value, SCT_FIELDS)?.into_any());
+    }
+    for (py_type, fields) in &JSON_OBJECT_FIELDS {
+        if value.is_instance(&py_type.get(py)?)? {
+            return Ok(json_fields(py, value, fields)?.into_any());
+        }
+    }
+    Err(CryptographyError::from(
+        pyo3::exceptions::PyTypeError::new_err(format!(
+            "Cannot convert {} to JSON",
+            value.get_type().name()?
+        )),
+    ))
+}
+
+fn json_list<'p>(
+    py: pyo3::Python<'p>,
+    value: &pyo3::Bound<'p, pyo3::PyAny>,
+) -> CryptographyResult<pyo3::Bound<'p, pyo3::PyAny>> {
+    let py_list = pyo3::types::PyList::empty(py);
+    for item in value.try_iter()? {
+        py_list.append(json_value(py, &item?)?)?;
+    }
+    Ok(py_list.into_any())
 }
 
 fn cert_version(
@@ -450,7 +1349,8 @@ fn warn_if_invalid_params(
     params: AlgorithmParameters<'_>,
 ) -> pyo3::PyResult<()> {
     match params {
-        AlgorithmParameters::EcDsaWithSha224(Some(..))
+        AlgorithmParameters::EcDsaWithSha1(Some(..))
+        | AlgorithmParameters::EcDsaWithSha224(Some(..))
         | AlgorithmParameters::EcDsaWithSha256(Some(..))
         | AlgorithmParameters::EcDsaWithSha384(Some(..))
         | AlgorithmParameters::EcDsaWithSha512(Some(..))
@@ -795,6 +1695,30 @@ fn parse_admissions<'p, 'a>(
     Ok(py_admissions.into_any())
 }
 
+/// Parses a certificate's extension, including those only found in
+/// pre-certificates.
+fn parse_cert_or_precert_ext<'p>(
+    py: pyo3::Python<'p>,
+    ext: &Extension<'p>,
+) -> CryptographyResult<Option<pyo3::Bound<'p, pyo3::PyAny>>> {
+    match ext.extn_id {
+        oid::PRECERT_POISON_OID => {
+            ext.value::<()>()?;
+            Ok(Some(types::PRECERT_POISON.get(py)?.call0()?))
+        }
+        oid::PRECERT_SIGNED_CERTIFICATE_TIMESTAMPS_OID => {
+            let contents = ext.value::<&[u8]>()?;
+            let scts = sct::parse_scts(py, contents, sct::LogEntryType::PreCertificate)?;
+            Ok(Some(
+                types::PRECERTIFICATE_SIGNED_CERTIFICATE_TIMESTAMPS
+                    .get(py)?
+                    .call1((scts,))?,
+            ))
+        }
+        _ => parse_cert_ext(py, ext),
+    }
+}
+
 pub fn parse_cert_ext<'p>(
     py: pyo3::Python<'p>,
     ext: &Extension<'p>,
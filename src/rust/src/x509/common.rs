@@ -97,6 +97,83 @@ pub(crate) fn encode_name_bytes<'p>(
     Ok(pyo3::types::PyBytes::new(py, &result))
 }
 
+/// Decodes a directory string attribute value and prepares it for
+/// comparison: case is folded and insignificant whitespace is removed, an
+/// approximation of the RFC 4518 string preparation required by RFC 5280
+/// Section 7.1. Returns `None` for values that aren't directory strings.
+fn prepare_name_value(value: &RawTlv<'_>) -> Option<String> {
+    let data = value.data();
+    let decoded = match value.tag().as_u8() {
+        // BMPString
+        Some(30) => {
+            let units = data
+                .chunks(2)
+                .map(|c| c.try_into().map(u16::from_be_bytes))
+                .collect::<Result<Vec<_>, _>>()
+                .ok()?;
+            String::from_utf16(&units).ok()?
+        }
+        // UniversalString
+        Some(28) => data
+            .chunks(4)
+            .map(|c| {
+                c.try_into()
+                    .ok()
+                    .map(u32::from_be_bytes)
+                    .and_then(char::from_u32)
+            })
+            .collect::<Option<String>>()?,
+        // UTF8String, PrintableString, TeletexString and IA5String. As when
+        // parsing names, TeletexStrings are decoded as UTF-8.
+        Some(12 | 19 | 20 | 22) => std::str::from_utf8(data).ok()?.to_string(),
+        _ => return None,
+    };
+    Some(
+        decoded
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase(),
+    )
+}
+
+fn name_values_match(a: &AttributeTypeValue<'_>, b: &AttributeTypeValue<'_>) -> bool {
+    if a.type_id != b.type_id {
+        return false;
+    }
+    match (prepare_name_value(&a.value), prepare_name_value(&b.value)) {
+        (Some(a), Some(b)) => a == b,
+        _ => a.value.tag() == b.value.tag() && a.value.data() == b.value.data(),
+    }
+}
+
+/// Compares two distinguished names as described in RFC 5280 Section 7.1:
+/// RDNs must match in order, while the attributes of a multi-valued RDN may
+/// appear in any order.
+pub(crate) fn names_match(a: &NameReadable<'_>, b: &NameReadable<'_>) -> bool {
+    let (a_rdns, b_rdns) = (a.clone().collect::<Vec<_>>(), b.clone().collect::<Vec<_>>());
+    if a_rdns.len() != b_rdns.len() {
+        return false;
+    }
+    a_rdns.into_iter().zip(b_rdns).all(|(a_rdn, b_rdn)| {
+        let a_attrs = a_rdn.collect::<Vec<_>>();
+        let mut b_attrs = b_rdn.collect::<Vec<_>>();
+        a_attrs.len() == b_attrs.len()
+            && a_attrs.iter().all(|a_attr| {
+                match b_attrs
+                    .iter()
+                    .position(|b_attr| name_values_match(a_attr, b_attr))
+                {
+                    Some(i) => {
+                        b_attrs.remove(i);
+                        true
+                    }
+                    None => false,
+                }
+            })
+    })
+}
+
 pub(crate) fn encode_general_names<'a>(
     py: pyo3::Python<'_>,
     ka_bytes: &'a cryptography_keepalive::KeepAlive<pyo3::pybacked::PyBackedBytes>,
@@ -192,10 +269,27 @@ pub(crate) fn parse_name<'p>(
     Ok(types::NAME.get(py)?.call1((py_rdns,))?)
 }
 
-fn parse_name_attribute<'p>(
+/// Parses a name as a list of RDNs, each a list of
+/// `(oid, value, _ASN1Type)` tuples that keep the attribute's string type.
+pub(crate) fn parse_name_attributes<'p>(
+    py: pyo3::Python<'p>,
+    name: &NameReadable<'_>,
+) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyList>> {
+    let py_rdns = pyo3::types::PyList::empty(py);
+    for rdn in name.clone() {
+        let py_attrs = pyo3::types::PyList::empty(py);
+        for attribute in rdn {
+            py_attrs.append(name_attribute_parts(py, attribute)?)?;
+        }
+        py_rdns.append(py_attrs)?;
+    }
+    Ok(py_rdns)
+}
+
+fn name_attribute_parts<'p>(
     py: pyo3::Python<'p>,
     attribute: AttributeTypeValue<'_>,
-) -> CryptographyResult<pyo3::Bound<'p, pyo3::PyAny>> {
+) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyTuple>> {
     let oid = oid_to_py_oid(py, &attribute.type_id)?;
     let tag_val = attribute.value.tag().as_u8().ok_or_else(|| {
         CryptographyError::from(pyo3::exceptions::PyValueError::new_err(
@@ -222,10 +316,20 @@ fn parse_name_attribute<'p>(
             pyo3::types::PyString::new(py, parsed).into_any()
         }
     };
+    Ok(pyo3::types::PyTuple::new(
+        py,
+        [oid.into_any(), py_data, py_tag],
+    )?)
+}
+
+fn parse_name_attribute<'p>(
+    py: pyo3::Python<'p>,
+    attribute: AttributeTypeValue<'_>,
+) -> CryptographyResult<pyo3::Bound<'p, pyo3::PyAny>> {
     let kwargs = [(pyo3::intern!(py, "_validate"), false)].into_py_dict(py)?;
     Ok(types::NAME_ATTRIBUTE
         .get(py)?
-        .call((oid, py_data, py_tag), Some(&kwargs))?)
+        .call(name_attribute_parts(py, attribute)?, Some(&kwargs))?)
 }
 
 pub(crate) fn parse_rdn<'a>(
@@ -351,6 +455,21 @@ fn ipv6_netmask(num: u128) -> Result<u32, CryptographyError> {
     Ok((!num).leading_zeros())
 }
 
+/// The extension OIDs in `raw_extensions`, in order. The extension values
+/// aren't parsed and duplicates aren't rejected, so this never fails.
+pub(crate) fn raw_extension_oids(
+    raw_extensions: &Option<RawExtensions<'_>>,
+) -> Vec<asn1::ObjectIdentifier> {
+    match raw_extensions {
+        Some(raw_extensions) => raw_extensions
+            .unwrap_read()
+            .clone()
+            .map(|ext| ext.extn_id)
+            .collect(),
+        None => vec![],
+    }
+}
+
 pub(crate) fn parse_and_cache_extensions<
     'p,
     F: Fn(&Extension<'p>) -> Result<Option<pyo3::Bound<'p, pyo3::PyAny>>, CryptographyError>,
@@ -2,6 +2,7 @@
 // 2.0, and the BSD License. See the LICENSE file in the root of this repository
 // for complete details.
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use cryptography_x509::common::{self, Asn1Read};
@@ -9,12 +10,16 @@ use cryptography_x509::crl::{
     self, CertificateRevocationList as RawCertificateRevocationList,
     RevokedCertificate as RawRevokedCertificate,
 };
-use cryptography_x509::extensions::{Extension, IssuerAlternativeName};
+use cryptography_x509::extensions::{
+    BasicConstraints, DuplicateExtensionsError, Extension, Extensions, IssuerAlternativeName,
+    RawExtensions,
+};
 use cryptography_x509::{name, oid};
 use pyo3::types::{PyAnyMethods, PyListMethods, PySliceMethods};
 
 use crate::asn1::{
-    big_byte_slice_to_py_int, encode_der_data, oid_to_py_oid, py_uint_to_big_endian_bytes,
+    big_byte_slice_to_py_int, encode_der_data, oid_to_py_oid, py_oid_to_oid,
+    py_uint_to_big_endian_bytes,
 };
 use crate::backend::hashes::Hash;
 use crate::error::{CryptographyError, CryptographyResult};
@@ -48,6 +53,7 @@ pub(crate) fn load_der_x509_crl(
     Ok(CertificateRevocationList {
         owned: Arc::new(owned),
         revoked_certs: pyo3::sync::GILOnceCell::new(),
+        revoked_certs_sorted: pyo3::sync::GILOnceCell::new(),
         cached_extensions: pyo3::sync::GILOnceCell::new(),
     })
 }
@@ -86,6 +92,7 @@ pub(crate) struct CertificateRevocationList {
     owned: Arc<OwnedCertificateRevocationList>,
 
     revoked_certs: pyo3::sync::GILOnceCell<Vec<OwnedRevokedCertificate>>,
+    revoked_certs_sorted: pyo3::sync::GILOnceCell<bool>,
     cached_extensions: pyo3::sync::GILOnceCell<pyo3::PyObject>,
 }
 
@@ -94,13 +101,122 @@ impl CertificateRevocationList {
         Ok(asn1::write_single(self.owned.borrow_dependent())?)
     }
 
+    fn revoked_certs(&self, py: pyo3::Python<'_>) -> &[OwnedRevokedCertificate] {
+        self.revoked_certs.get_or_init(py, || {
+            let mut revoked_certs = vec![];
+            let mut it = self.__iter__();
+            while let Some(c) = it.__next__() {
+                revoked_certs.push(c.owned);
+            }
+            revoked_certs
+        })
+    }
+
+    fn revoked_serials(&self, py: pyo3::Python<'_>) -> HashSet<&[u8]> {
+        self.revoked_certs(py)
+            .iter()
+            .map(|c| c.borrow_dependent().user_certificate.as_bytes())
+            .collect()
+    }
+
+    /// Returns the CRL's CRL Number, with any leading zero bytes removed,
+    /// or `None` if it has no CRL Number extension.
+    fn crl_number(&self, py: pyo3::Python<'_>) -> CryptographyResult<Option<&[u8]>> {
+        let tbs_cert_list = &self.owned.borrow_dependent().tbs_cert_list;
+        match Extensions::from_raw_extensions(tbs_cert_list.raw_crl_extensions.as_ref()) {
+            Ok(extensions) => match extensions.get_extension(&oid::CRL_NUMBER_OID) {
+                Some(ext) => {
+                    let number = ext.value::<asn1::BigUint<'_>>()?.as_bytes();
+                    let start = number.iter().position(|b| *b != 0).unwrap_or(number.len());
+                    Ok(Some(&number[start..]))
+                }
+                None => Ok(None),
+            },
+            Err(DuplicateExtensionsError(oid)) => {
+                let oid_obj = oid_to_py_oid(py, &oid)?;
+                Err(exceptions::DuplicateExtension::new_err((
+                    format!("Duplicate {} extension found", &oid),
+                    oid_obj.unbind(),
+                ))
+                .into())
+            }
+        }
+    }
+
     fn revoked_cert(&self, py: pyo3::Python<'_>, idx: usize) -> RevokedCertificate {
         RevokedCertificate {
-            owned: self.revoked_certs.get(py).unwrap()[idx].clone(),
+            owned: self.revoked_certs(py)[idx].clone(),
             cached_extensions: pyo3::sync::GILOnceCell::new(),
         }
     }
 
+    /// Finds the first entry for `serial_bytes`. This is a binary search if
+    /// the entries are in ascending serial order (checked once, on first
+    /// use) and a linear scan otherwise.
+    fn find_revoked_cert(
+        &self,
+        py: pyo3::Python<'_>,
+        serial_bytes: &[u8],
+    ) -> Option<RevokedCertificate> {
+        let certs = self.revoked_certs(py);
+        let sorted = *self.revoked_certs_sorted.get_or_init(py, || {
+            certs
+                .windows(2)
+                .all(|w| serial_sort_key(&w[0]) <= serial_sort_key(&w[1]))
+        });
+        let idx = if sorted {
+            let key = (serial_bytes.len(), serial_bytes);
+            let idx = certs.partition_point(|c| serial_sort_key(c) < key);
+            Some(idx).filter(|&idx| idx < certs.len() && serial_sort_key(&certs[idx]) == key)
+        } else {
+            certs
+                .iter()
+                .position(|c| c.borrow_dependent().user_certificate.as_bytes() == serial_bytes)
+        };
+        idx.map(|idx| self.revoked_cert(py, idx))
+    }
+
+    /// Returns the CRL's issuing distribution point, or `None` if it has no
+    /// IssuingDistributionPoint extension.
+    fn issuing_distribution_point(
+        &self,
+        py: pyo3::Python<'_>,
+    ) -> CryptographyResult<Option<crl::IssuingDistributionPoint<'_, Asn1Read>>> {
+        let tbs_cert_list = &self.owned.borrow_dependent().tbs_cert_list;
+        let extensions = parse_crl_extensions(py, tbs_cert_list.raw_crl_extensions.as_ref())?;
+        match extensions.get_extension(&oid::ISSUING_DISTRIBUTION_POINT_OID) {
+            Some(ext) => Ok(Some(ext.value()?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the indices of the entries for certificates from `issuer`.
+    /// Per RFC 5280 section 5.3.3, an entry's certificate issuer is named by
+    /// its certificateIssuer extension or, without one, is that of the
+    /// preceding entry, and the first entry's defaults to the CRL's issuer.
+    fn entries_issued_by(
+        &self,
+        py: pyo3::Python<'_>,
+        issuer: &name::Name<'_>,
+    ) -> CryptographyResult<Vec<usize>> {
+        let mut from_issuer = self.owned.borrow_dependent().tbs_cert_list.issuer == *issuer;
+        let mut indices = vec![];
+        for (idx, entry) in self.revoked_certs(py).iter().enumerate() {
+            let raw_extensions = &entry.borrow_dependent().raw_crl_entry_extensions;
+            let extensions = parse_crl_extensions(py, raw_extensions.as_ref())?;
+            if let Some(ext) = extensions.get_extension(&oid::CERTIFICATE_ISSUER_OID) {
+                let gns = ext.value::<asn1::SequenceOf<'_, name::GeneralName<'_>>>()?;
+                from_issuer = gns.into_iter().any(
+                    |gn| matches!(gn, name::GeneralName::DirectoryName(name) if name == *issuer),
+                );
+            }
+            if from_issuer {
+                indices.push(idx);
+            }
+        }
+        Ok(indices)
+    }
+
     fn len(&self) -> usize {
         self.owned
             .borrow_dependent()
@@ -111,6 +227,26 @@ impl CertificateRevocationList {
     }
 }
 
+#[derive(pyo3::FromPyObject)]
+enum SerialNumberOrCertificate<'a> {
+    SerialNumber(pyo3::Bound<'a, pyo3::types::PyInt>),
+    Certificate(pyo3::Bound<'a, certificate::Certificate>),
+}
+
+fn parse_crl_extensions<'a>(
+    py: pyo3::Python<'_>,
+    raw_extensions: Option<&RawExtensions<'a>>,
+) -> CryptographyResult<Extensions<'a>> {
+    Extensions::from_raw_extensions(raw_extensions).or_else(|DuplicateExtensionsError(oid)| {
+        let oid_obj = oid_to_py_oid(py, &oid)?;
+        Err(exceptions::DuplicateExtension::new_err((
+            format!("Duplicate {} extension found", &oid),
+            oid_obj.unbind(),
+        ))
+        .into())
+    })
+}
+
 #[pyo3::pymethods]
 impl CertificateRevocationList {
     fn __eq__(&self, other: pyo3::PyRef<'_, CertificateRevocationList>) -> bool {
@@ -141,15 +277,6 @@ impl CertificateRevocationList {
         py: pyo3::Python<'p>,
         idx: pyo3::Bound<'_, pyo3::PyAny>,
     ) -> pyo3::PyResult<pyo3::Bound<'p, pyo3::PyAny>> {
-        self.revoked_certs.get_or_init(py, || {
-            let mut revoked_certs = vec![];
-            let mut it = self.__iter__();
-            while let Some(c) = it.__next__() {
-                revoked_certs.push(c.owned);
-            }
-            revoked_certs
-        });
-
         if idx.is_instance_of::<pyo3::types::PySlice>() {
             let indices = idx
                 .downcast::<pyo3::types::PySlice>()?
@@ -379,27 +506,42 @@ impl CertificateRevocationList {
         serial: pyo3::Bound<'_, pyo3::types::PyInt>,
     ) -> pyo3::PyResult<Option<RevokedCertificate>> {
         let serial_bytes = py_uint_to_big_endian_bytes(py, serial)?;
-        let owned = OwnedRevokedCertificate::try_new(Arc::clone(&self.owned), |v| {
-            let certs = match &v.borrow_dependent().tbs_cert_list.revoked_certificates {
-                Some(certs) => certs.unwrap_read().clone(),
-                None => return Err(()),
-            };
-
-            // TODO: linear scan. Make a hash or bisect!
-            for cert in certs {
-                if serial_bytes == cert.user_certificate.as_bytes() {
-                    return Ok(cert);
-                }
+        Ok(self.find_revoked_cert(py, &serial_bytes))
+    }
+
+    fn is_revoked(
+        &self,
+        py: pyo3::Python<'_>,
+        serial_number: SerialNumberOrCertificate<'_>,
+    ) -> CryptographyResult<Option<RevokedCertificate>> {
+        let certificate = match serial_number {
+            SerialNumberOrCertificate::SerialNumber(serial) => {
+                return Ok(self.get_revoked_certificate_by_serial_number(py, serial)?)
             }
-            Err(())
-        });
-        match owned {
-            Ok(o) => Ok(Some(RevokedCertificate {
-                owned: o,
-                cached_extensions: pyo3::sync::GILOnceCell::new(),
-            })),
-            Err(()) => Ok(None),
+            SerialNumberOrCertificate::Certificate(certificate) => certificate,
+        };
+        if !self.applies_to(py, certificate.borrow())? {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyValueError::new_err("The CRL does not cover this certificate."),
+            ));
         }
+        let cert = certificate.get().raw.borrow_dependent();
+        let serial_bytes = cert.tbs_cert.serial.as_bytes();
+        let indirect = self
+            .issuing_distribution_point(py)?
+            .map_or(false, |idp| idp.indirect_crl);
+        if !indirect {
+            return Ok(self.find_revoked_cert(py, serial_bytes));
+        }
+
+        // Only the entries for the certificate's issuer are searched, as an
+        // indirect CRL also lists other issuers' certificates.
+        let certs = self.revoked_certs(py);
+        Ok(self
+            .entries_issued_by(py, &cert.tbs_cert.issuer)?
+            .into_iter()
+            .find(|&idx| certs[idx].borrow_dependent().user_certificate.as_bytes() == serial_bytes)
+            .map(|idx| self.revoked_cert(py, idx)))
     }
 
     fn is_signature_valid<'p>(
@@ -426,6 +568,143 @@ impl CertificateRevocationList {
         )
         .is_ok())
     }
+
+    #[getter]
+    fn extension_count(&self) -> usize {
+        x509::raw_extension_oids(
+            &self
+                .owned
+                .borrow_dependent()
+                .tbs_cert_list
+                .raw_crl_extensions,
+        )
+        .len()
+    }
+
+    fn has_extension(&self, oid: pyo3::Bound<'_, pyo3::PyAny>) -> pyo3::PyResult<bool> {
+        let oid = py_oid_to_oid(oid)?;
+        Ok(x509::raw_extension_oids(
+            &self
+                .owned
+                .borrow_dependent()
+                .tbs_cert_list
+                .raw_crl_extensions,
+        )
+        .contains(&oid))
+    }
+
+    fn applies_to(
+        &self,
+        py: pyo3::Python<'_>,
+        certificate: pyo3::PyRef<'_, certificate::Certificate>,
+    ) -> CryptographyResult<bool> {
+        let idp = self.issuing_distribution_point(py)?;
+        let issuer = &certificate.raw.borrow_dependent().tbs_cert.issuer;
+        if self.owned.borrow_dependent().tbs_cert_list.issuer != *issuer {
+            // An indirect CRL also covers the certificates of the issuers
+            // named in its entries' certificateIssuer extensions.
+            let indirect = idp.as_ref().map_or(false, |idp| idp.indirect_crl);
+            if !indirect || self.entries_issued_by(py, issuer)?.is_empty() {
+                return Ok(false);
+            }
+        }
+
+        let idp = match idp {
+            Some(idp) => idp,
+            None => return Ok(true),
+        };
+        if idp.only_contains_attribute_certs {
+            return Ok(false);
+        }
+        let is_ca = match certificate.get_extension(py, &oid::BASIC_CONSTRAINTS_OID)? {
+            Some(ext) => ext.value::<BasicConstraints>()?.ca,
+            None => false,
+        };
+        Ok(!(idp.only_contains_user_certs && is_ca || idp.only_contains_ca_certs && !is_ca))
+    }
+
+    fn covered_reasons<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+        certificate: pyo3::PyRef<'_, certificate::Certificate>,
+    ) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyFrozenSet>> {
+        if !self.applies_to(py, certificate)? {
+            return Ok(pyo3::types::PyFrozenSet::empty(py)?);
+        }
+        let reasons = self
+            .issuing_distribution_point(py)?
+            .and_then(|idp| idp.only_some_reasons);
+        let reason_bit_mapping = types::REASON_BIT_MAPPING.get(py)?;
+        let mut covered = vec![];
+        for i in 1..=8 {
+            if reasons.as_ref().map_or(true, |bs| bs.has_bit_set(i)) {
+                covered.push(reason_bit_mapping.get_item(i)?);
+            }
+        }
+        Ok(pyo3::types::PyFrozenSet::new(py, &covered)?)
+    }
+
+    fn compare_crl_number(
+        &self,
+        py: pyo3::Python<'_>,
+        other: pyo3::PyRef<'_, CertificateRevocationList>,
+    ) -> CryptographyResult<Option<i32>> {
+        if self.owned.borrow_dependent().tbs_cert_list.issuer
+            != other.owned.borrow_dependent().tbs_cert_list.issuer
+        {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyValueError::new_err(
+                    "CRLs from different issuers cannot be compared.",
+                ),
+            ));
+        }
+        let (Some(number), Some(other_number)) = (self.crl_number(py)?, other.crl_number(py)?)
+        else {
+            return Ok(None);
+        };
+        // Both numbers are big-endian with no leading zeros, so the longer
+        // one is larger.
+        let ordering = number
+            .len()
+            .cmp(&other_number.len())
+            .then_with(|| number.cmp(other_number));
+        Ok(Some(ordering as i32))
+    }
+
+    #[pyo3(signature = (newer, *, require_newer_crl_number = false))]
+    fn revocation_changes<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+        newer: pyo3::Bound<'_, CertificateRevocationList>,
+        require_newer_crl_number: bool,
+    ) -> CryptographyResult<(
+        pyo3::Bound<'p, pyo3::types::PySet>,
+        pyo3::Bound<'p, pyo3::types::PySet>,
+    )> {
+        let ordering = self.compare_crl_number(py, newer.borrow())?;
+        if require_newer_crl_number && ordering != Some(-1) {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyValueError::new_err(
+                    "The newer CRL must have a greater CRL Number.",
+                ),
+            ));
+        }
+
+        let old_serials = self.revoked_serials(py);
+        let new_serials = newer.get().revoked_serials(py);
+        let to_py_set = |serials: Vec<&[u8]>| {
+            pyo3::types::PySet::new(
+                py,
+                serials
+                    .into_iter()
+                    .map(|serial| big_byte_slice_to_py_int(py, serial))
+                    .collect::<pyo3::PyResult<Vec<_>>>()?,
+            )
+        };
+        let added = to_py_set(new_serials.difference(&old_serials).copied().collect())?;
+        let removed = to_py_set(old_serials.difference(&new_serials).copied().collect())?;
+        Ok((added, removed))
+    }
 }
 
 type RawCRLIterator<'a> = Option<asn1::SequenceOf<'a, crl::RevokedCertificate<'a>>>;
@@ -517,6 +796,13 @@ impl Clone for OwnedRevokedCertificate {
     }
 }
 
+/// Serials are DER encoded non-negative integers, so ordering by length and
+/// then by bytes orders them numerically.
+fn serial_sort_key(cert: &OwnedRevokedCertificate) -> (usize, &[u8]) {
+    let serial = cert.borrow_dependent().user_certificate.as_bytes();
+    (serial.len(), serial)
+}
+
 #[pyo3::pyclass(frozen, module = "cryptography.hazmat.bindings._rust.x509")]
 pub(crate) struct RevokedCertificate {
     owned: OwnedRevokedCertificate,
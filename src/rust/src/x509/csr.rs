@@ -32,6 +32,22 @@ pub(crate) struct CertificateSigningRequest {
     cached_extensions: pyo3::sync::GILOnceCell<pyo3::PyObject>,
 }
 
+impl CertificateSigningRequest {
+    fn raw_extensions(
+        &self,
+    ) -> pyo3::PyResult<Option<cryptography_x509::extensions::RawExtensions<'_>>> {
+        self.raw
+            .borrow_dependent()
+            .csr_info
+            .get_extension_attribute()
+            .map_err(|_| {
+                pyo3::exceptions::PyValueError::new_err(
+                    "Only single-valued attributes are supported",
+                )
+            })
+    }
+}
+
 #[pyo3::pymethods]
 impl CertificateSigningRequest {
     fn __hash__(&self, py: pyo3::Python<'_>) -> u64 {
@@ -205,22 +221,23 @@ impl CertificateSigningRequest {
 
     #[getter]
     fn extensions(&self, py: pyo3::Python<'_>) -> pyo3::PyResult<pyo3::PyObject> {
-        let raw_exts = self
-            .raw
-            .borrow_dependent()
-            .csr_info
-            .get_extension_attribute()
-            .map_err(|_| {
-                pyo3::exceptions::PyValueError::new_err(
-                    "Only single-valued attributes are supported",
-                )
-            })?;
+        let raw_exts = self.raw_extensions()?;
 
         x509::parse_and_cache_extensions(py, &self.cached_extensions, &raw_exts, |ext| {
             certificate::parse_cert_ext(py, ext)
         })
     }
 
+    #[getter]
+    fn extension_count(&self) -> pyo3::PyResult<usize> {
+        Ok(x509::raw_extension_oids(&self.raw_extensions()?).len())
+    }
+
+    fn has_extension(&self, oid: pyo3::Bound<'_, pyo3::PyAny>) -> pyo3::PyResult<bool> {
+        let oid = py_oid_to_oid(oid)?;
+        Ok(x509::raw_extension_oids(&self.raw_extensions()?).contains(&oid))
+    }
+
     #[getter]
     fn is_signature_valid(&self, py: pyo3::Python<'_>) -> CryptographyResult<bool> {
         let public_key = self.public_key(py)?;
@@ -16,5 +16,6 @@ pub(crate) mod verify;
 
 pub(crate) use common::{
     datetime_to_py, datetime_to_py_utc, find_in_pem, parse_and_cache_extensions,
-    parse_general_name, parse_general_names, parse_name, parse_rdn, py_to_datetime,
+    parse_general_name, parse_general_names, parse_name, parse_name_attributes, parse_rdn,
+    py_to_datetime, raw_extension_oids,
 };
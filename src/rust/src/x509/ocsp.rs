@@ -80,11 +80,9 @@ pub(crate) fn certid_new<'p>(
     hash_algorithm: &pyo3::Bound<'p, pyo3::PyAny>,
 ) -> CryptographyResult<CertID<'p>> {
     let issuer_der = asn1::write_single(&cert.raw.borrow_dependent().tbs_cert.issuer)?;
-    let issuer_name_hash =
-        pyo3::pybacked::PyBackedBytes::from(hash_data(py, hash_algorithm, &issuer_der)?);
-    let issuer_key_hash = pyo3::pybacked::PyBackedBytes::from(hash_data(
+    let (issuer_name_hash, issuer_key_hash) = issuer_hashes(
         py,
-        hash_algorithm,
+        &issuer_der,
         issuer
             .raw
             .borrow_dependent()
@@ -92,15 +90,16 @@ pub(crate) fn certid_new<'p>(
             .spki
             .subject_public_key
             .as_bytes(),
-    )?);
+        hash_algorithm,
+    )?;
 
     Ok(CertID {
         hash_algorithm: HASH_NAME_TO_ALGORITHM_IDENTIFIERS[&*hash_algorithm
             .getattr(pyo3::intern!(py, "name"))?
             .extract::<pyo3::pybacked::PyBackedStr>()?]
             .clone(),
-        issuer_name_hash: ka.add(issuer_name_hash),
-        issuer_key_hash: ka.add(issuer_key_hash),
+        issuer_name_hash: ka.add(pyo3::pybacked::PyBackedBytes::from(issuer_name_hash)),
+        issuer_key_hash: ka.add(pyo3::pybacked::PyBackedBytes::from(issuer_key_hash)),
         serial_number: cert.raw.borrow_dependent().tbs_cert.serial,
     })
 }
@@ -123,6 +122,44 @@ pub(crate) fn certid_new_from_hash<'p>(
     })
 }
 
+/// Computes the `issuerNameHash` and `issuerKeyHash` of a `CertID` from the
+/// DER encoding of the issuer's name and the issuer's public key bits.
+pub(crate) fn issuer_hashes<'p>(
+    py: pyo3::Python<'p>,
+    issuer_name_der: &[u8],
+    issuer_spki_bits: &[u8],
+    hash_algorithm: &pyo3::Bound<'p, pyo3::PyAny>,
+) -> pyo3::PyResult<(
+    pyo3::Bound<'p, pyo3::types::PyBytes>,
+    pyo3::Bound<'p, pyo3::types::PyBytes>,
+)> {
+    Ok((
+        hash_data(py, hash_algorithm, issuer_name_der)?,
+        hash_data(py, hash_algorithm, issuer_spki_bits)?,
+    ))
+}
+
+/// Computes the `issuerNameHash` and `issuerKeyHash` of a `CertID` for
+/// certificates issued by `issuer`.
+#[pyo3::pyfunction]
+#[pyo3(name = "issuer_hashes")]
+pub(crate) fn py_issuer_hashes<'p>(
+    py: pyo3::Python<'p>,
+    issuer: pyo3::PyRef<'p, Certificate>,
+    hash_algorithm: pyo3::Bound<'p, pyo3::PyAny>,
+) -> CryptographyResult<(
+    pyo3::Bound<'p, pyo3::types::PyBytes>,
+    pyo3::Bound<'p, pyo3::types::PyBytes>,
+)> {
+    let tbs_cert = &issuer.raw.borrow_dependent().tbs_cert;
+    Ok(issuer_hashes(
+        py,
+        &asn1::write_single(&tbs_cert.subject)?,
+        tbs_cert.spki.subject_public_key.as_bytes(),
+        &hash_algorithm,
+    )?)
+}
+
 pub(crate) fn hash_data<'p>(
     py: pyo3::Python<'p>,
     py_hash_alg: &pyo3::Bound<'p, pyo3::PyAny>,
@@ -3,12 +3,14 @@
 // for complete details.
 
 use cryptography_x509::ocsp_req::{self, OCSPRequest as RawOCSPRequest};
-use cryptography_x509::{common, oid};
+use cryptography_x509::{common, name, oid};
 use pyo3::types::{PyAnyMethods, PyListMethods};
 
 use crate::asn1::{big_byte_slice_to_py_int, oid_to_py_oid, py_uint_to_big_endian_bytes};
+use crate::backend::keys;
 use crate::error::{CryptographyError, CryptographyResult};
-use crate::x509::{extensions, ocsp};
+use crate::x509::certificate::load_der_x509_certificate;
+use crate::x509::{extensions, ocsp, sign};
 use crate::{exceptions, types, x509};
 
 self_cell::self_cell!(
@@ -55,7 +57,7 @@ pub(crate) struct OCSPRequest {
 }
 
 impl OCSPRequest {
-    fn cert_id(&self) -> ocsp_req::CertID<'_> {
+    pub(crate) fn cert_id(&self) -> ocsp_req::CertID<'_> {
         self.raw
             .borrow_dependent()
             .tbs_request
@@ -81,7 +83,7 @@ impl OCSPRequest {
     }
 
     #[getter]
-    fn hash_algorithm<'p>(
+    pub(crate) fn hash_algorithm<'p>(
         &self,
         py: pyo3::Python<'p>,
     ) -> Result<pyo3::Bound<'p, pyo3::PyAny>, CryptographyError> {
@@ -160,6 +162,63 @@ impl OCSPRequest {
         let result = asn1::write_single(self.raw.borrow_dependent())?;
         Ok(pyo3::types::PyBytes::new(py, &result))
     }
+
+    fn verify_signature(
+        &self,
+        py: pyo3::Python<'_>,
+    ) -> CryptographyResult<Option<x509::certificate::Certificate>> {
+        let raw = self.raw.borrow_dependent();
+        let raw_signature = match &raw.optional_signature {
+            Some(signature) => asn1::write_single(signature)?,
+            None => return Ok(None),
+        };
+        let signature = asn1::parse_single::<ocsp_req::Signature<'_>>(&raw_signature)?;
+        // RFC 6960 requires signed requests to carry a requestorName, which
+        // identifies the certificate the request was signed with.
+        let requestor_name = match &raw.tbs_request.requestor_name {
+            Some(name::GeneralName::DirectoryName(name)) => name,
+            _ => {
+                return Err(CryptographyError::from(
+                    pyo3::exceptions::PyValueError::new_err(
+                        "The OCSP request is signed, but has no directoryName requestorName.",
+                    ),
+                ))
+            }
+        };
+        let requestor_cert = signature.certs.as_ref().and_then(|certs| {
+            certs
+                .clone()
+                .find(|cert| &cert.tbs_cert.subject == requestor_name)
+        });
+        let requestor_cert = match requestor_cert {
+            Some(cert) => cert,
+            None => {
+                return Err(CryptographyError::from(
+                    pyo3::exceptions::PyValueError::new_err(
+                        "The OCSP request does not include a certificate matching its \
+                         requestorName.",
+                    ),
+                ))
+            }
+        };
+
+        let requestor_key =
+            keys::load_der_public_key_bytes(py, requestor_cert.tbs_cert.spki.tlv().full_data())?;
+        sign::verify_signature_with_signature_algorithm(
+            py,
+            requestor_key,
+            &signature.signature_algorithm,
+            signature.signature.as_bytes(),
+            &asn1::write_single(&raw.tbs_request)?,
+        )?;
+
+        let der = asn1::write_single(&requestor_cert)?;
+        Ok(Some(load_der_x509_certificate(
+            py,
+            pyo3::types::PyBytes::new(py, &der).unbind(),
+            None,
+        )?))
+    }
 }
 
 #[pyo3::pyfunction]
@@ -225,3 +284,16 @@ pub(crate) fn create_ocsp_request(
     let data = asn1::write_single(&ocsp_req)?;
     load_der_ocsp_request(py, pyo3::types::PyBytes::new(py, &data).unbind())
 }
+
+#[pyo3::pyfunction]
+pub(crate) fn create_ocsp_cert_id<'p>(
+    py: pyo3::Python<'p>,
+    cert: pyo3::PyRef<'p, x509::certificate::Certificate>,
+    issuer: pyo3::PyRef<'p, x509::certificate::Certificate>,
+    hash_algorithm: pyo3::Bound<'p, pyo3::PyAny>,
+) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyBytes>> {
+    let ka_bytes = cryptography_keepalive::KeepAlive::new();
+    let cert_id = ocsp::certid_new(py, &ka_bytes, &cert, &issuer, &hash_algorithm)?;
+    let data = asn1::write_single(&cert_id)?;
+    Ok(pyo3::types::PyBytes::new(py, &data))
+}
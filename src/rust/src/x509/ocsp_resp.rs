@@ -4,6 +4,7 @@
 
 use std::sync::Arc;
 
+use cryptography_x509::extensions::ExtendedKeyUsage;
 use cryptography_x509::ocsp_resp::{
     self, OCSPResponse as RawOCSPResponse, SingleResponse, SingleResponse as RawSingleResponse,
 };
@@ -11,9 +12,10 @@ use cryptography_x509::{common, oid};
 use pyo3::types::{PyAnyMethods, PyBytesMethods, PyListMethods};
 
 use crate::asn1::{big_byte_slice_to_py_int, oid_to_py_oid, py_uint_to_big_endian_bytes};
+use crate::backend::keys;
 use crate::error::{CryptographyError, CryptographyResult};
 use crate::utils::cstr_from_literal;
-use crate::x509::{certificate, crl, extensions, ocsp, py_to_datetime, sct};
+use crate::x509::{certificate, crl, extensions, ocsp, ocsp_req, py_to_datetime, sct, sign};
 use crate::{exceptions, types, x509};
 
 const BASIC_RESPONSE_OID: asn1::ObjectIdentifier = asn1::oid!(1, 3, 6, 1, 5, 5, 7, 48, 1, 1);
@@ -71,6 +73,31 @@ self_cell::self_cell!(
     }
 );
 
+/// The certificate that signed an OCSP response on behalf of an issuer.
+pub(crate) enum Responder<'a> {
+    /// The issuer signed the response itself.
+    Issuer,
+    /// A delegated responder signed the response, with a certificate that is
+    /// at `index` in the response's certificates.
+    Delegate {
+        index: usize,
+        cert: Box<cryptography_x509::certificate::Certificate<'a>>,
+    },
+}
+
+/// Why no certificate authorized to sign an OCSP response on behalf of an
+/// issuer could be found. When several included certificates match the
+/// ResponderID, this is the reason the first of them was rejected.
+pub(crate) enum ResponderError {
+    /// Neither the issuer nor any included certificate matches the
+    /// ResponderID.
+    Unidentified,
+    /// The responder's certificate lacks the OCSP signing EKU.
+    MissingEku,
+    /// The responder's certificate was not issued by the issuer.
+    WrongIssuer,
+}
+
 #[pyo3::pyclass(frozen, module = "cryptography.hazmat.bindings._rust.ocsp")]
 pub(crate) struct OCSPResponse {
     raw: Arc<OwnedOCSPResponse>,
@@ -80,7 +107,9 @@ pub(crate) struct OCSPResponse {
 }
 
 impl OCSPResponse {
-    fn requires_successful_response(&self) -> pyo3::PyResult<&ocsp_resp::BasicOCSPResponse<'_>> {
+    pub(crate) fn requires_successful_response(
+        &self,
+    ) -> pyo3::PyResult<&ocsp_resp::BasicOCSPResponse<'_>> {
         match self.raw.borrow_dependent().response_bytes.as_ref() {
             Some(b) => Ok(b.response.get()),
             None => Err(pyo3::exceptions::PyValueError::new_err(
@@ -88,6 +117,187 @@ impl OCSPResponse {
             )),
         }
     }
+
+    /// Locates the SingleResponse whose CertID identifies the certificate
+    /// with `serial` issued under `issuer_name_der` and `issuer_key`,
+    /// recomputing the issuer hashes with whichever hash algorithm each
+    /// CertID was computed with.
+    fn find_single_response(
+        &self,
+        py: pyo3::Python<'_>,
+        serial: &asn1::BigInt<'_>,
+        issuer_name_der: &[u8],
+        issuer_key: &[u8],
+    ) -> CryptographyResult<Option<OCSPSingleResponse>> {
+        let resp = self.requires_successful_response()?;
+        let mut index = None;
+        for (i, single_resp) in resp
+            .tbs_response_data
+            .responses
+            .unwrap_read()
+            .clone()
+            .enumerate()
+        {
+            let cert_id = &single_resp.cert_id;
+            let Some(alg_name) =
+                ocsp::ALGORITHM_PARAMETERS_TO_HASH.get(&cert_id.hash_algorithm.params)
+            else {
+                continue;
+            };
+            if cert_id.serial_number != *serial {
+                continue;
+            }
+            let py_hash = types::HASHES_MODULE.get(py)?.getattr(*alg_name)?.call0()?;
+            let (name_hash, key_hash) =
+                ocsp::issuer_hashes(py, issuer_name_der, issuer_key, &py_hash)?;
+            if cert_id.issuer_name_hash == name_hash.as_bytes()
+                && cert_id.issuer_key_hash == key_hash.as_bytes()
+            {
+                index = Some(i);
+                break;
+            }
+        }
+        Ok(index.map(|index| OCSPSingleResponse {
+            raw: OwnedSingleResponse::new(Arc::clone(&self.raw), |v| {
+                v.borrow_dependent()
+                    .response_bytes
+                    .as_ref()
+                    .unwrap()
+                    .response
+                    .get()
+                    .tbs_response_data
+                    .responses
+                    .unwrap_read()
+                    .clone()
+                    .nth(index)
+                    .unwrap()
+            }),
+        }))
+    }
+
+    /// Returns the SingleResponse for `certificate`, issued by `issuer`.
+    pub(crate) fn single_response_for_certificate(
+        &self,
+        py: pyo3::Python<'_>,
+        certificate: &certificate::Certificate,
+        issuer: &certificate::Certificate,
+    ) -> CryptographyResult<OCSPSingleResponse> {
+        let raw_cert = certificate.raw.borrow_dependent();
+        let raw_issuer = issuer.raw.borrow_dependent();
+        let Some(single_resp) = self.find_single_response(
+            py,
+            &raw_cert.tbs_cert.serial,
+            &asn1::write_single(&raw_cert.tbs_cert.issuer)?,
+            raw_issuer.tbs_cert.spki.subject_public_key.as_bytes(),
+        )?
+        else {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyValueError::new_err(
+                    "No SingleResponse in the OCSP response matches the given certificate.",
+                ),
+            ));
+        };
+        Ok(single_resp)
+    }
+
+    /// Finds the certificate that signed the response on behalf of
+    /// `issuer`. RFC 6960 4.2.2.2: the response is signed by either the
+    /// issuer itself or a responder the issuer delegated to, whose
+    /// certificate the response must then include. A delegated responder
+    /// must have the OCSP signing EKU and be issued directly by `issuer`.
+    pub(crate) fn find_responder(
+        &self,
+        py: pyo3::Python<'_>,
+        issuer: &certificate::Certificate,
+    ) -> CryptographyResult<Result<Responder<'_>, ResponderError>> {
+        let resp = self.requires_successful_response()?;
+        let raw_issuer = issuer.raw.borrow_dependent();
+        if self.responder_matches(py, raw_issuer)? {
+            return Ok(Ok(Responder::Issuer));
+        }
+
+        let issuer_public_key =
+            keys::load_der_public_key_bytes(py, raw_issuer.tbs_cert.spki.tlv().full_data())?;
+        let mut error = None;
+        for (index, cert) in resp
+            .certs
+            .iter()
+            .flat_map(|certs| certs.unwrap_read().clone())
+            .enumerate()
+        {
+            if !self.responder_matches(py, &cert)? {
+                continue;
+            }
+            let is_ocsp_signer = match cert.extensions() {
+                Ok(extensions) => match extensions.get_extension(&oid::EXTENDED_KEY_USAGE_OID) {
+                    Some(ext) => ext
+                        .value::<ExtendedKeyUsage<'_>>()?
+                        .any(|eku| eku == oid::EKU_OCSP_SIGNING_OID),
+                    None => false,
+                },
+                Err(_) => false,
+            };
+            if !is_ocsp_signer {
+                error.get_or_insert(ResponderError::MissingEku);
+                continue;
+            }
+            if cert.tbs_cert.issuer != raw_issuer.tbs_cert.subject
+                || sign::verify_signature_with_signature_algorithm(
+                    py,
+                    issuer_public_key.clone(),
+                    &cert.signature_alg,
+                    cert.signature.as_bytes(),
+                    &asn1::write_single(&cert.tbs_cert)?,
+                )
+                .is_err()
+            {
+                error.get_or_insert(ResponderError::WrongIssuer);
+                continue;
+            }
+            return Ok(Ok(Responder::Delegate {
+                index,
+                cert: Box::new(cert),
+            }));
+        }
+        Ok(Err(error.unwrap_or(ResponderError::Unidentified)))
+    }
+
+    /// Verifies the response's signature with `responder`'s public key,
+    /// raising `InvalidSignature` if it doesn't match.
+    pub(crate) fn verify_response_signature(
+        &self,
+        py: pyo3::Python<'_>,
+        responder: &cryptography_x509::certificate::Certificate<'_>,
+    ) -> CryptographyResult<()> {
+        let resp = self.requires_successful_response()?;
+        let responder_public_key =
+            keys::load_der_public_key_bytes(py, responder.tbs_cert.spki.tlv().full_data())?;
+        sign::verify_signature_with_signature_algorithm(
+            py,
+            responder_public_key,
+            &resp.signature_algorithm,
+            resp.signature.as_bytes(),
+            &asn1::write_single(&resp.tbs_response_data)?,
+        )
+    }
+
+    /// Returns whether `cert` is identified by the response's ResponderID.
+    pub(crate) fn responder_matches(
+        &self,
+        py: pyo3::Python<'_>,
+        cert: &cryptography_x509::certificate::Certificate<'_>,
+    ) -> CryptographyResult<bool> {
+        let resp = self.requires_successful_response()?;
+        Ok(match &resp.tbs_response_data.responder_id {
+            ocsp_resp::ResponderId::ByName(name) => &cert.tbs_cert.subject == name,
+            ocsp_resp::ResponderId::ByKey(key_hash) => {
+                let sha1 = types::SHA1.get(py)?.call0()?;
+                *key_hash
+                    == ocsp::hash_data(py, &sha1, cert.tbs_cert.spki.subject_public_key.as_bytes())?
+                        .as_bytes()
+            }
+        })
+    }
 }
 
 const SUCCESSFUL_RESPONSE: u32 = 0;
@@ -247,7 +457,7 @@ impl OCSPResponse {
     }
 
     #[getter]
-    fn certificates<'p>(
+    pub(crate) fn certificates<'p>(
         &self,
         py: pyo3::Python<'p>,
     ) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyList>> {
@@ -361,6 +571,16 @@ impl OCSPResponse {
         singleresp_py_revocation_reason(&single_resp, py)
     }
 
+    #[getter]
+    fn revoked_certificate<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+    ) -> CryptographyResult<pyo3::Bound<'p, pyo3::PyAny>> {
+        let resp = self.requires_successful_response()?;
+        let single_resp = single_response(resp)?;
+        singleresp_py_revoked_certificate(&single_resp, py)
+    }
+
     #[getter]
     fn this_update<'p>(
         &self,
@@ -489,6 +709,60 @@ impl OCSPResponse {
         let result = asn1::write_single(self.raw.borrow_dependent())?;
         Ok(pyo3::types::PyBytes::new(py, &result))
     }
+
+    pub(crate) fn verify_single_response(
+        &self,
+        py: pyo3::Python<'_>,
+        certificate: pyo3::PyRef<'_, certificate::Certificate>,
+        issuer: pyo3::PyRef<'_, certificate::Certificate>,
+    ) -> CryptographyResult<OCSPSingleResponse> {
+        let single_resp = self.single_response_for_certificate(py, &certificate, &issuer)?;
+        match self.find_responder(py, &issuer)? {
+            Ok(Responder::Issuer) => {
+                self.verify_response_signature(py, issuer.raw.borrow_dependent())?
+            }
+            Ok(Responder::Delegate { cert, .. }) => self.verify_response_signature(py, &cert)?,
+            Err(_) => {
+                return Err(CryptographyError::from(
+                    pyo3::exceptions::PyValueError::new_err(
+                        "The OCSP response is signed by neither the issuer nor a delegated \
+                         responder included in the response.",
+                    ),
+                ))
+            }
+        }
+
+        Ok(single_resp)
+    }
+
+    fn single_response_for_request(
+        &self,
+        py: pyo3::Python<'_>,
+        request: pyo3::PyRef<'_, ocsp_req::OCSPRequest>,
+        issuer: pyo3::PyRef<'_, certificate::Certificate>,
+    ) -> CryptographyResult<Option<OCSPSingleResponse>> {
+        let req_cert_id = request.cert_id();
+        let tbs_issuer = &issuer.raw.borrow_dependent().tbs_cert;
+        let issuer_name_der = asn1::write_single(&tbs_issuer.subject)?;
+        let issuer_key = tbs_issuer.spki.subject_public_key.as_bytes();
+
+        // The request's CertID may use a different hash algorithm than the
+        // response's, so both are checked against the issuer itself.
+        let py_hash = request.hash_algorithm(py)?;
+        let (name_hash, key_hash) =
+            ocsp::issuer_hashes(py, &issuer_name_der, issuer_key, &py_hash)?;
+        if req_cert_id.issuer_name_hash != name_hash.as_bytes()
+            || req_cert_id.issuer_key_hash != key_hash.as_bytes()
+        {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyValueError::new_err(
+                    "The OCSP request is not for a certificate issued by the given issuer.",
+                ),
+            ));
+        }
+
+        self.find_single_response(py, &req_cert_id.serial_number, &issuer_name_der, issuer_key)
+    }
 }
 
 // Open-coded implementation of the API discussed in
@@ -666,6 +940,37 @@ fn singleresp_py_revocation_time_utc<'p>(
     }
 }
 
+fn singleresp_py_revoked_certificate<'p>(
+    resp: &ocsp_resp::SingleResponse<'_>,
+    py: pyo3::Python<'p>,
+) -> CryptographyResult<pyo3::Bound<'p, pyo3::PyAny>> {
+    let ocsp_resp::CertStatus::Revoked(revoked_info) = &resp.cert_status else {
+        return Ok(py.None().into_bound(py));
+    };
+    // Build the same structure a CRL entry for this certificate would have.
+    let mut builder = types::REVOKED_CERTIFICATE_BUILDER
+        .get(py)?
+        .call0()?
+        .call_method1(
+            pyo3::intern!(py, "serial_number"),
+            (singleresp_py_serial_number(resp, py)?,),
+        )?
+        .call_method1(
+            pyo3::intern!(py, "revocation_date"),
+            (x509::datetime_to_py_utc(
+                py,
+                revoked_info.revocation_time.as_datetime(),
+            )?,),
+        )?;
+    if let Some(reason) = &revoked_info.revocation_reason {
+        let py_reason = types::CRL_REASON
+            .get(py)?
+            .call1((crl::parse_crl_reason_flags(py, reason)?,))?;
+        builder = builder.call_method1(pyo3::intern!(py, "add_extension"), (py_reason, false))?;
+    }
+    Ok(builder.call_method0(pyo3::intern!(py, "build"))?)
+}
+
 #[pyo3::pyfunction]
 pub(crate) fn create_ocsp_response(
     py: pyo3::Python<'_>,
@@ -925,7 +1230,7 @@ pub(crate) struct OCSPSingleResponse {
 }
 
 impl OCSPSingleResponse {
-    fn single_response(&self) -> &SingleResponse<'_> {
+    pub(crate) fn single_response(&self) -> &SingleResponse<'_> {
         self.raw.borrow_dependent()
     }
 }
@@ -1000,6 +1305,14 @@ impl OCSPSingleResponse {
         singleresp_py_revocation_reason(single_resp, py)
     }
 
+    #[getter]
+    fn revoked_certificate<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+    ) -> CryptographyResult<pyo3::Bound<'p, pyo3::PyAny>> {
+        singleresp_py_revoked_certificate(self.single_response(), py)
+    }
+
     #[getter]
     fn this_update<'p>(
         &self,
@@ -5,6 +5,7 @@
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
 
+use cryptography_x509::common;
 use pyo3::types::{PyAnyMethods, PyDictMethods, PyListMethods};
 
 use crate::error::{CryptographyError, CryptographyResult};
@@ -129,17 +130,116 @@ impl TryFrom<u8> for SignatureAlgorithm {
 
 #[pyo3::pyclass(frozen, module = "cryptography.hazmat.bindings._rust.x509")]
 pub(crate) struct Sct {
-    log_id: [u8; 32],
+    pub(crate) log_id: [u8; 32],
     timestamp: u64,
     entry_type: LogEntryType,
     hash_algorithm: HashAlgorithm,
     signature_algorithm: SignatureAlgorithm,
     // TODO: These could be 'self references back into sct_data with ouroboros.
-    signature: Vec<u8>,
+    pub(crate) signature: Vec<u8>,
     extension_bytes: Vec<u8>,
     pub(crate) sct_data: Vec<u8>,
 }
 
+impl Sct {
+    /// The `digitally-signed` struct from RFC 6962 section 3.2 that a log
+    /// signs over for a `precert_entry`.
+    pub(crate) fn precertificate_signed_data(
+        &self,
+        issuer_key_hash: &[u8; 32],
+        tbs_precertificate: &[u8],
+    ) -> CryptographyResult<Vec<u8>> {
+        if !matches!(self.entry_type, LogEntryType::PreCertificate) {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyValueError::new_err(
+                    "The SCT is not for a pre-certificate log entry.",
+                ),
+            ));
+        }
+        let mut entry = issuer_key_hash.to_vec();
+        entry.extend_from_slice(&u24_length_prefix(tbs_precertificate, "TBSCertificate")?);
+        entry.extend_from_slice(tbs_precertificate);
+        Ok(self.signed_data(1, &entry))
+    }
+
+    /// The `digitally-signed` struct from RFC 6962 section 3.2 that a log
+    /// signs over for an `x509_entry`, i.e. for an SCT delivered in a TLS
+    /// extension or an OCSP response rather than embedded in the
+    /// certificate.
+    pub(crate) fn certificate_signed_data(
+        &self,
+        certificate: &[u8],
+    ) -> CryptographyResult<Vec<u8>> {
+        if !matches!(self.entry_type, LogEntryType::Certificate) {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyValueError::new_err(
+                    "The SCT is not for an X.509 certificate log entry.",
+                ),
+            ));
+        }
+        let mut entry = u24_length_prefix(certificate, "Certificate")?.to_vec();
+        entry.extend_from_slice(certificate);
+        Ok(self.signed_data(0, &entry))
+    }
+
+    fn signed_data(&self, entry_type: u16, signed_entry: &[u8]) -> Vec<u8> {
+        // Extensions were read with a two byte length prefix.
+        let extensions_len = self.extension_bytes.len() as u16;
+
+        let mut data = vec![];
+        // Version (v1) and signature type (certificate_timestamp)
+        data.extend_from_slice(&[0, 0]);
+        data.extend_from_slice(&self.timestamp.to_be_bytes());
+        data.extend_from_slice(&entry_type.to_be_bytes());
+        data.extend_from_slice(signed_entry);
+        data.extend_from_slice(&extensions_len.to_be_bytes());
+        data.extend_from_slice(&self.extension_bytes);
+        data
+    }
+
+    /// The X.509 signature algorithm equivalent to the SCT's hash and
+    /// signature algorithms, or `None` if there is none we can verify.
+    pub(crate) fn signature_algorithm_identifier(
+        &self,
+    ) -> Option<common::AlgorithmIdentifier<'static>> {
+        let params = match (&self.signature_algorithm, &self.hash_algorithm) {
+            (SignatureAlgorithm::Ecdsa, HashAlgorithm::Sha256) => {
+                common::AlgorithmParameters::EcDsaWithSha256(None)
+            }
+            (SignatureAlgorithm::Ecdsa, HashAlgorithm::Sha384) => {
+                common::AlgorithmParameters::EcDsaWithSha384(None)
+            }
+            (SignatureAlgorithm::Ecdsa, HashAlgorithm::Sha512) => {
+                common::AlgorithmParameters::EcDsaWithSha512(None)
+            }
+            (SignatureAlgorithm::Rsa, HashAlgorithm::Sha256) => {
+                common::AlgorithmParameters::RsaWithSha256(Some(()))
+            }
+            (SignatureAlgorithm::Rsa, HashAlgorithm::Sha384) => {
+                common::AlgorithmParameters::RsaWithSha384(Some(()))
+            }
+            (SignatureAlgorithm::Rsa, HashAlgorithm::Sha512) => {
+                common::AlgorithmParameters::RsaWithSha512(Some(()))
+            }
+            _ => return None,
+        };
+        Some(common::AlgorithmIdentifier {
+            oid: asn1::DefinedByMarker::marker(),
+            params,
+        })
+    }
+}
+
+/// The three byte length prefix TLS uses for `opaque<1..2^24-1>` values.
+fn u24_length_prefix(data: &[u8], name: &str) -> CryptographyResult<[u8; 3]> {
+    let len = u32::try_from(data.len())
+        .ok()
+        .filter(|len| *len < (1 << 24))
+        .ok_or_else(|| pyo3::exceptions::PyValueError::new_err(format!("{name} is too large")))?;
+    let [_, a, b, c] = len.to_be_bytes();
+    Ok([a, b, c])
+}
+
 #[pyo3::pymethods]
 impl Sct {
     fn __eq__(&self, other: pyo3::PyRef<'_, Sct>) -> bool {
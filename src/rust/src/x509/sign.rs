@@ -10,6 +10,7 @@ use pyo3::pybacked::PyBackedBytes;
 use pyo3::types::PyAnyMethods;
 
 use crate::asn1::oid_to_py_oid;
+use crate::backend::hashes;
 use crate::error::{CryptographyError, CryptographyResult};
 use crate::{exceptions, types};
 
@@ -26,6 +27,10 @@ static HASH_OIDS_TO_HASH: Lazy<HashMap<&asn1::ObjectIdentifier, &str>> = Lazy::n
     h.insert(&oid::SHA3_256_OID, "SHA3_256");
     h.insert(&oid::SHA3_384_OID, "SHA3_384");
     h.insert(&oid::SHA3_512_OID, "SHA3_512");
+    h.insert(&oid::NIST_SHA3_224_OID, "SHA3_224");
+    h.insert(&oid::NIST_SHA3_256_OID, "SHA3_256");
+    h.insert(&oid::NIST_SHA3_384_OID, "SHA3_384");
+    h.insert(&oid::NIST_SHA3_512_OID, "SHA3_512");
     h
 });
 
@@ -338,15 +343,24 @@ pub(crate) fn verify_signature_with_signature_algorithm<'p>(
             pyo3::intern!(py, "verify"),
             (signature, data, py_signature_algorithm_parameters),
         )?,
-        KeyType::Rsa => issuer_public_key.call_method1(
-            pyo3::intern!(py, "verify"),
-            (
-                signature,
-                data,
-                py_signature_algorithm_parameters,
-                py_signature_hash_algorithm,
-            ),
-        )?,
+        KeyType::Rsa => {
+            if let common::AlgorithmParameters::RsaPss(_) = signature_algorithm.params {
+                check_pss_hashes_supported(
+                    py,
+                    &py_signature_hash_algorithm,
+                    &py_signature_algorithm_parameters,
+                )?;
+            }
+            issuer_public_key.call_method1(
+                pyo3::intern!(py, "verify"),
+                (
+                    signature,
+                    data,
+                    py_signature_algorithm_parameters,
+                    py_signature_hash_algorithm,
+                ),
+            )?
+        }
         KeyType::Dsa => issuer_public_key.call_method1(
             pyo3::intern!(py, "verify"),
             (signature, data, py_signature_hash_algorithm),
@@ -355,6 +369,32 @@ pub(crate) fn verify_signature_with_signature_algorithm<'p>(
     Ok(())
 }
 
+// PSS parameters may name digests, such as SHA-3, that the loaded OpenSSL
+// providers don't offer.
+fn check_pss_hashes_supported(
+    py: pyo3::Python<'_>,
+    hash_algorithm: &pyo3::Bound<'_, pyo3::PyAny>,
+    pss: &pyo3::Bound<'_, pyo3::PyAny>,
+) -> CryptographyResult<()> {
+    let mgf1_hash_algorithm = pss
+        .getattr(pyo3::intern!(py, "_mgf"))?
+        .getattr(pyo3::intern!(py, "_algorithm"))?;
+    for algorithm in [hash_algorithm, &mgf1_hash_algorithm] {
+        if hashes::message_digest_from_algorithm(py, algorithm).is_err() {
+            return Err(CryptographyError::from(
+                exceptions::UnsupportedAlgorithm::new_err((
+                    format!(
+                        "RSASSA-PSS signatures using {} are not supported by this backend.",
+                        algorithm.getattr(pyo3::intern!(py, "name"))?
+                    ),
+                    exceptions::Reasons::UNSUPPORTED_HASH,
+                )),
+            ));
+        }
+    }
+    Ok(())
+}
+
 pub(crate) fn identify_public_key_type(
     py: pyo3::Python<'_>,
     public_key: pyo3::Bound<'_, pyo3::PyAny>,
@@ -376,11 +416,13 @@ pub(crate) fn identify_public_key_type(
     }
 }
 
-fn identify_key_type_for_algorithm_params(
+pub(crate) fn identify_key_type_for_algorithm_params(
     params: &common::AlgorithmParameters<'_>,
 ) -> pyo3::PyResult<KeyType> {
     match params {
-        common::AlgorithmParameters::RsaWithSha224(..)
+        common::AlgorithmParameters::RsaWithSha1(..)
+        | common::AlgorithmParameters::RsaWithSha1Alt(..)
+        | common::AlgorithmParameters::RsaWithSha224(..)
         | common::AlgorithmParameters::RsaWithSha256(..)
         | common::AlgorithmParameters::RsaWithSha384(..)
         | common::AlgorithmParameters::RsaWithSha512(..)
@@ -389,7 +431,8 @@ fn identify_key_type_for_algorithm_params(
         | common::AlgorithmParameters::RsaWithSha3_384(..)
         | common::AlgorithmParameters::RsaWithSha3_512(..)
         | common::AlgorithmParameters::RsaPss(..) => Ok(KeyType::Rsa),
-        common::AlgorithmParameters::EcDsaWithSha224(..)
+        common::AlgorithmParameters::EcDsaWithSha1(..)
+        | common::AlgorithmParameters::EcDsaWithSha224(..)
         | common::AlgorithmParameters::EcDsaWithSha256(..)
         | common::AlgorithmParameters::EcDsaWithSha384(..)
         | common::AlgorithmParameters::EcDsaWithSha512(..)
@@ -504,7 +547,8 @@ pub(crate) fn identify_signature_algorithm_parameters<'p>(
         | common::AlgorithmParameters::RsaWithSha3_512(_) => {
             Ok(types::PKCS1V15.get(py)?.call0()?)
         }
-        common::AlgorithmParameters::EcDsaWithSha224(_)
+        common::AlgorithmParameters::EcDsaWithSha1(_)
+        | common::AlgorithmParameters::EcDsaWithSha224(_)
         | common::AlgorithmParameters::EcDsaWithSha256(_)
         | common::AlgorithmParameters::EcDsaWithSha384(_)
         | common::AlgorithmParameters::EcDsaWithSha512(_)
@@ -2,27 +2,48 @@
 // 2.0, and the BSD License. See the LICENSE file in the root of this repository
 // for complete details.
 
+use std::collections::HashSet;
+use std::sync::Arc;
+
 use cryptography_x509::certificate::Certificate;
-use cryptography_x509::extensions::SubjectAlternativeName;
-use cryptography_x509::oid::SUBJECT_ALTERNATIVE_NAME_OID;
+use cryptography_x509::extensions::{ExtendedKeyUsage, SubjectAlternativeName, TnAuthList};
+use cryptography_x509::name::GeneralName;
+use cryptography_x509::oid::{
+    EKU_JWT_OID, EXTENDED_KEY_USAGE_OID, SUBJECT_ALTERNATIVE_NAME_OID, TN_AUTH_LIST_OID,
+};
 use cryptography_x509_verification::ops::{CryptoOps, VerificationCertificate};
-use cryptography_x509_verification::policy::{Policy, PolicyDefinition, Subject};
+use cryptography_x509_verification::policy::{
+    EeCheckCallback, IssuerCheckCallback, Policy, PolicyDefinition, Subject,
+};
 use cryptography_x509_verification::trust_store::Store;
 use cryptography_x509_verification::types::{DNSName, IPAddress};
-use pyo3::types::{PyAnyMethods, PyListMethods};
+use cryptography_x509_verification::{
+    StepKind, TracedVerification, ValidationError, ValidationErrorKind, ValidationResult,
+};
+use pyo3::types::{PyAnyMethods, PyListMethods, PyTypeMethods};
 
 mod extension_policy;
+mod ocsp;
 mod policy;
+mod timestamp;
+mod tls;
 use super::parse_general_names;
+use crate::asn1::py_oid_to_oid;
 use crate::backend::keys;
 use crate::error::{CryptographyError, CryptographyResult};
+use crate::pkcs7::{load_der_pkcs7_certificates, load_pem_pkcs7_certificates};
 use crate::types;
 use crate::utils::cstr_from_literal;
-use crate::x509::certificate::Certificate as PyCertificate;
-use crate::x509::common::{datetime_now, py_to_datetime};
+use crate::x509::certificate::{
+    load_der_x509_certificate, load_pem_x509_certificates, Certificate as PyCertificate,
+};
+use crate::x509::common::{datetime_now, datetime_to_py_utc, parse_general_name, py_to_datetime};
 use crate::x509::sign;
 pub(crate) use extension_policy::{PyCriticality, PyExtensionPolicy};
-pub(crate) use policy::PyPolicy;
+pub(crate) use ocsp::{verify_ocsp_response, OCSPVerificationError, PyOCSPVerificationStage};
+pub(crate) use policy::{PyPolicy, PyValidityStatus};
+pub(crate) use timestamp::{verify_timestamp_token, verify_timestamp_token_within_validity};
+pub(crate) use tls::{verify_tls_server, PyTLSVerificationStage, TLSVerificationError};
 
 #[derive(Clone)]
 pub(crate) struct PyCryptoOps {}
@@ -87,6 +108,24 @@ pub(crate) struct PolicyBuilder {
     max_chain_depth: Option<u8>,
     ca_ext_policy: Option<pyo3::Py<PyExtensionPolicy>>,
     ee_ext_policy: Option<pyo3::Py<PyExtensionPolicy>>,
+    expiry_warning_threshold: Option<pyo3::PyObject>,
+    warnings_as_errors: bool,
+    client_subject_types: Option<pyo3::Py<pyo3::types::PyTuple>>,
+    subject_organizations: Option<Vec<String>>,
+    name_only_issuer_matching: bool,
+    eku_chaining: bool,
+    ignore_trust_anchor_validity: bool,
+    leaf_any_extended_key_usage: bool,
+    additional_signature_algorithms: Option<Vec<asn1::ObjectIdentifier>>,
+    denied_signature_algorithms: Option<Vec<asn1::ObjectIdentifier>>,
+    minimum_signature_digest_size: Option<usize>,
+    nested_validity: bool,
+    leaf_non_tls_key_usage: bool,
+    shaken_leaf: bool,
+    revocation_checker: Option<pyo3::PyObject>,
+    revocation_fail_closed: bool,
+    max_dns_name_labels: Option<usize>,
+    dns_name_labels_all_names: bool,
 }
 
 impl PolicyBuilder {
@@ -97,7 +136,86 @@ impl PolicyBuilder {
             max_chain_depth: self.max_chain_depth,
             ca_ext_policy: self.ca_ext_policy.as_ref().map(|p| p.clone_ref(py)),
             ee_ext_policy: self.ee_ext_policy.as_ref().map(|p| p.clone_ref(py)),
+            expiry_warning_threshold: self
+                .expiry_warning_threshold
+                .as_ref()
+                .map(|t| t.clone_ref(py)),
+            warnings_as_errors: self.warnings_as_errors,
+            client_subject_types: self.client_subject_types.as_ref().map(|t| t.clone_ref(py)),
+            subject_organizations: self.subject_organizations.clone(),
+            name_only_issuer_matching: self.name_only_issuer_matching,
+            eku_chaining: self.eku_chaining,
+            ignore_trust_anchor_validity: self.ignore_trust_anchor_validity,
+            leaf_any_extended_key_usage: self.leaf_any_extended_key_usage,
+            additional_signature_algorithms: self.additional_signature_algorithms.clone(),
+            denied_signature_algorithms: self.denied_signature_algorithms.clone(),
+            minimum_signature_digest_size: self.minimum_signature_digest_size,
+            nested_validity: self.nested_validity,
+            leaf_non_tls_key_usage: self.leaf_non_tls_key_usage,
+            shaken_leaf: self.shaken_leaf,
+            revocation_checker: self.revocation_checker.as_ref().map(|c| c.clone_ref(py)),
+            revocation_fail_closed: self.revocation_fail_closed,
+            max_dns_name_labels: self.max_dns_name_labels,
+            dns_name_labels_all_names: self.dns_name_labels_all_names,
+        }
+    }
+
+    /// Builds the check made on the leaf for the options concerning it, if
+    /// any are set.
+    fn ee_check(&self) -> Option<EeCheckCallback<'static, PyCryptoOps>> {
+        let subject_organizations = self.subject_organizations.clone();
+        let max_dns_name_labels = self.max_dns_name_labels;
+        let dns_name_labels_all_names = self.dns_name_labels_all_names;
+        let shaken_leaf = self.shaken_leaf;
+        if subject_organizations.is_none() && max_dns_name_labels.is_none() && !shaken_leaf {
+            return None;
         }
+
+        Some(Arc::new(
+            move |policy: &Policy<'_, PyCryptoOps>,
+                  leaf: &VerificationCertificate<'_, PyCryptoOps>| {
+                if let Some(allowlist) = &subject_organizations {
+                    pyo3::Python::with_gil(|py| check_subject_organization(py, allowlist, leaf))?;
+                }
+                if let Some(max_labels) = max_dns_name_labels {
+                    check_dns_name_labels(policy, max_labels, dns_name_labels_all_names, leaf)?;
+                }
+                if shaken_leaf {
+                    check_shaken_leaf(leaf)?;
+                }
+                Ok(())
+            },
+        ))
+    }
+
+    /// Builds the check made on each candidate issuer for the options
+    /// concerning a certificate and its issuer, if any are set.
+    fn issuer_check(
+        &self,
+        py: pyo3::Python<'_>,
+    ) -> Option<IssuerCheckCallback<'static, PyCryptoOps>> {
+        let nested_validity = self.nested_validity;
+        let revocation_checker = self.revocation_checker.as_ref().map(|c| c.clone_ref(py));
+        let revocation_fail_closed = self.revocation_fail_closed;
+        if !nested_validity && revocation_checker.is_none() {
+            return None;
+        }
+
+        Some(Arc::new(
+            move |_policy: &Policy<'_, PyCryptoOps>,
+                  issuer: &VerificationCertificate<'_, PyCryptoOps>,
+                  child: &VerificationCertificate<'_, PyCryptoOps>| {
+                if nested_validity {
+                    check_nested_validity(issuer, child)?;
+                }
+                if let Some(checker) = &revocation_checker {
+                    pyo3::Python::with_gil(|py| {
+                        check_revocation(py, checker, revocation_fail_closed, issuer, child)
+                    })?;
+                }
+                Ok(())
+            },
+        ))
     }
 }
 
@@ -111,6 +229,24 @@ impl PolicyBuilder {
             max_chain_depth: None,
             ca_ext_policy: None,
             ee_ext_policy: None,
+            expiry_warning_threshold: None,
+            warnings_as_errors: false,
+            client_subject_types: None,
+            subject_organizations: None,
+            name_only_issuer_matching: false,
+            eku_chaining: false,
+            ignore_trust_anchor_validity: false,
+            leaf_any_extended_key_usage: false,
+            additional_signature_algorithms: None,
+            denied_signature_algorithms: None,
+            minimum_signature_digest_size: None,
+            nested_validity: false,
+            leaf_non_tls_key_usage: false,
+            shaken_leaf: false,
+            revocation_checker: None,
+            revocation_fail_closed: false,
+            max_dns_name_labels: None,
+            dns_name_labels_all_names: false,
         }
     }
 
@@ -170,6 +306,245 @@ impl PolicyBuilder {
         })
     }
 
+    fn expiry_warning_threshold(
+        &self,
+        py: pyo3::Python<'_>,
+        threshold: pyo3::Bound<'_, pyo3::PyAny>,
+    ) -> CryptographyResult<PolicyBuilder> {
+        policy_builder_set_once_check!(self, expiry_warning_threshold, "expiry warning threshold");
+
+        if !threshold.is_instance(&types::DATETIME_TIMEDELTA.get(py)?)? {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyTypeError::new_err("threshold must be a datetime.timedelta"),
+            ));
+        }
+
+        Ok(PolicyBuilder {
+            expiry_warning_threshold: Some(threshold.unbind()),
+            ..self.py_clone(py)
+        })
+    }
+
+    fn warnings_as_errors(&self, py: pyo3::Python<'_>) -> PolicyBuilder {
+        PolicyBuilder {
+            warnings_as_errors: true,
+            ..self.py_clone(py)
+        }
+    }
+
+    fn allow_name_only_issuer_matching(&self, py: pyo3::Python<'_>) -> PolicyBuilder {
+        PolicyBuilder {
+            name_only_issuer_matching: true,
+            ..self.py_clone(py)
+        }
+    }
+
+    fn enforce_eku_chaining(&self, py: pyo3::Python<'_>) -> PolicyBuilder {
+        PolicyBuilder {
+            eku_chaining: true,
+            ..self.py_clone(py)
+        }
+    }
+
+    fn ignore_trust_anchor_validity(&self, py: pyo3::Python<'_>) -> PolicyBuilder {
+        PolicyBuilder {
+            ignore_trust_anchor_validity: true,
+            ..self.py_clone(py)
+        }
+    }
+
+    fn allow_leaf_any_extended_key_usage(&self, py: pyo3::Python<'_>) -> PolicyBuilder {
+        PolicyBuilder {
+            leaf_any_extended_key_usage: true,
+            ..self.py_clone(py)
+        }
+    }
+
+    fn allow_leaf_non_tls_key_usage(&self, py: pyo3::Python<'_>) -> PolicyBuilder {
+        PolicyBuilder {
+            leaf_non_tls_key_usage: true,
+            ..self.py_clone(py)
+        }
+    }
+
+    fn require_nested_validity(&self, py: pyo3::Python<'_>) -> PolicyBuilder {
+        PolicyBuilder {
+            nested_validity: true,
+            ..self.py_clone(py)
+        }
+    }
+
+    fn require_shaken_leaf(&self, py: pyo3::Python<'_>) -> PolicyBuilder {
+        PolicyBuilder {
+            shaken_leaf: true,
+            ..self.py_clone(py)
+        }
+    }
+
+    #[pyo3(signature = (checker, *, fail_closed = false))]
+    fn revocation_checker(
+        &self,
+        py: pyo3::Python<'_>,
+        checker: pyo3::PyObject,
+        fail_closed: bool,
+    ) -> CryptographyResult<PolicyBuilder> {
+        policy_builder_set_once_check!(self, revocation_checker, "revocation checker");
+
+        if !checker.bind(py).is_callable() {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyTypeError::new_err("checker must be callable"),
+            ));
+        }
+
+        Ok(PolicyBuilder {
+            revocation_checker: Some(checker),
+            revocation_fail_closed: fail_closed,
+            ..self.py_clone(py)
+        })
+    }
+
+    #[pyo3(signature = (max_labels, *, all_names = false))]
+    fn max_dns_name_labels(
+        &self,
+        py: pyo3::Python<'_>,
+        max_labels: usize,
+        all_names: bool,
+    ) -> CryptographyResult<PolicyBuilder> {
+        policy_builder_set_once_check!(self, max_dns_name_labels, "maximum DNS name labels");
+
+        if max_labels == 0 {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyValueError::new_err(
+                    "The maximum number of DNS name labels must be at least 1.",
+                ),
+            ));
+        }
+
+        Ok(PolicyBuilder {
+            max_dns_name_labels: Some(max_labels),
+            dns_name_labels_all_names: all_names,
+            ..self.py_clone(py)
+        })
+    }
+
+    fn permit_signature_algorithms(
+        &self,
+        py: pyo3::Python<'_>,
+        oids: Vec<pyo3::Bound<'_, pyo3::PyAny>>,
+    ) -> CryptographyResult<PolicyBuilder> {
+        policy_builder_set_once_check!(
+            self,
+            additional_signature_algorithms,
+            "permitted signature algorithms"
+        );
+
+        Ok(PolicyBuilder {
+            additional_signature_algorithms: Some(signature_algorithm_oids(oids)?),
+            ..self.py_clone(py)
+        })
+    }
+
+    fn deny_signature_algorithms(
+        &self,
+        py: pyo3::Python<'_>,
+        oids: Vec<pyo3::Bound<'_, pyo3::PyAny>>,
+    ) -> CryptographyResult<PolicyBuilder> {
+        policy_builder_set_once_check!(
+            self,
+            denied_signature_algorithms,
+            "denied signature algorithms"
+        );
+
+        Ok(PolicyBuilder {
+            denied_signature_algorithms: Some(signature_algorithm_oids(oids)?),
+            ..self.py_clone(py)
+        })
+    }
+
+    fn min_signature_strength(
+        &self,
+        py: pyo3::Python<'_>,
+        algorithm: pyo3::Bound<'_, pyo3::PyAny>,
+    ) -> CryptographyResult<PolicyBuilder> {
+        policy_builder_set_once_check!(
+            self,
+            minimum_signature_digest_size,
+            "minimum signature strength"
+        );
+
+        if !algorithm.is_instance(&types::HASH_ALGORITHM.get(py)?)? {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyTypeError::new_err(
+                    "Algorithm must be a registered hash algorithm.",
+                ),
+            ));
+        }
+
+        Ok(PolicyBuilder {
+            minimum_signature_digest_size: Some(
+                algorithm
+                    .getattr(pyo3::intern!(py, "digest_size"))?
+                    .extract()?,
+            ),
+            ..self.py_clone(py)
+        })
+    }
+
+    fn client_subject_types(
+        &self,
+        py: pyo3::Python<'_>,
+        subject_types: Vec<pyo3::Bound<'_, pyo3::types::PyType>>,
+    ) -> CryptographyResult<PolicyBuilder> {
+        policy_builder_set_once_check!(self, client_subject_types, "client subject types");
+
+        if subject_types.is_empty() {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyValueError::new_err(
+                    "At least one client subject type must be provided.",
+                ),
+            ));
+        }
+        for subject_type in &subject_types {
+            if !subject_type.is_subclass(&types::GENERAL_NAME.get(py)?)? {
+                return Err(CryptographyError::from(
+                    pyo3::exceptions::PyTypeError::new_err(
+                        "subject_types must be subclasses of GeneralName",
+                    ),
+                ));
+            }
+        }
+
+        Ok(PolicyBuilder {
+            client_subject_types: Some(pyo3::types::PyTuple::new(py, subject_types)?.unbind()),
+            ..self.py_clone(py)
+        })
+    }
+
+    fn require_subject_organization(
+        &self,
+        py: pyo3::Python<'_>,
+        allowlist: Vec<String>,
+    ) -> CryptographyResult<PolicyBuilder> {
+        policy_builder_set_once_check!(
+            self,
+            subject_organizations,
+            "subject organization allowlist"
+        );
+
+        if allowlist.is_empty() {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyValueError::new_err(
+                    "At least one subject organization must be provided.",
+                ),
+            ));
+        }
+
+        Ok(PolicyBuilder {
+            subject_organizations: Some(allowlist),
+            ..self.py_clone(py)
+        })
+    }
+
     fn build_client_verifier(&self, py: pyo3::Python<'_>) -> CryptographyResult<PyClientVerifier> {
         let store = match self.store.as_ref() {
             Some(s) => s.clone_ref(py),
@@ -182,13 +557,29 @@ impl PolicyBuilder {
             }
         };
 
+        if self.leaf_non_tls_key_usage {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyValueError::new_err(
+                    "Non-TLS leaf key usages may only be allowed with a server verifier.",
+                ),
+            ));
+        }
+
+        if self.max_dns_name_labels.is_some() && !self.dns_name_labels_all_names {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyValueError::new_err(
+                    "A client verifier may only limit DNS name labels with all_names=True.",
+                ),
+            ));
+        }
+
         let time = match self.time.as_ref() {
             Some(t) => t.clone(),
             None => datetime_now(py)?,
         };
 
         let policy_definition = OwnedPolicyDefinition::try_new(None, |_subject| {
-            PolicyDefinition::client(
+            let mut definition = PolicyDefinition::client(
                 PyCryptoOps {},
                 time,
                 self.max_chain_depth,
@@ -199,17 +590,40 @@ impl PolicyBuilder {
                     .as_ref()
                     .map(|p| p.get().clone_inner_policy()),
             )
-            .map_err(pyo3::exceptions::PyValueError::new_err)
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+            definition.require_key_identifier_match = !self.name_only_issuer_matching;
+            definition.eku_chaining = self.eku_chaining;
+            definition.ignore_trust_anchor_validity = self.ignore_trust_anchor_validity;
+            definition.ee_any_extended_key_usage = self.leaf_any_extended_key_usage;
+            definition.additional_signature_algorithms = self
+                .additional_signature_algorithms
+                .clone()
+                .unwrap_or_default();
+            definition.denied_signature_algorithms =
+                self.denied_signature_algorithms.clone().unwrap_or_default();
+            definition.minimum_signature_digest_size = self.minimum_signature_digest_size;
+            if self.shaken_leaf {
+                definition.extended_key_usage = EKU_JWT_OID.clone();
+            }
+            definition.ee_check = self.ee_check();
+            definition.issuer_check = self.issuer_check(py);
+            Ok::<_, pyo3::PyErr>(definition)
         })?;
 
         let py_policy = PyPolicy {
             policy_definition,
             subject: py.None(),
+            expiry_warning_threshold: self
+                .expiry_warning_threshold
+                .as_ref()
+                .map(|t| t.clone_ref(py)),
+            warnings_as_errors: self.warnings_as_errors,
         };
 
         Ok(PyClientVerifier {
             py_policy: pyo3::Py::new(py, py_policy)?,
             store,
+            subject_types: self.client_subject_types.as_ref().map(|t| t.clone_ref(py)),
         })
     }
 
@@ -229,6 +643,22 @@ impl PolicyBuilder {
             }
         };
 
+        if self.client_subject_types.is_some() {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyValueError::new_err(
+                    "Client subject types may only be used with a client verifier.",
+                ),
+            ));
+        }
+
+        if self.shaken_leaf {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyValueError::new_err(
+                    "SHAKEN leaves may only be required with a client verifier.",
+                ),
+            ));
+        }
+
         let time = match self.time.as_ref() {
             Some(t) => t.clone(),
             None => datetime_now(py)?,
@@ -244,7 +674,7 @@ impl PolicyBuilder {
                         .expect("subject_owner for ServerVerifier can not be None"),
                 )?;
 
-                PolicyDefinition::server(
+                let mut definition = PolicyDefinition::server(
                     PyCryptoOps {},
                     subject,
                     time,
@@ -256,12 +686,32 @@ impl PolicyBuilder {
                         .as_ref()
                         .map(|p| p.get().clone_inner_policy()),
                 )
-                .map_err(pyo3::exceptions::PyValueError::new_err)
+                .map_err(pyo3::exceptions::PyValueError::new_err)?;
+                definition.require_key_identifier_match = !self.name_only_issuer_matching;
+                definition.eku_chaining = self.eku_chaining;
+                definition.ignore_trust_anchor_validity = self.ignore_trust_anchor_validity;
+                definition.ee_any_extended_key_usage = self.leaf_any_extended_key_usage;
+                definition.additional_signature_algorithms = self
+                    .additional_signature_algorithms
+                    .clone()
+                    .unwrap_or_default();
+                definition.denied_signature_algorithms =
+                    self.denied_signature_algorithms.clone().unwrap_or_default();
+                definition.minimum_signature_digest_size = self.minimum_signature_digest_size;
+                definition.ee_tls_key_usage = !self.leaf_non_tls_key_usage;
+                definition.ee_check = self.ee_check();
+                definition.issuer_check = self.issuer_check(py);
+                Ok::<_, pyo3::PyErr>(definition)
             })?;
 
         let py_policy = PyPolicy {
             policy_definition,
             subject,
+            expiry_warning_threshold: self
+                .expiry_warning_threshold
+                .as_ref()
+                .map(|t| t.clone_ref(py)),
+            warnings_as_errors: self.warnings_as_errors,
         };
 
         Ok(PyServerVerifier {
@@ -303,6 +753,578 @@ pub(crate) struct PyVerifiedClient {
     subjects: Option<pyo3::Py<pyo3::PyAny>>,
     #[pyo3(get)]
     chain: pyo3::Py<pyo3::types::PyList>,
+    #[pyo3(get)]
+    trust_anchor: pyo3::Py<PyCertificate>,
+    #[pyo3(get)]
+    validity_statuses: pyo3::Py<pyo3::types::PyList>,
+}
+
+#[pyo3::pyclass(
+    frozen,
+    name = "VerificationWarning",
+    module = "cryptography.hazmat.bindings._rust.x509"
+)]
+pub(crate) struct PyVerificationWarning {
+    #[pyo3(get)]
+    certificate: pyo3::Py<PyCertificate>,
+    #[pyo3(get)]
+    message: String,
+}
+
+#[pyo3::pymethods]
+impl PyVerificationWarning {
+    fn __repr__(&self, py: pyo3::Python<'_>) -> pyo3::PyResult<String> {
+        let cert_repr = self.certificate.bind(py).repr()?;
+        Ok(format!(
+            "<VerificationWarning(message={:?}, certificate={cert_repr})>",
+            self.message
+        ))
+    }
+}
+
+#[pyo3::pyclass(
+    frozen,
+    name = "VerificationStep",
+    module = "cryptography.hazmat.bindings._rust.x509"
+)]
+pub(crate) struct PyVerificationStep {
+    #[pyo3(get)]
+    kind: &'static str,
+    #[pyo3(get)]
+    certificate: pyo3::Py<PyCertificate>,
+    #[pyo3(get)]
+    issuer: Option<pyo3::Py<PyCertificate>>,
+    #[pyo3(get)]
+    error: Option<String>,
+    #[pyo3(get)]
+    validity_status: PyValidityStatus,
+}
+
+#[pyo3::pymethods]
+impl PyVerificationStep {
+    fn __repr__(&self, py: pyo3::Python<'_>) -> pyo3::PyResult<String> {
+        let cert_repr = self.certificate.bind(py).repr()?;
+        Ok(match &self.error {
+            Some(error) => format!(
+                "<VerificationStep(kind={:?}, certificate={cert_repr}, error={error:?})>",
+                self.kind
+            ),
+            None => format!(
+                "<VerificationStep(kind={:?}, certificate={cert_repr})>",
+                self.kind
+            ),
+        })
+    }
+}
+
+fn step_kind_name(kind: StepKind) -> &'static str {
+    match kind {
+        StepKind::EePolicy => "ee_policy",
+        StepKind::Candidate => "candidate",
+        StepKind::CaPolicy => "ca_policy",
+        StepKind::Signature => "signature",
+        StepKind::NameConstraints => "name_constraints",
+        StepKind::ChainDepth => "chain_depth",
+        StepKind::CandidatesExhausted => "candidates_exhausted",
+        StepKind::TrustAnchor => "trust_anchor",
+    }
+}
+
+/// The verifier's policy and store, and the certificates being verified,
+/// which a trace borrows from.
+struct TraceOwner {
+    py_policy: pyo3::Py<PyPolicy>,
+    store: pyo3::Py<PyStore>,
+    leaf: pyo3::Py<PyCertificate>,
+    intermediates: Vec<pyo3::Py<PyCertificate>>,
+}
+
+struct TraceInputs<'a> {
+    policy: Policy<'a, PyCryptoOps>,
+    leaf: VerificationCertificate<'a, PyCryptoOps>,
+    intermediates: Vec<VerificationCertificate<'a, PyCryptoOps>>,
+}
+
+self_cell::self_cell!(
+    struct OwnedTraceInputs {
+        owner: TraceOwner,
+
+        #[covariant]
+        dependent: TraceInputs,
+    }
+);
+
+type PyTracedVerification<'a> = TracedVerification<'a, 'a, PyCryptoOps>;
+
+self_cell::self_cell!(
+    struct OwnedTrace {
+        owner: OwnedTraceInputs,
+
+        #[not_covariant]
+        dependent: PyTracedVerification,
+    }
+);
+
+/// An iterator over the `VerificationStep`s of path validation, as returned
+/// by `trace()`. Validation only proceeds as far as needed to return the
+/// next step. Failing to build a chain is not an error: the final step
+/// records why.
+#[pyo3::pyclass(module = "cryptography.hazmat.bindings._rust.x509")]
+pub(crate) struct VerificationTrace {
+    trace: OwnedTrace,
+    // The client verifier's `client_subject_types`, checked once the chain
+    // is built.
+    subject_types: Option<pyo3::Py<pyo3::types::PyTuple>>,
+}
+
+impl VerificationTrace {
+    fn new(
+        py: pyo3::Python<'_>,
+        py_policy: &pyo3::Py<PyPolicy>,
+        store: &pyo3::Py<PyStore>,
+        subject_types: Option<&pyo3::Py<pyo3::types::PyTuple>>,
+        leaf: pyo3::Py<PyCertificate>,
+        intermediates: Vec<pyo3::Py<PyCertificate>>,
+    ) -> Self {
+        let owner = TraceOwner {
+            py_policy: py_policy.clone_ref(py),
+            store: store.clone_ref(py),
+            leaf,
+            intermediates,
+        };
+        let inputs = OwnedTraceInputs::new(owner, |owner| TraceInputs {
+            policy: Policy::new(
+                owner.py_policy.get().policy_definition.borrow_dependent(),
+                owner.py_policy.clone_ref(py),
+            ),
+            leaf: VerificationCertificate::new(
+                owner.leaf.get().raw.borrow_dependent(),
+                owner.leaf.clone_ref(py),
+            ),
+            intermediates: owner
+                .intermediates
+                .iter()
+                .map(|i| {
+                    VerificationCertificate::new(i.get().raw.borrow_dependent(), i.clone_ref(py))
+                })
+                .collect(),
+        });
+        let trace = OwnedTrace::new(inputs, |inputs| {
+            let store = inputs.borrow_owner().store.get().raw.borrow_dependent();
+            let inputs = inputs.borrow_dependent();
+            cryptography_x509_verification::verify_traced(
+                &inputs.leaf,
+                &inputs.intermediates,
+                &inputs.policy,
+                store,
+            )
+        });
+        VerificationTrace {
+            trace,
+            subject_types: subject_types.map(|t| t.clone_ref(py)),
+        }
+    }
+}
+
+#[pyo3::pymethods]
+impl VerificationTrace {
+    fn __iter__(slf: pyo3::PyRef<'_, Self>) -> pyo3::PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: pyo3::Python<'_>) -> CryptographyResult<Option<PyVerificationStep>> {
+        let subject_types = self.subject_types.as_ref();
+        self.trace.with_dependent_mut(|inputs, trace| {
+            let py_policy = inputs.borrow_owner().py_policy.get();
+            let validation_time = &py_policy
+                .policy_definition
+                .borrow_dependent()
+                .validation_time;
+            let step = |kind, cert: &VerificationCertificate<'_, PyCryptoOps>, issuer, error| {
+                PyVerificationStep {
+                    kind,
+                    certificate: cert.extra().clone_ref(py),
+                    issuer,
+                    error,
+                    validity_status: policy::validity_status(validation_time, cert.certificate()),
+                }
+            };
+
+            if let Some(s) = trace.next() {
+                return Ok(Some(step(
+                    step_kind_name(s.kind),
+                    &s.cert,
+                    s.issuer.map(|i| i.extra().clone_ref(py)),
+                    s.error,
+                )));
+            }
+            // Exceptions raised by checks made during path building are
+            // re-raised, as they are by verification itself. Otherwise,
+            // the step that failed has already been returned.
+            let chain = match trace.take_result() {
+                Some(Ok(chain)) => chain,
+                Some(Err(e)) => match e.into_callback_error() {
+                    Ok(err) => return Err(err),
+                    Err(_) => return Ok(None),
+                },
+                None => return Ok(None),
+            };
+            // Verification goes on to check the chain once it's built, so
+            // that may fail too.
+            match check_built_chain(py, py_policy, subject_types, &chain) {
+                Ok(()) => Ok(None),
+                Err(e) => {
+                    let e = match e.into_callback_error() {
+                        Ok(err) => return Err(err),
+                        Err(e) => e,
+                    };
+                    let cert = e.certificate().unwrap_or(&chain[0]);
+                    Ok(Some(step("chain_policy", cert, None, Some(e.to_string()))))
+                }
+            }
+        })
+    }
+}
+
+fn signature_algorithm_oids(
+    oids: Vec<pyo3::Bound<'_, pyo3::PyAny>>,
+) -> CryptographyResult<Vec<asn1::ObjectIdentifier>> {
+    if oids.is_empty() {
+        return Err(CryptographyError::from(
+            pyo3::exceptions::PyValueError::new_err(
+                "At least one signature algorithm must be provided.",
+            ),
+        ));
+    }
+    oids.into_iter()
+        .map(|oid| {
+            py_oid_to_oid(oid).map_err(|_| {
+                CryptographyError::from(pyo3::exceptions::PyTypeError::new_err(
+                    "Signature algorithms must be ObjectIdentifiers.",
+                ))
+            })
+        })
+        .collect()
+}
+
+/// Collects the non-fatal conditions configured on `py_policy` for a
+/// successfully built chain. If the policy promotes warnings to errors,
+/// the first warning fails verification instead.
+fn collect_warnings<'chain>(
+    py: pyo3::Python<'_>,
+    py_policy: &PyPolicy,
+    chain: &[VerificationCertificate<'chain, PyCryptoOps>],
+) -> ValidationResult<'chain, Vec<PyVerificationWarning>, PyCryptoOps> {
+    let mut warnings = vec![];
+
+    if let Some(threshold) = &py_policy.expiry_warning_threshold {
+        let message = "certificate expires within the configured warning threshold";
+        for cert in
+            expiring_certificates(py, py_policy, threshold, chain).map_err(callback_error)?
+        {
+            if py_policy.warnings_as_errors {
+                return Err(
+                    ValidationError::new(ValidationErrorKind::Other(message.to_string()))
+                        .set_cert(cert.clone()),
+                );
+            }
+            warnings.push(PyVerificationWarning {
+                certificate: cert.extra().clone_ref(py),
+                message: message.to_string(),
+            });
+        }
+    }
+
+    Ok(warnings)
+}
+
+/// Returns the members of `chain` that expire within `threshold` of the
+/// validation time.
+fn expiring_certificates<'a, 'chain>(
+    py: pyo3::Python<'_>,
+    py_policy: &PyPolicy,
+    threshold: &pyo3::PyObject,
+    chain: &'a [VerificationCertificate<'chain, PyCryptoOps>],
+) -> CryptographyResult<Vec<&'a VerificationCertificate<'chain, PyCryptoOps>>> {
+    let validation_time = datetime_to_py_utc(
+        py,
+        &py_policy
+            .policy_definition
+            .borrow_dependent()
+            .validation_time,
+    )?;
+    let cutoff = validation_time.add(threshold.bind(py))?;
+    let mut expiring = vec![];
+    for cert in chain {
+        let not_after = datetime_to_py_utc(
+            py,
+            cert.certificate().tbs_cert.validity.not_after.as_datetime(),
+        )?;
+        if not_after.le(&cutoff)? {
+            expiring.push(cert);
+        }
+    }
+    Ok(expiring)
+}
+
+/// Returns the leaf's subject alternative names, limited to those that are
+/// instances of `subject_types` if it is given.
+fn client_subjects<'chain>(
+    py: pyo3::Python<'_>,
+    subject_types: Option<&pyo3::Py<pyo3::types::PyTuple>>,
+    leaf: &VerificationCertificate<'chain, PyCryptoOps>,
+) -> ValidationResult<'chain, Option<pyo3::PyObject>, PyCryptoOps> {
+    // NOTE: The `unwrap()` cannot fail, since the underlying policy
+    // enforces the well-formedness of the extension set.
+    let subjects = match &leaf
+        .certificate()
+        .extensions()
+        .ok()
+        .unwrap()
+        .get_extension(&SUBJECT_ALTERNATIVE_NAME_OID)
+    {
+        Some(leaf_san) => {
+            let leaf_gns = leaf_san.value::<SubjectAlternativeName<'_>>()?;
+            Some(
+                parse_general_names(py, &leaf_gns)
+                    .map_err(callback_error)?
+                    .unbind(),
+            )
+        }
+        None => None,
+    };
+
+    let Some(subject_types) = subject_types else {
+        return Ok(subjects);
+    };
+    let filter = || -> pyo3::PyResult<_> {
+        let allowed = pyo3::types::PyList::empty(py);
+        if let Some(subjects) = &subjects {
+            for gn in subjects.bind(py).try_iter()? {
+                let gn = gn?;
+                if gn.is_instance(subject_types.bind(py))? {
+                    allowed.append(gn)?;
+                }
+            }
+        }
+        Ok(allowed)
+    };
+    let allowed = filter().map_err(callback_error)?;
+    if allowed.is_empty() {
+        return Err(ValidationError::new(ValidationErrorKind::Other(
+            "leaf certificate has no subjectAltName entries of an allowed client subject type"
+                .to_string(),
+        ))
+        .set_cert(leaf.clone()));
+    }
+    Ok(Some(allowed.into_any().unbind()))
+}
+
+/// Makes the checks that verification makes on a chain once it's built.
+fn check_built_chain<'chain>(
+    py: pyo3::Python<'_>,
+    py_policy: &PyPolicy,
+    subject_types: Option<&pyo3::Py<pyo3::types::PyTuple>>,
+    chain: &[VerificationCertificate<'chain, PyCryptoOps>],
+) -> ValidationResult<'chain, (), PyCryptoOps> {
+    if subject_types.is_some() {
+        client_subjects(py, subject_types, &chain[0])?;
+    }
+    collect_warnings(py, py_policy, chain)?;
+    Ok(())
+}
+
+/// Wraps an exception raised while making a check during path building, so
+/// that it ends verification and is re-raised as is.
+fn callback_error<'chain>(e: impl Into<CryptographyError>) -> ValidationError<'chain, PyCryptoOps> {
+    ValidationError::new(ValidationErrorKind::CallbackError(e.into()))
+}
+
+/// Builds the error for a candidate issuer rejected by an `issuer_check`.
+/// Path building doesn't attach the certificates to these, so they're
+/// described in the message instead.
+fn issuer_check_error<'chain>(
+    py: pyo3::Python<'_>,
+    msg: String,
+    issuer: &VerificationCertificate<'_, PyCryptoOps>,
+    child: &VerificationCertificate<'_, PyCryptoOps>,
+) -> ValidationError<'chain, PyCryptoOps> {
+    let describe = || -> pyo3::PyResult<String> {
+        Ok(format!(
+            "{msg} (encountered processing {}, issued by {})",
+            child.extra().bind(py).repr()?,
+            issuer.extra().bind(py).repr()?
+        ))
+    };
+    ValidationError::new(ValidationErrorKind::Other(describe().unwrap_or(msg)))
+}
+
+/// Enforces `require_nested_validity`: a certificate's validity period must
+/// lie within that of its issuer.
+fn check_nested_validity<'chain>(
+    issuer: &VerificationCertificate<'chain, PyCryptoOps>,
+    child: &VerificationCertificate<'chain, PyCryptoOps>,
+) -> ValidationResult<'chain, (), PyCryptoOps> {
+    let child_validity = &child.certificate().tbs_cert.validity;
+    let issuer_validity = &issuer.certificate().tbs_cert.validity;
+    if child_validity.not_before.as_datetime() >= issuer_validity.not_before.as_datetime()
+        && child_validity.not_after.as_datetime() <= issuer_validity.not_after.as_datetime()
+    {
+        return Ok(());
+    }
+
+    pyo3::Python::with_gil(|py| {
+        Err(issuer_check_error(
+            py,
+            "certificate's validity period is not within its issuer's validity period".to_string(),
+            issuer,
+            child,
+        ))
+    })
+}
+
+/// Enforces the allowlist configured with `require_subject_organization`:
+/// the leaf must have at least one organizationName, and every one of them
+/// must be on the allowlist.
+fn check_subject_organization<'chain>(
+    py: pyo3::Python<'_>,
+    allowlist: &[String],
+    leaf: &VerificationCertificate<'chain, PyCryptoOps>,
+) -> ValidationResult<'chain, (), PyCryptoOps> {
+    let attributes = leaf
+        .extra()
+        .bind(py)
+        .getattr(pyo3::intern!(py, "subject"))
+        .and_then(|subject| {
+            subject.call_method1(
+                pyo3::intern!(py, "get_attributes_for_oid"),
+                (types::NAME_OID_ORGANIZATION_NAME.get(py)?,),
+            )
+        })
+        .and_then(|attributes| attributes.extract::<Vec<pyo3::Bound<'_, pyo3::PyAny>>>())
+        .map_err(callback_error)?;
+    let mut allowed = !attributes.is_empty();
+    for attribute in &attributes {
+        // Empty and BitString values are never allowed.
+        let value = attribute
+            .getattr(pyo3::intern!(py, "value"))
+            .map_err(callback_error)?
+            .extract::<String>();
+        if !matches!(value, Ok(v) if !v.is_empty() && allowlist.contains(&v)) {
+            allowed = false;
+            break;
+        }
+    }
+    if allowed {
+        return Ok(());
+    }
+
+    let msg = if attributes.is_empty() {
+        "leaf certificate has no subject organization"
+    } else {
+        "leaf certificate's subject organization is not allowed"
+    };
+    Err(ValidationError::new(ValidationErrorKind::Other(
+        msg.to_string(),
+    )))
+}
+
+/// Enforces `max_dns_name_labels`: the dNSName in the leaf's
+/// subjectAltName that matched the policy's subject, or with `all_names`
+/// every dNSName, may not have more labels than the configured maximum. A
+/// wildcard counts as a label.
+fn check_dns_name_labels<'chain>(
+    policy: &Policy<'_, PyCryptoOps>,
+    max_labels: usize,
+    all_names: bool,
+    leaf: &VerificationCertificate<'chain, PyCryptoOps>,
+) -> ValidationResult<'chain, (), PyCryptoOps> {
+    let extensions = leaf.certificate().extensions()?;
+    let Some(san) = extensions.get_extension(&SUBJECT_ALTERNATIVE_NAME_OID) else {
+        return Ok(());
+    };
+    let san = san.value::<SubjectAlternativeName<'_>>()?;
+    let names = match &policy.subject {
+        Some(subject) if !all_names => subject.matching_name(&san).into_iter().collect(),
+        _ => san.collect::<Vec<_>>(),
+    };
+    for gn in names {
+        let GeneralName::DNSName(name) = gn else {
+            continue;
+        };
+        let labels = name.0.split('.').count();
+        if labels > max_labels {
+            return Err(ValidationError::new(ValidationErrorKind::Other(format!(
+                "leaf certificate's dNSName {} has {labels} labels, more than the maximum of \
+                 {max_labels}",
+                name.0
+            ))));
+        }
+    }
+    Ok(())
+}
+
+/// Asks the `revocation_checker` for the status of `child` as issued by
+/// `issuer`, rejecting `issuer` if `child` is revoked (or, when the checker
+/// was configured to fail closed, has an unknown status).
+fn check_revocation<'chain>(
+    py: pyo3::Python<'_>,
+    checker: &pyo3::PyObject,
+    fail_closed: bool,
+    issuer: &VerificationCertificate<'chain, PyCryptoOps>,
+    child: &VerificationCertificate<'chain, PyCryptoOps>,
+) -> ValidationResult<'chain, (), PyCryptoOps> {
+    let status = checker
+        .bind(py)
+        .call1((child.extra().clone_ref(py), issuer.extra().clone_ref(py)))
+        .map_err(callback_error)?;
+    let is_status = |status_type: &types::LazyPyImport| -> ValidationResult<'chain, _, _> {
+        Ok(status.is(&status_type.get(py).map_err(callback_error)?))
+    };
+    let msg = if is_status(&types::OCSP_CERT_STATUS_GOOD)? {
+        return Ok(());
+    } else if is_status(&types::OCSP_CERT_STATUS_REVOKED)? {
+        "certificate is revoked"
+    } else if is_status(&types::OCSP_CERT_STATUS_UNKNOWN)? {
+        if !fail_closed {
+            return Ok(());
+        }
+        "certificate's revocation status is unknown"
+    } else {
+        return Err(callback_error(pyo3::exceptions::PyTypeError::new_err(
+            "The revocation checker must return an OCSPCertStatus.",
+        )));
+    };
+    Err(issuer_check_error(py, msg.to_string(), issuer, child))
+}
+
+/// Enforces `require_shaken_leaf`: the leaf must carry a TNAuthList and
+/// an extendedKeyUsage that includes id-kp-jwt.
+fn check_shaken_leaf<'chain>(
+    leaf: &VerificationCertificate<'chain, PyCryptoOps>,
+) -> ValidationResult<'chain, (), PyCryptoOps> {
+    // NOTE: The `unwrap()` cannot fail, since the underlying policy
+    // enforces the well-formedness of the extension set.
+    let extensions = leaf.certificate().extensions().ok().unwrap();
+    let msg = match extensions.get_extension(&TN_AUTH_LIST_OID) {
+        Some(ext) if ext.value::<TnAuthList<'_>>().is_ok() => {
+            match extensions.get_extension(&EXTENDED_KEY_USAGE_OID) {
+                Some(ext)
+                    if ext
+                        .value::<ExtendedKeyUsage<'_>>()?
+                        .any(|eku| eku == EKU_JWT_OID) =>
+                {
+                    return Ok(())
+                }
+                _ => "leaf certificate's extendedKeyUsage does not include id-kp-jwt",
+            }
+        }
+        Some(_) => "leaf certificate has a malformed TNAuthList",
+        None => "leaf certificate has no TNAuthList",
+    };
+    Err(ValidationError::new(ValidationErrorKind::Other(
+        msg.to_string(),
+    )))
 }
 
 macro_rules! warn_verifier_deprecated_getter {
@@ -334,6 +1356,7 @@ pub(crate) struct PyClientVerifier {
     py_policy: pyo3::Py<PyPolicy>,
     #[pyo3(get)]
     store: pyo3::Py<PyStore>,
+    subject_types: Option<pyo3::Py<pyo3::types::PyTuple>>,
 }
 
 impl PyClientVerifier {
@@ -356,12 +1379,58 @@ impl PyClientVerifier {
         Ok(self.py_policy.get().max_chain_depth())
     }
 
+    #[pyo3(signature = (leaf, intermediates, *, additional_anchors = None))]
     fn verify(
         &self,
         py: pyo3::Python<'_>,
         leaf: pyo3::Py<PyCertificate>,
-        intermediates: Vec<pyo3::Py<PyCertificate>>,
+        intermediates: PyIntermediates,
+        additional_anchors: Option<Vec<pyo3::Py<PyCertificate>>>,
     ) -> CryptographyResult<PyVerifiedClient> {
+        let intermediates = intermediates.into_certificates(py)?;
+        Ok(self
+            .verify_inner(py, leaf, intermediates, additional_anchors)?
+            .0)
+    }
+
+    #[pyo3(signature = (leaf, intermediates, *, additional_anchors = None))]
+    fn verify_with_warnings(
+        &self,
+        py: pyo3::Python<'_>,
+        leaf: pyo3::Py<PyCertificate>,
+        intermediates: PyIntermediates,
+        additional_anchors: Option<Vec<pyo3::Py<PyCertificate>>>,
+    ) -> CryptographyResult<(PyVerifiedClient, Vec<PyVerificationWarning>)> {
+        let intermediates = intermediates.into_certificates(py)?;
+        self.verify_inner(py, leaf, intermediates, additional_anchors)
+    }
+
+    fn trace(
+        &self,
+        py: pyo3::Python<'_>,
+        leaf: pyo3::Py<PyCertificate>,
+        intermediates: PyIntermediates,
+    ) -> CryptographyResult<VerificationTrace> {
+        let intermediates = intermediates.into_certificates(py)?;
+        Ok(VerificationTrace::new(
+            py,
+            &self.py_policy,
+            &self.store,
+            self.subject_types.as_ref(),
+            leaf,
+            intermediates,
+        ))
+    }
+}
+
+impl PyClientVerifier {
+    fn verify_inner(
+        &self,
+        py: pyo3::Python<'_>,
+        leaf: pyo3::Py<PyCertificate>,
+        intermediates: Vec<pyo3::Py<PyCertificate>>,
+        additional_anchors: Option<Vec<pyo3::Py<PyCertificate>>>,
+    ) -> CryptographyResult<(PyVerifiedClient, Vec<PyVerificationWarning>)> {
         let policy = Policy::new(self.as_policy_def(), self.py_policy.clone_ref(py));
         let store = self.store.get();
 
@@ -372,39 +1441,39 @@ impl PyClientVerifier {
 
         let v = VerificationCertificate::new(leaf.get().raw.borrow_dependent(), leaf.clone_ref(py));
 
-        let chain = cryptography_x509_verification::verify(
+        let chain = verify_with_additional_anchors(
+            py,
             &v,
             &intermediates,
             &policy,
-            store.raw.borrow_dependent(),
-        )
-        .or_else(|e| handle_validation_error(py, e))?;
+            store,
+            additional_anchors.as_deref(),
+        )?;
 
         let py_chain = pyo3::types::PyList::empty(py);
+        let validity_statuses = pyo3::types::PyList::empty(py);
         for c in &chain {
             py_chain.append(c.extra())?;
+            validity_statuses.append(policy::validity_status(
+                &policy.validation_time,
+                c.certificate(),
+            ))?;
         }
 
-        // NOTE: The `unwrap()` cannot fail, since the underlying policy
-        // enforces the well-formedness of the extension set.
-        let subjects = match &chain[0]
-            .certificate()
-            .extensions()
-            .ok()
-            .unwrap()
-            .get_extension(&SUBJECT_ALTERNATIVE_NAME_OID)
-        {
-            Some(leaf_san) => {
-                let leaf_gns = leaf_san.value::<SubjectAlternativeName<'_>>()?;
-                Some(parse_general_names(py, &leaf_gns)?.unbind())
-            }
-            None => None,
-        };
-
-        Ok(PyVerifiedClient {
-            subjects,
-            chain: py_chain.unbind(),
-        })
+        let subjects = client_subjects(py, self.subject_types.as_ref(), &chain[0])
+            .or_else(|e| handle_validation_error(py, e))?;
+        let warnings = collect_warnings(py, self.py_policy.get(), &chain)
+            .or_else(|e| handle_validation_error(py, e))?;
+
+        Ok((
+            PyVerifiedClient {
+                subjects,
+                chain: py_chain.unbind(),
+                trust_anchor: chain[chain.len() - 1].extra().clone_ref(py),
+                validity_statuses: validity_statuses.unbind(),
+            },
+            warnings,
+        ))
     }
 }
 
@@ -446,12 +1515,95 @@ impl PyServerVerifier {
         Ok(self.py_policy.get().max_chain_depth())
     }
 
+    #[pyo3(signature = (leaf, intermediates, *, additional_anchors = None))]
     fn verify<'p>(
         &self,
         py: pyo3::Python<'p>,
         leaf: pyo3::Py<PyCertificate>,
-        intermediates: Vec<pyo3::Py<PyCertificate>>,
+        intermediates: PyIntermediates,
+        additional_anchors: Option<Vec<pyo3::Py<PyCertificate>>>,
     ) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyList>> {
+        let intermediates = intermediates.into_certificates(py)?;
+        Ok(self
+            .verify_inner(py, leaf, intermediates, additional_anchors)?
+            .0)
+    }
+
+    #[pyo3(signature = (leaf, intermediates, *, additional_anchors = None))]
+    fn verify_with_warnings<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+        leaf: pyo3::Py<PyCertificate>,
+        intermediates: PyIntermediates,
+        additional_anchors: Option<Vec<pyo3::Py<PyCertificate>>>,
+    ) -> CryptographyResult<(
+        pyo3::Bound<'p, pyo3::types::PyList>,
+        Vec<PyVerificationWarning>,
+    )> {
+        let intermediates = intermediates.into_certificates(py)?;
+        self.verify_inner(py, leaf, intermediates, additional_anchors)
+    }
+
+    fn trace(
+        &self,
+        py: pyo3::Python<'_>,
+        leaf: pyo3::Py<PyCertificate>,
+        intermediates: PyIntermediates,
+    ) -> CryptographyResult<VerificationTrace> {
+        let intermediates = intermediates.into_certificates(py)?;
+        Ok(VerificationTrace::new(
+            py,
+            &self.py_policy,
+            &self.store,
+            None,
+            leaf,
+            intermediates,
+        ))
+    }
+
+    #[pyo3(signature = (leaf, intermediates, *, additional_anchors = None))]
+    fn verify_with_matched_name<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+        leaf: pyo3::Py<PyCertificate>,
+        intermediates: PyIntermediates,
+        additional_anchors: Option<Vec<pyo3::Py<PyCertificate>>>,
+    ) -> CryptographyResult<(
+        pyo3::Bound<'p, pyo3::types::PyList>,
+        pyo3::Bound<'p, pyo3::PyAny>,
+    )> {
+        let intermediates = intermediates.into_certificates(py)?;
+        let (chain, _) =
+            self.verify_inner(py, leaf.clone_ref(py), intermediates, additional_anchors)?;
+
+        // NOTE: The chain was built, so the leaf has a SAN entry matching
+        // the subject.
+        let leaf_san = leaf
+            .get()
+            .get_extension(py, &SUBJECT_ALTERNATIVE_NAME_OID)?
+            .unwrap()
+            .value::<SubjectAlternativeName<'_>>()?;
+        let matched_name = self
+            .as_policy_def()
+            .subject
+            .as_ref()
+            .and_then(|subject| subject.matching_name(&leaf_san))
+            .unwrap();
+        Ok((chain, parse_general_name(py, matched_name)?))
+    }
+}
+
+impl PyServerVerifier {
+    fn verify_inner<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+        leaf: pyo3::Py<PyCertificate>,
+        intermediates: Vec<pyo3::Py<PyCertificate>>,
+        additional_anchors: Option<Vec<pyo3::Py<PyCertificate>>>,
+    ) -> CryptographyResult<(
+        pyo3::Bound<'p, pyo3::types::PyList>,
+        Vec<PyVerificationWarning>,
+    )> {
         let policy = Policy::new(self.as_policy_def(), self.py_policy.clone_ref(py));
         let store = self.store.get();
 
@@ -462,22 +1614,80 @@ impl PyServerVerifier {
 
         let v = VerificationCertificate::new(leaf.get().raw.borrow_dependent(), leaf.clone_ref(py));
 
-        let chain = cryptography_x509_verification::verify(
+        let chain = verify_with_additional_anchors(
+            py,
             &v,
             &intermediates,
             &policy,
-            store.raw.borrow_dependent(),
-        )
-        .or_else(|e| handle_validation_error(py, e))?;
+            store,
+            additional_anchors.as_deref(),
+        )?;
+        let warnings = collect_warnings(py, self.py_policy.get(), &chain)
+            .or_else(|e| handle_validation_error(py, e))?;
 
         let result = pyo3::types::PyList::empty(py);
         for c in chain {
             result.append(c.extra())?;
         }
-        Ok(result)
+        Ok((result, warnings))
     }
 }
 
+/// Untrusted intermediates, either as certificates or as a DER or PEM
+/// encoded degenerate PKCS#7 structure containing them.
+#[derive(pyo3::FromPyObject)]
+pub(crate) enum PyIntermediates {
+    Pkcs7(pyo3::Py<pyo3::types::PyBytes>),
+    Certificates(Vec<pyo3::Py<PyCertificate>>),
+}
+
+impl PyIntermediates {
+    fn into_certificates(
+        self,
+        py: pyo3::Python<'_>,
+    ) -> CryptographyResult<Vec<pyo3::Py<PyCertificate>>> {
+        match self {
+            PyIntermediates::Certificates(certs) => Ok(certs),
+            PyIntermediates::Pkcs7(data) => {
+                let data = data.as_bytes(py);
+                let certs = if data.starts_with(b"-----BEGIN ") {
+                    load_pem_pkcs7_certificates(py, data)?
+                } else {
+                    load_der_pkcs7_certificates(py, data)?
+                };
+                Ok(certs.extract()?)
+            }
+        }
+    }
+}
+
+/// Builds a chain to a member of `store`, or to one of `additional_anchors`,
+/// which are trusted only for this call.
+fn verify_with_additional_anchors<'a>(
+    py: pyo3::Python<'_>,
+    leaf: &VerificationCertificate<'a, PyCryptoOps>,
+    intermediates: &[VerificationCertificate<'a, PyCryptoOps>],
+    policy: &Policy<'_, PyCryptoOps>,
+    store: &'a PyStore,
+    additional_anchors: Option<&'a [pyo3::Py<PyCertificate>]>,
+) -> CryptographyResult<cryptography_x509_verification::Chain<'a, PyCryptoOps>> {
+    let extended_store;
+    let store = match additional_anchors {
+        Some(anchors) if !anchors.is_empty() => {
+            extended_store = store
+                .raw
+                .borrow_dependent()
+                .with_additional(anchors.iter().map(|a| {
+                    VerificationCertificate::new(a.get().raw.borrow_dependent(), a.clone_ref(py))
+                }));
+            &extended_store
+        }
+        _ => store.raw.borrow_dependent(),
+    };
+    cryptography_x509_verification::verify(leaf, intermediates, policy, store)
+        .or_else(|e| handle_validation_error(py, e))
+}
+
 fn build_subject_owner(
     py: pyo3::Python<'_>,
     subject: &pyo3::Py<pyo3::PyAny>,
@@ -525,10 +1735,43 @@ fn build_subject<'a>(
     }
 }
 
+#[pyo3::pyfunction]
+pub(crate) fn matching_subjects<'p>(
+    py: pyo3::Python<'p>,
+    leaf: pyo3::PyRef<'_, PyCertificate>,
+    subjects: Vec<pyo3::Py<pyo3::PyAny>>,
+) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyList>> {
+    // Reject malformed candidates up front, even if the leaf has no SAN.
+    let subject_owners = subjects
+        .iter()
+        .map(|s| build_subject_owner(py, s))
+        .collect::<pyo3::PyResult<Vec<_>>>()?;
+    let parsed_subjects = subject_owners
+        .iter()
+        .map(|s| build_subject(py, s))
+        .collect::<pyo3::PyResult<Vec<_>>>()?;
+
+    let matched = pyo3::types::PyList::empty(py);
+    let Some(leaf_san) = leaf.get_extension(py, &SUBJECT_ALTERNATIVE_NAME_OID)? else {
+        return Ok(matched);
+    };
+    let leaf_san = leaf_san.value::<SubjectAlternativeName<'_>>()?;
+    for (py_subject, subject) in subjects.iter().zip(&parsed_subjects) {
+        if subject.matches(&leaf_san) {
+            matched.append(py_subject.bind(py))?;
+        }
+    }
+    Ok(matched)
+}
+
 fn handle_validation_error<T>(
     py: pyo3::Python<'_>,
     e: cryptography_x509_verification::ValidationError<'_, PyCryptoOps>,
 ) -> CryptographyResult<T> {
+    let e = match e.into_callback_error() {
+        Ok(err) => return Err(err),
+        Err(e) => e,
+    };
     let mut msg = format!("validation failed: {e}");
     if let Some(cert) = e.certificate() {
         let cert_repr = cert.extra().bind(py).repr()?;
@@ -575,6 +1818,46 @@ impl PyStore {
             }),
         })
     }
+
+    /// Loads every certificate found in the regular files of `path`, as
+    /// used by `c_rehash` style CA directories. Symlinks are followed, each
+    /// distinct certificate is loaded once, and files that don't contain
+    /// PEM or DER certificates are skipped.
+    #[staticmethod]
+    fn from_directory(py: pyo3::Python<'_>, path: std::path::PathBuf) -> CryptographyResult<Self> {
+        let mut paths = std::fs::read_dir(path)
+            .map_err(pyo3::PyErr::from)?
+            .map(|entry| Ok(entry?.path()))
+            .collect::<std::io::Result<Vec<_>>>()
+            .map_err(pyo3::PyErr::from)?;
+        paths.sort();
+
+        let mut seen = HashSet::new();
+        let mut certs = vec![];
+        for path in paths {
+            // NOTE: `metadata` follows symlinks, so dangling links and links
+            // to directories are skipped along with other non-files.
+            if !std::fs::metadata(&path).map_or(false, |m| m.is_file()) {
+                continue;
+            }
+            let Ok(data) = std::fs::read(&path) else {
+                continue;
+            };
+            let loaded = if data.windows(11).any(|w| w == b"-----BEGIN ") {
+                load_pem_x509_certificates(py, &data).ok()
+            } else {
+                load_der_x509_certificate(py, pyo3::types::PyBytes::new(py, &data).unbind(), None)
+                    .ok()
+                    .map(|cert| vec![cert])
+            };
+            for cert in loaded.into_iter().flatten() {
+                if seen.insert(cert.raw.borrow_owner().as_bytes(py).to_vec()) {
+                    certs.push(pyo3::Py::new(py, cert)?);
+                }
+            }
+        }
+        Ok(Self::new(py, certs)?)
+    }
 }
 
 #[cfg(test)]
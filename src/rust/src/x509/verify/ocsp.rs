@@ -0,0 +1,167 @@
+// This file is dual licensed under the terms of the Apache License, Version
+// 2.0, and the BSD License. See the LICENSE file in the root of this repository
+// for complete details.
+
+use cryptography_x509_verification::ops::VerificationCertificate;
+use cryptography_x509_verification::policy::{Policy, PolicyDefinition};
+use pyo3::types::{PyAnyMethods, PyListMethods};
+
+use super::{
+    handle_validation_error, OwnedPolicyDefinition, PyCryptoOps, PyPolicy, PyStore,
+    VerificationError,
+};
+use crate::error::{CryptographyError, CryptographyResult};
+use crate::exceptions;
+use crate::x509::certificate::Certificate as PyCertificate;
+use crate::x509::ocsp_resp::{OCSPResponse, OCSPSingleResponse, Responder, ResponderError};
+
+pyo3::create_exception!(
+    cryptography.hazmat.bindings._rust.x509,
+    OCSPVerificationError,
+    VerificationError
+);
+
+#[pyo3::pyclass(
+    frozen,
+    eq,
+    module = "cryptography.x509.verification",
+    name = "OCSPVerificationStage"
+)]
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub(crate) enum PyOCSPVerificationStage {
+    #[pyo3(name = "SIGNATURE")]
+    Signature,
+    #[pyo3(name = "EKU")]
+    Eku,
+    #[pyo3(name = "CHAIN")]
+    Chain,
+}
+
+fn stage_error(
+    py: pyo3::Python<'_>,
+    stage: PyOCSPVerificationStage,
+    msg: String,
+) -> CryptographyError {
+    let err = OCSPVerificationError::new_err(msg);
+    match err.value(py).setattr(pyo3::intern!(py, "stage"), stage) {
+        Ok(()) => CryptographyError::from(err),
+        Err(e) => CryptographyError::from(e),
+    }
+}
+
+#[pyo3::pyfunction]
+pub(crate) fn verify_ocsp_response(
+    py: pyo3::Python<'_>,
+    response: pyo3::PyRef<'_, OCSPResponse>,
+    certificate: pyo3::PyRef<'_, PyCertificate>,
+    issuer: pyo3::Py<PyCertificate>,
+    store: pyo3::Py<PyStore>,
+) -> CryptographyResult<OCSPSingleResponse> {
+    let resp = response.requires_successful_response()?;
+    let single_resp = response.single_response_for_certificate(py, &certificate, issuer.get())?;
+
+    // The responder is found the same way as by
+    // `OCSPResponse.verify_single_response`, but each reason it may be
+    // rejected is reported with its own stage.
+    let certs = response
+        .certificates(py)?
+        .iter()
+        .map(|c| Ok(c.extract::<pyo3::Py<PyCertificate>>()?))
+        .collect::<CryptographyResult<Vec<_>>>()?;
+    let (responder, responder_cert) = match response.find_responder(py, issuer.get())? {
+        Ok(Responder::Issuer) => (None, &issuer),
+        Ok(Responder::Delegate { index, .. }) => (Some(index), &certs[index]),
+        Err(ResponderError::Unidentified) => {
+            return Err(stage_error(
+                py,
+                PyOCSPVerificationStage::Signature,
+                "validation failed: the response's signer is neither the issuer nor a \
+                 responder whose certificate is included in the response"
+                    .to_string(),
+            ))
+        }
+        Err(ResponderError::MissingEku) => {
+            return Err(stage_error(
+                py,
+                PyOCSPVerificationStage::Eku,
+                "validation failed: the delegated responder's certificate does not have the \
+                 OCSP signing extended key usage"
+                    .to_string(),
+            ))
+        }
+        Err(ResponderError::WrongIssuer) => {
+            return Err(stage_error(
+                py,
+                PyOCSPVerificationStage::Chain,
+                "validation failed: the delegated responder's certificate was not issued by \
+                 the certificate's issuer"
+                    .to_string(),
+            ))
+        }
+    };
+
+    match response.verify_response_signature(py, responder_cert.get().raw.borrow_dependent()) {
+        Ok(()) => {}
+        Err(CryptographyError::Py(e)) if e.is_instance_of::<exceptions::InvalidSignature>(py) => {
+            return Err(stage_error(
+                py,
+                PyOCSPVerificationStage::Signature,
+                "validation failed: the response's signature is invalid".to_string(),
+            ));
+        }
+        Err(e) => return Err(e),
+    }
+
+    // The responder's certificate must have been valid when it produced
+    // the response.
+    let policy_definition = OwnedPolicyDefinition::try_new(None, |_subject| {
+        PolicyDefinition::ocsp_responder(
+            PyCryptoOps {},
+            resp.tbs_response_data.produced_at.as_datetime().clone(),
+            None,
+        )
+        .map_err(pyo3::exceptions::PyValueError::new_err)
+    })?;
+    let py_policy = pyo3::Py::new(
+        py,
+        PyPolicy {
+            policy_definition,
+            subject: py.None(),
+            expiry_warning_threshold: None,
+            warnings_as_errors: false,
+        },
+    )?;
+    let policy = Policy::new(
+        py_policy.get().policy_definition.borrow_dependent(),
+        py_policy.clone_ref(py),
+    );
+    let intermediates = certs
+        .iter()
+        .chain(responder.map(|_| &issuer))
+        .map(|i| VerificationCertificate::new(i.get().raw.borrow_dependent(), i.clone_ref(py)))
+        .collect::<Vec<_>>();
+    let v = VerificationCertificate::new(
+        responder_cert.get().raw.borrow_dependent(),
+        responder_cert.clone_ref(py),
+    );
+    match cryptography_x509_verification::verify(
+        &v,
+        &intermediates,
+        &policy,
+        store.get().raw.borrow_dependent(),
+    )
+    .or_else(|e| handle_validation_error(py, e))
+    {
+        Ok(_) => {}
+        Err(CryptographyError::Py(e)) if e.is_instance_of::<VerificationError>(py) => {
+            return Err(stage_error(
+                py,
+                PyOCSPVerificationStage::Chain,
+                e.value(py).str()?.to_string(),
+            ));
+        }
+        Err(e) => return Err(e),
+    }
+
+    Ok(single_resp)
+}
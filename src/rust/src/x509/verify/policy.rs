@@ -1,12 +1,33 @@
+use cryptography_x509::certificate::Certificate;
+
 use super::OwnedPolicyDefinition;
 use crate::asn1::oid_to_py_oid;
+use crate::x509::certificate::Certificate as PyCertificate;
 use crate::x509::datetime_to_py;
 
+#[pyo3::pyclass(
+    frozen,
+    eq,
+    module = "cryptography.x509.verification",
+    name = "ValidityStatus"
+)]
+#[derive(PartialEq, Eq, Clone)]
+pub(crate) enum PyValidityStatus {
+    #[pyo3(name = "VALID")]
+    Valid,
+    #[pyo3(name = "NOT_YET_VALID")]
+    NotYetValid,
+    #[pyo3(name = "EXPIRED")]
+    Expired,
+}
+
 /// Python-accessible wrapper for a cryptography_x509_verification::policy::Policy.
 #[pyo3::pyclass(module = "cryptography.x509.verification", name = "Policy", frozen)]
 pub(crate) struct PyPolicy {
     pub(super) policy_definition: OwnedPolicyDefinition,
     pub(super) subject: pyo3::PyObject,
+    pub(super) expiry_warning_threshold: Option<pyo3::PyObject>,
+    pub(super) warnings_as_errors: bool,
 }
 
 #[pyo3::pymethods]
@@ -39,4 +60,26 @@ impl PyPolicy {
             .borrow_dependent()
             .minimum_rsa_modulus
     }
+
+    fn validity_status(&self, certificate: pyo3::PyRef<'_, PyCertificate>) -> PyValidityStatus {
+        validity_status(
+            &self.policy_definition.borrow_dependent().validation_time,
+            certificate.raw.borrow_dependent(),
+        )
+    }
+}
+
+/// Compares `cert`'s validity period against `validation_time`.
+pub(super) fn validity_status(
+    validation_time: &asn1::DateTime,
+    cert: &Certificate<'_>,
+) -> PyValidityStatus {
+    let validity = &cert.tbs_cert.validity;
+    if validation_time < validity.not_before.as_datetime() {
+        PyValidityStatus::NotYetValid
+    } else if validation_time > validity.not_after.as_datetime() {
+        PyValidityStatus::Expired
+    } else {
+        PyValidityStatus::Valid
+    }
 }
@@ -0,0 +1,255 @@
+// This file is dual licensed under the terms of the Apache License, Version
+// 2.0, and the BSD License. See the LICENSE file in the root of this repository
+// for complete details.
+
+use cryptography_x509::pkcs7;
+use cryptography_x509::tsp::TSTInfo;
+use cryptography_x509_verification::ops::VerificationCertificate;
+use cryptography_x509_verification::policy::{Policy, PolicyDefinition};
+use pyo3::types::{PyAnyMethods, PyBytesMethods};
+
+use super::{
+    handle_validation_error, OwnedPolicyDefinition, PyCryptoOps, PyPolicy, PyStore,
+    VerificationError,
+};
+use crate::backend::keys;
+use crate::error::{CryptographyError, CryptographyResult};
+use crate::exceptions;
+use crate::pkcs7::{
+    hash_algorithm_for, signer_signature_algorithm, PKCS7_CONTENT_TYPE_OID,
+    PKCS7_MESSAGE_DIGEST_OID,
+};
+use crate::x509::certificate::{load_der_x509_certificate, Certificate as PyCertificate};
+use crate::x509::common::datetime_to_py_utc;
+use crate::x509::{ocsp, sign};
+
+fn verification_error(msg: &str) -> CryptographyError {
+    CryptographyError::from(VerificationError::new_err(format!(
+        "validation failed: {msg}"
+    )))
+}
+
+#[pyo3::pyfunction]
+pub(crate) fn verify_timestamp_token<'p>(
+    py: pyo3::Python<'p>,
+    token: &[u8],
+    data: &[u8],
+    store: pyo3::Py<PyStore>,
+) -> CryptographyResult<pyo3::Bound<'p, pyo3::PyAny>> {
+    let gen_time = verify_token(py, token, data, store)?;
+    Ok(datetime_to_py_utc(py, &gen_time)?)
+}
+
+#[pyo3::pyfunction]
+pub(crate) fn verify_timestamp_token_within_validity<'p>(
+    py: pyo3::Python<'p>,
+    token: &[u8],
+    data: &[u8],
+    store: pyo3::Py<PyStore>,
+    certificate: pyo3::Bound<'p, PyCertificate>,
+) -> CryptographyResult<pyo3::Bound<'p, pyo3::PyAny>> {
+    let gen_time = verify_token(py, token, data, store)?;
+    let validity = &certificate.get().raw.borrow_dependent().tbs_cert.validity;
+    if &gen_time < validity.not_before.as_datetime() || &gen_time > validity.not_after.as_datetime()
+    {
+        let cert_repr = certificate.repr()?;
+        return Err(CryptographyError::from(VerificationError::new_err(
+            format!(
+                "validation failed: timestamp is not within the certificate's validity period \
+                 (encountered processing {cert_repr})"
+            ),
+        )));
+    }
+    Ok(datetime_to_py_utc(py, &gen_time)?)
+}
+
+/// Verifies `token` over `data` against `store`, returning its genTime.
+fn verify_token(
+    py: pyo3::Python<'_>,
+    token: &[u8],
+    data: &[u8],
+    store: pyo3::Py<PyStore>,
+) -> CryptographyResult<asn1::DateTime> {
+    let content_info = asn1::parse_single::<pkcs7::ContentInfo<'_>>(token)?;
+    let signed_data = match content_info.content {
+        pkcs7::Content::SignedData(signed_data) => signed_data.into_inner(),
+        _ => {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyValueError::new_err(
+                    "The timestamp token must contain SignedData.",
+                ),
+            ))
+        }
+    };
+    let tst_info_der = match signed_data.content_info.content {
+        pkcs7::Content::TstInfo(Some(content)) => content.into_inner(),
+        _ => {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyValueError::new_err(
+                    "The timestamp token must encapsulate a TSTInfo.",
+                ),
+            ))
+        }
+    };
+    let tst_info = asn1::parse_single::<TSTInfo<'_>>(tst_info_der)?;
+
+    let mut signer_infos = signed_data.signer_infos.unwrap_read().clone();
+    let signer_info = match (signer_infos.next(), signer_infos.next()) {
+        (Some(signer_info), None) => signer_info,
+        _ => {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyValueError::new_err(
+                    "The timestamp token must have exactly one signer.",
+                ),
+            ))
+        }
+    };
+
+    // RFC 3161 requires the TSA's signature to cover signed attributes
+    // binding both the content type and the digest of the TSTInfo.
+    let signed_attributes = match &signer_info.authenticated_attributes {
+        Some(attributes) => attributes,
+        None => {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyValueError::new_err(
+                    "The timestamp token signer must have signed attributes.",
+                ),
+            ))
+        }
+    };
+    let mut content_type = None;
+    let mut message_digest = None;
+    for attribute in signed_attributes.unwrap_read().clone() {
+        let mut values = attribute.values.unwrap_read().clone();
+        let value = match (values.next(), values.next()) {
+            (Some(value), None) => value,
+            _ => continue,
+        };
+        if attribute.type_id == PKCS7_CONTENT_TYPE_OID {
+            content_type = Some(value.parse::<asn1::ObjectIdentifier>()?);
+        } else if attribute.type_id == PKCS7_MESSAGE_DIGEST_OID {
+            message_digest = Some(value.parse::<&[u8]>()?);
+        }
+    }
+    if content_type != Some(pkcs7::PKCS7_TST_INFO_OID) {
+        return Err(verification_error(
+            "signed contentType attribute does not identify a TSTInfo",
+        ));
+    }
+    let py_digest_alg = hash_algorithm_for(py, &signer_info.digest_algorithm)?;
+    let tst_info_digest = ocsp::hash_data(py, &py_digest_alg, tst_info_der)?;
+    if message_digest != Some(tst_info_digest.as_bytes()) {
+        return Err(verification_error(
+            "signed messageDigest attribute does not match the TSTInfo",
+        ));
+    }
+
+    let certificates = match &signed_data.certificates {
+        Some(certificates) => certificates
+            .unwrap_read()
+            .clone()
+            .map(|cert| {
+                let der = asn1::write_single(&cert)?;
+                let py_cert = load_der_x509_certificate(
+                    py,
+                    pyo3::types::PyBytes::new(py, &der).unbind(),
+                    None,
+                )?;
+                Ok(pyo3::Py::new(py, py_cert)?)
+            })
+            .collect::<CryptographyResult<Vec<pyo3::Py<PyCertificate>>>>()?,
+        None => vec![],
+    };
+    let sid = &signer_info.issuer_and_serial_number;
+    let (signers, intermediates): (Vec<_>, Vec<_>) = certificates.into_iter().partition(|c| {
+        let tbs_cert = &c.get().raw.borrow_dependent().tbs_cert;
+        tbs_cert.serial == sid.serial_number && tbs_cert.issuer == sid.issuer
+    });
+    let signer = match signers.into_iter().next() {
+        Some(signer) => signer,
+        None => {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyValueError::new_err(
+                    "The timestamp token does not include the TSA's certificate.",
+                ),
+            ))
+        }
+    };
+
+    let signer_key = keys::load_der_public_key_bytes(
+        py,
+        signer
+            .get()
+            .raw
+            .borrow_dependent()
+            .tbs_cert
+            .spki
+            .tlv()
+            .full_data(),
+    )?;
+    let signature_algorithm = signer_signature_algorithm(
+        &signer_info.digest_algorithm,
+        &signer_info.digest_encryption_algorithm,
+    )?;
+    match sign::verify_signature_with_signature_algorithm(
+        py,
+        signer_key,
+        &signature_algorithm,
+        signer_info.encrypted_digest,
+        &asn1::write_single(signed_attributes)?,
+    ) {
+        Ok(()) => {}
+        Err(CryptographyError::Py(e)) if e.is_instance_of::<exceptions::InvalidSignature>(py) => {
+            return Err(verification_error("TSA signature is invalid"));
+        }
+        Err(e) => return Err(e),
+    }
+
+    // The TSA certificate must have been valid when the timestamp was issued.
+    let policy_definition = OwnedPolicyDefinition::try_new(None, |_subject| {
+        PolicyDefinition::timestamping(
+            PyCryptoOps {},
+            tst_info.gen_time.as_datetime().clone(),
+            None,
+        )
+        .map_err(pyo3::exceptions::PyValueError::new_err)
+    })?;
+    let py_policy = pyo3::Py::new(
+        py,
+        PyPolicy {
+            policy_definition,
+            subject: py.None(),
+            expiry_warning_threshold: None,
+            warnings_as_errors: false,
+        },
+    )?;
+    let policy = Policy::new(
+        py_policy.get().policy_definition.borrow_dependent(),
+        py_policy.clone_ref(py),
+    );
+    let intermediates = intermediates
+        .iter()
+        .map(|i| VerificationCertificate::new(i.get().raw.borrow_dependent(), i.clone_ref(py)))
+        .collect::<Vec<_>>();
+    let v = VerificationCertificate::new(signer.get().raw.borrow_dependent(), signer.clone_ref(py));
+    cryptography_x509_verification::verify(
+        &v,
+        &intermediates,
+        &policy,
+        store.get().raw.borrow_dependent(),
+    )
+    .or_else(|e| handle_validation_error(py, e))?;
+
+    let message_imprint = &tst_info.message_imprint;
+    let py_imprint_alg = hash_algorithm_for(py, &message_imprint.hash_algorithm)?;
+    let data_digest = ocsp::hash_data(py, &py_imprint_alg, data)?;
+    if data_digest.as_bytes() != message_imprint.hashed_message {
+        return Err(CryptographyError::from(
+            exceptions::InvalidSignature::new_err(
+                "The timestamp token's messageImprint does not match the data.",
+            ),
+        ));
+    }
+
+    Ok(tst_info.gen_time.as_datetime().clone())
+}
@@ -0,0 +1,255 @@
+// This file is dual licensed under the terms of the Apache License, Version
+// 2.0, and the BSD License. See the LICENSE file in the root of this repository
+// for complete details.
+
+use std::collections::HashSet;
+
+use cryptography_x509::extensions::Extensions;
+use cryptography_x509::ocsp_resp::CertStatus;
+use cryptography_x509::oid;
+use pyo3::types::{PyAnyMethods, PyDictMethods, PyListMethods};
+
+use super::{PyIntermediates, PyServerVerifier, VerificationError};
+use crate::error::{CryptographyError, CryptographyResult};
+use crate::x509::certificate::Certificate as PyCertificate;
+use crate::x509::ocsp_resp::OCSPResponse;
+use crate::x509::{sct, sign};
+
+pyo3::create_exception!(
+    cryptography.hazmat.bindings._rust.x509,
+    TLSVerificationError,
+    VerificationError
+);
+
+// The `status_request` TLS feature (RFC 7633), i.e. OCSP Must-Staple.
+const TLS_FEATURE_STATUS_REQUEST: u64 = 5;
+
+#[pyo3::pyclass(
+    frozen,
+    eq,
+    module = "cryptography.x509.verification",
+    name = "TLSVerificationStage"
+)]
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub(crate) enum PyTLSVerificationStage {
+    #[pyo3(name = "CHAIN")]
+    Chain,
+    #[pyo3(name = "MUST_STAPLE")]
+    MustStaple,
+    #[pyo3(name = "OCSP")]
+    Ocsp,
+    #[pyo3(name = "SCT")]
+    Sct,
+}
+
+fn stage_error(
+    py: pyo3::Python<'_>,
+    stage: PyTLSVerificationStage,
+    msg: String,
+) -> CryptographyError {
+    let err = TLSVerificationError::new_err(msg);
+    match err.value(py).setattr(pyo3::intern!(py, "stage"), stage) {
+        Ok(()) => CryptographyError::from(err),
+        Err(e) => CryptographyError::from(e),
+    }
+}
+
+/// Converts a `ValueError` or `InvalidSignature` raised while checking a
+/// stage into a `TLSVerificationError` for that stage.
+fn into_stage_error(
+    py: pyo3::Python<'_>,
+    stage: PyTLSVerificationStage,
+    what: &str,
+    e: CryptographyError,
+) -> CryptographyError {
+    match e {
+        CryptographyError::Py(e)
+            if e.is_instance_of::<pyo3::exceptions::PyValueError>(py)
+                || e.is_instance_of::<crate::exceptions::InvalidSignature>(py) =>
+        {
+            stage_error(py, stage, format!("validation failed: {what}: {e}"))
+        }
+        e => e,
+    }
+}
+
+#[pyo3::pyfunction]
+#[pyo3(signature = (verifier, leaf, intermediates, *, ocsp_response = None, ct_log_keys = None, minimum_scts = 0))]
+pub(crate) fn verify_tls_server<'p>(
+    py: pyo3::Python<'p>,
+    verifier: pyo3::PyRef<'_, PyServerVerifier>,
+    leaf: pyo3::Py<PyCertificate>,
+    intermediates: PyIntermediates,
+    ocsp_response: Option<pyo3::PyRef<'_, OCSPResponse>>,
+    ct_log_keys: Option<pyo3::Bound<'p, pyo3::types::PyDict>>,
+    minimum_scts: usize,
+) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyList>> {
+    let intermediates = intermediates.into_certificates(py)?;
+    let chain = match verifier.verify_inner(py, leaf.clone_ref(py), intermediates, None) {
+        Ok((chain, _)) => chain,
+        Err(CryptographyError::Py(e)) if e.is_instance_of::<VerificationError>(py) => {
+            return Err(stage_error(
+                py,
+                PyTLSVerificationStage::Chain,
+                e.value(py).str()?.to_string(),
+            ));
+        }
+        Err(e) => return Err(e),
+    };
+    let issuer = chain
+        .get_item(if chain.len() > 1 { 1 } else { 0 })?
+        .extract::<pyo3::Bound<'_, PyCertificate>>()?;
+    let raw_leaf = leaf.get().raw.borrow_dependent();
+    // NOTE: The chain was built, so the leaf's extensions are well-formed.
+    let leaf_extensions = raw_leaf.extensions().ok().unwrap();
+
+    let must_staple = match leaf_extensions.get_extension(&oid::TLS_FEATURE_OID) {
+        Some(ext) => ext
+            .value::<asn1::SequenceOf<'_, u64>>()?
+            .any(|feature| feature == TLS_FEATURE_STATUS_REQUEST),
+        None => false,
+    };
+    if must_staple && ocsp_response.is_none() {
+        return Err(stage_error(
+            py,
+            PyTLSVerificationStage::MustStaple,
+            "validation failed: leaf certificate requires a stapled OCSP response (OCSP Must-Staple)".to_string(),
+        ));
+    }
+
+    let validation_time = &verifier.as_policy_def().validation_time;
+    let mut scts = vec![];
+    if let Some(ocsp_response) = &ocsp_response {
+        let single_resp = ocsp_response
+            .verify_single_response(py, leaf.bind(py).borrow(), issuer.borrow())
+            .map_err(|e| {
+                into_stage_error(
+                    py,
+                    PyTLSVerificationStage::Ocsp,
+                    "OCSP response could not be verified",
+                    e,
+                )
+            })?;
+        let single_resp = single_resp.single_response();
+        match single_resp.cert_status {
+            CertStatus::Good(_) => {}
+            CertStatus::Revoked(_) => {
+                return Err(stage_error(
+                    py,
+                    PyTLSVerificationStage::Ocsp,
+                    "validation failed: OCSP response reports the leaf certificate as revoked"
+                        .to_string(),
+                ));
+            }
+            CertStatus::Unknown(_) => {
+                return Err(stage_error(
+                    py,
+                    PyTLSVerificationStage::Ocsp,
+                    "validation failed: OCSP response reports the leaf certificate's status as unknown"
+                        .to_string(),
+                ));
+            }
+        }
+        if validation_time < single_resp.this_update.as_datetime()
+            || single_resp
+                .next_update
+                .as_ref()
+                .map_or(false, |next_update| {
+                    validation_time > next_update.as_datetime()
+                })
+        {
+            return Err(stage_error(
+                py,
+                PyTLSVerificationStage::Ocsp,
+                "validation failed: OCSP response is not current at the validation time"
+                    .to_string(),
+            ));
+        }
+
+        let Ok(single_extensions) =
+            Extensions::from_raw_extensions(single_resp.raw_single_extensions.as_ref())
+        else {
+            return Err(stage_error(
+                py,
+                PyTLSVerificationStage::Ocsp,
+                "validation failed: OCSP response has duplicate single extensions".to_string(),
+            ));
+        };
+        let stapled_scts = single_extensions
+            .get_extension(&oid::SIGNED_CERTIFICATE_TIMESTAMPS_OID)
+            .filter(|_| minimum_scts > 0);
+        if let Some(ext) = stapled_scts {
+            let leaf_der = asn1::write_single(raw_leaf)?;
+            for sct in sct::parse_scts(py, ext.value::<&[u8]>()?, sct::LogEntryType::Certificate)?
+                .try_iter()?
+            {
+                let sct = sct?.extract::<pyo3::Bound<'_, sct::Sct>>()?;
+                let data = sct.get().certificate_signed_data(&leaf_der)?;
+                scts.push((sct, data));
+            }
+        }
+    }
+
+    if minimum_scts > 0 {
+        if let Some(ext) =
+            leaf_extensions.get_extension(&oid::PRECERT_SIGNED_CERTIFICATE_TIMESTAMPS_OID)
+        {
+            let tbs_precert = leaf.get().tbs_without_extensions(
+                py,
+                &[oid::PRECERT_SIGNED_CERTIFICATE_TIMESTAMPS_OID],
+                "Could not find SCT list extension",
+            )?;
+            let issuer_key_hash = issuer.get().ct_issuer_key_hash_bytes();
+            for sct in
+                sct::parse_scts(py, ext.value::<&[u8]>()?, sct::LogEntryType::PreCertificate)?
+                    .try_iter()?
+            {
+                let sct = sct?.extract::<pyo3::Bound<'_, sct::Sct>>()?;
+                let data = sct
+                    .get()
+                    .precertificate_signed_data(&issuer_key_hash, &tbs_precert)?;
+                scts.push((sct, data));
+            }
+        }
+
+        // Only one SCT from each log counts towards the minimum.
+        let mut logs = HashSet::new();
+        for (sct, data) in &scts {
+            let sct = sct.get();
+            let Some(log_key) = ct_log_keys
+                .as_ref()
+                .map(|keys| keys.get_item(pyo3::types::PyBytes::new(py, &sct.log_id)))
+                .transpose()?
+                .flatten()
+            else {
+                continue;
+            };
+            let Some(signature_algorithm) = sct.signature_algorithm_identifier() else {
+                continue;
+            };
+            if sign::verify_signature_with_signature_algorithm(
+                py,
+                log_key,
+                &signature_algorithm,
+                &sct.signature,
+                data,
+            )
+            .is_ok()
+            {
+                logs.insert(sct.log_id);
+            }
+        }
+        if logs.len() < minimum_scts {
+            return Err(stage_error(
+                py,
+                PyTLSVerificationStage::Sct,
+                format!(
+                    "validation failed: found valid SCTs from {} log(s), but {minimum_scts} are required",
+                    logs.len()
+                ),
+            ));
+        }
+    }
+
+    Ok(chain)
+}
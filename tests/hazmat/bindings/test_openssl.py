@@ -5,6 +5,7 @@
 import pytest
 
 from cryptography.exceptions import InternalError
+from cryptography.hazmat.bindings._rust import ObjectIdentifier
 from cryptography.hazmat.bindings._rust import openssl as rust_openssl
 from cryptography.hazmat.bindings.openssl.binding import (
     Binding,
@@ -52,6 +53,17 @@ class TestOpenSSL:
         assert resp == expected_options
         assert b.lib.SSL_get_options(ssl) == expected_options
 
+    def test_supported_digest_oids(self):
+        oids = rust_openssl.supported_digest_oids()
+        assert isinstance(oids, frozenset)
+        assert ObjectIdentifier("2.16.840.1.101.3.4.2.1") in oids
+
+    def test_supported_signature_algorithms(self):
+        schemes = rust_openssl.supported_signature_algorithms()
+        assert isinstance(schemes, frozenset)
+        assert "RSA" in schemes
+        assert "ECDSA" in schemes
+
     def test_conditional_removal(self):
         b = Binding()
 
@@ -31,8 +31,10 @@ from cryptography.hazmat.primitives.serialization.pkcs12 import (
     PKCS12Certificate,
     PKCS12KeyAndCertificates,
     load_key_and_certificates,
+    load_mac_data,
     load_pkcs12,
     serialize_key_and_certificates,
+    verify_mac,
 )
 
 from ...doubles import DummyKeySerializationEncryption
@@ -276,6 +278,48 @@ class TestPKCS12Loading:
         assert pkcs12.additional_certs[1].certificate == cert3
         assert pkcs12.additional_certs[1].friendly_name == name3
 
+    @pytest.mark.parametrize(
+        ("filename", "iterations"),
+        [("cert-key-aes256cbc.p12", 2048), ("no-password.p12", 1)],
+    )
+    def test_load_mac_data(self, filename, iterations):
+        mac_data = load_vectors_from_file(
+            os.path.join("pkcs12", filename),
+            lambda derfile: load_mac_data(derfile.read()),
+            mode="rb",
+        )
+        assert mac_data is not None
+        algorithm, salt, mac_iterations = mac_data
+        assert isinstance(algorithm, hashes.SHA1)
+        assert len(salt) == 8
+        assert mac_iterations == iterations
+
+    def test_load_mac_data_invalid(self):
+        with pytest.raises(ValueError):
+            load_mac_data(b"invalid")
+
+    @pytest.mark.parametrize(
+        ("filename", "password", "expected"),
+        [
+            ("cert-key-aes256cbc.p12", b"cryptography", True),
+            ("cert-key-aes256cbc.p12", b"not cryptography", False),
+            ("cert-key-aes256cbc.p12", None, False),
+            ("no-password.p12", None, True),
+            ("no-mac.p12", b"cryptography", None),
+        ],
+    )
+    def test_verify_mac(self, filename, password, expected):
+        result = load_vectors_from_file(
+            os.path.join("pkcs12", filename),
+            lambda derfile: verify_mac(derfile.read(), password),
+            mode="rb",
+        )
+        assert result is expected
+
+    def test_verify_mac_invalid(self):
+        with pytest.raises(ValueError):
+            verify_mac(b"invalid", b"cryptography")
+
 
 def _load_cert(backend, path):
     return load_vectors_from_file(
@@ -698,6 +742,32 @@ class TestPKCS12Creation:
         )
         assert parsed_more_certs == [cacert]
 
+    def test_load_mac_data(self, backend):
+        key = ec.generate_private_key(ec.SECP256R1())
+        encryption = (
+            serialization.PrivateFormat.PKCS12.encryption_builder()
+            .hmac_hash(hashes.SHA256())
+            .build(b"password")
+        )
+        p12 = serialize_key_and_certificates(
+            b"name", key, None, None, encryption
+        )
+
+        mac_data = load_mac_data(p12)
+        assert mac_data is not None
+        algorithm, salt, iterations = mac_data
+        assert isinstance(algorithm, hashes.SHA256)
+        assert len(salt) == 8
+        assert iterations == 2048
+
+        # Strip the trailing MacData, a 67 byte SEQUENCE holding a SHA-256
+        # DigestInfo, the salt, and the iteration count.
+        assert p12[:2] == b"\x30\x82"
+        assert p12[-67:-65] == b"\x30\x41"
+        body = p12[4:-67]
+        no_mac = b"\x30\x82" + len(body).to_bytes(2, "big") + body
+        assert load_mac_data(no_mac) is None
+
     def test_set_mac_key_certificate_mismatch(self, backend):
         cacert, _ = _load_ca(backend)
         key = ec.generate_private_key(ec.SECP256R1())
@@ -736,6 +806,106 @@ class TestPKCS12Creation:
         )
         assert p12.count(cert.fingerprint(hashes.SHA1())) == count
 
+    @pytest.mark.parametrize(
+        "encoding", [serialization.Encoding.PEM, serialization.Encoding.DER]
+    )
+    def test_generate_encrypted_pkcs8_key(self, backend, encoding):
+        cert, key = _load_ca(backend)
+        encrypted_key = key.private_bytes(
+            encoding,
+            serialization.PrivateFormat.PKCS8,
+            serialization.BestAvailableEncryption(b"keypassword"),
+        )
+
+        p12 = serialize_key_and_certificates(
+            b"name",
+            encrypted_key,
+            cert,
+            None,
+            serialization.BestAvailableEncryption(b"password"),
+            key_password=b"keypassword",
+        )
+
+        parsed_key, parsed_cert, _ = load_key_and_certificates(
+            p12, b"password", backend
+        )
+        assert parsed_cert == cert
+        assert isinstance(parsed_key, ec.EllipticCurvePrivateKey)
+        assert parsed_key.private_numbers() == key.private_numbers()
+
+    @pytest.mark.parametrize("buffer_type", [bytearray, memoryview])
+    def test_generate_pkcs8_key_buffer(self, backend, buffer_type):
+        cert, key = _load_ca(backend)
+        pkcs8_key = key.private_bytes(
+            serialization.Encoding.DER,
+            serialization.PrivateFormat.PKCS8,
+            serialization.NoEncryption(),
+        )
+
+        p12 = serialize_key_and_certificates(
+            None,
+            buffer_type(pkcs8_key),
+            cert,
+            None,
+            serialization.NoEncryption(),
+        )
+
+        parsed_key, parsed_cert, _ = load_key_and_certificates(
+            p12, None, backend
+        )
+        assert parsed_cert == cert
+        assert isinstance(parsed_key, ec.EllipticCurvePrivateKey)
+        assert parsed_key.private_numbers() == key.private_numbers()
+
+    def test_generate_encrypted_pkcs8_key_wrong_password(self, backend):
+        cert, key = _load_ca(backend)
+        encrypted_key = key.private_bytes(
+            serialization.Encoding.PEM,
+            serialization.PrivateFormat.PKCS8,
+            serialization.BestAvailableEncryption(b"keypassword"),
+        )
+
+        with pytest.raises(ValueError, match="Could not load the PKCS8"):
+            serialize_key_and_certificates(
+                None,
+                encrypted_key,
+                cert,
+                None,
+                serialization.NoEncryption(),
+                key_password=b"wrong",
+            )
+
+    @pytest.mark.parametrize(
+        "encoding", [serialization.Encoding.PEM, serialization.Encoding.DER]
+    )
+    def test_generate_non_pkcs8_key(self, backend, encoding):
+        cert, key = _load_ca(backend)
+        traditional_key = key.private_bytes(
+            encoding,
+            serialization.PrivateFormat.TraditionalOpenSSL,
+            serialization.NoEncryption(),
+        )
+        with pytest.raises(ValueError, match="must be a PEM or DER encoded"):
+            serialize_key_and_certificates(
+                None,
+                traditional_key,
+                cert,
+                None,
+                serialization.NoEncryption(),
+            )
+
+    def test_generate_key_password_requires_serialized_key(self, backend):
+        cert, key = _load_ca(backend)
+        with pytest.raises(TypeError):
+            serialize_key_and_certificates(
+                None,
+                key,
+                cert,
+                None,
+                serialization.NoEncryption(),
+                key_password=b"keypassword",
+            )
+
 
 @pytest.mark.skip_fips(
     reason="PKCS12 unsupported in FIPS mode. So much bad crypto in it."
@@ -1407,6 +1407,266 @@ class TestPKCS7Decrypt:
             )
 
 
+@pytest.mark.supported(
+    only_if=lambda backend: backend.pkcs7_supported(),
+    skip_message="Requires OpenSSL with PKCS7 support",
+)
+class TestPKCS7VerifySignedAttributes:
+    def _sign(self, data, options, cert_key=None):
+        cert, key = cert_key or _load_cert_key()
+        signed = (
+            pkcs7.PKCS7SignatureBuilder()
+            .set_data(data)
+            .add_signer(cert, key, hashes.SHA256())
+            .sign(serialization.Encoding.DER, options)
+        )
+        return signed, cert
+
+    def test_detached(self, backend):
+        data = b"hello world"
+        signed, cert = self._sign(data, [pkcs7.PKCS7Options.DetachedSignature])
+        assert pkcs7.pkcs7_verify_signed_attributes_der(
+            signed, cert, data
+        ) == (True, True, True)
+
+    def test_detached_tampered_content(self, backend):
+        signed, cert = self._sign(
+            b"hello world", [pkcs7.PKCS7Options.DetachedSignature]
+        )
+        # The signature over the signed attributes is intact; only the
+        # messageDigest binding to the content is broken.
+        assert pkcs7.pkcs7_verify_signed_attributes_der(
+            signed, cert, b"hello w0rld"
+        ) == (True, False, True)
+
+    def test_embedded_content(self, backend):
+        signed, cert = self._sign(b"hello world", [])
+        assert pkcs7.pkcs7_verify_signed_attributes_der(signed, cert) == (
+            True,
+            True,
+            True,
+        )
+
+    def test_rsa_signer(self, backend):
+        data = b"hello world"
+        signed, cert = self._sign(
+            data,
+            [pkcs7.PKCS7Options.DetachedSignature],
+            cert_key=_load_rsa_cert_key(),
+        )
+        assert pkcs7.pkcs7_verify_signed_attributes_der(
+            signed, cert, data
+        ) == (True, True, True)
+
+    def test_no_attributes(self, backend):
+        data = b"hello world"
+        signed, cert = self._sign(
+            data,
+            [
+                pkcs7.PKCS7Options.DetachedSignature,
+                pkcs7.PKCS7Options.NoAttributes,
+            ],
+        )
+        assert pkcs7.pkcs7_verify_signed_attributes_der(
+            signed, cert, data
+        ) == (False, False, True)
+        assert pkcs7.pkcs7_verify_signed_attributes_der(
+            signed, cert, b"other"
+        ) == (False, False, False)
+
+    def test_corrupted_signature(self, backend):
+        data = b"hello world"
+        signed, cert = self._sign(data, [pkcs7.PKCS7Options.DetachedSignature])
+        # The signature is the final field of the only SignerInfo.
+        corrupted = signed[:-1] + bytes([signed[-1] ^ 0x01])
+        assert pkcs7.pkcs7_verify_signed_attributes_der(
+            corrupted, cert, data
+        ) == (True, True, False)
+
+    def test_detached_without_content(self, backend):
+        signed, cert = self._sign(
+            b"hello world", [pkcs7.PKCS7Options.DetachedSignature]
+        )
+        with pytest.raises(ValueError):
+            pkcs7.pkcs7_verify_signed_attributes_der(signed, cert)
+
+    def test_wrong_certificate(self, backend):
+        data = b"hello world"
+        signed, _ = self._sign(data, [pkcs7.PKCS7Options.DetachedSignature])
+        other_cert, _ = _load_rsa_cert_key()
+        with pytest.raises(ValueError):
+            pkcs7.pkcs7_verify_signed_attributes_der(signed, other_cert, data)
+
+    def test_not_signed_data(self, backend):
+        cert, _ = _load_rsa_cert_key()
+        enveloped = (
+            pkcs7.PKCS7EnvelopeBuilder()
+            .set_data(b"hello world")
+            .add_recipient(cert)
+            .encrypt(serialization.Encoding.DER, [])
+        )
+        with pytest.raises(ValueError):
+            pkcs7.pkcs7_verify_signed_attributes_der(enveloped, cert)
+
+
+@pytest.mark.supported(
+    only_if=lambda backend: backend.pkcs7_supported(),
+    skip_message="Requires OpenSSL with PKCS7 support",
+)
+class TestPKCS7SMIMECapabilities:
+    def _sign(self, options):
+        cert, key = _load_cert_key()
+        signed = (
+            pkcs7.PKCS7SignatureBuilder()
+            .set_data(b"hello world")
+            .add_signer(cert, key, hashes.SHA256())
+            .sign(serialization.Encoding.DER, options)
+        )
+        return signed, cert
+
+    def test_capabilities(self, backend):
+        signed, cert = self._sign([])
+        assert pkcs7.pkcs7_smime_capabilities_der(signed, cert) == [
+            (x509.ObjectIdentifier("2.16.840.1.101.3.4.1.42"), None),
+            (x509.ObjectIdentifier("2.16.840.1.101.3.4.1.22"), None),
+            (x509.ObjectIdentifier("2.16.840.1.101.3.4.1.2"), None),
+        ]
+
+    @pytest.mark.parametrize(
+        "options",
+        [
+            [pkcs7.PKCS7Options.NoCapabilities],
+            [pkcs7.PKCS7Options.NoAttributes],
+        ],
+    )
+    def test_no_capabilities(self, options, backend):
+        signed, cert = self._sign(options)
+        assert pkcs7.pkcs7_smime_capabilities_der(signed, cert) is None
+
+    def test_wrong_certificate(self, backend):
+        signed, _ = self._sign([])
+        other_cert, _ = _load_rsa_cert_key()
+        with pytest.raises(ValueError):
+            pkcs7.pkcs7_smime_capabilities_der(signed, other_cert)
+
+    def test_misordered_signer_infos(self, backend):
+        # SET OF elements must be sorted by their DER encoding, and this is
+        # always enforced at parse time.
+        signed = load_vectors_from_file(
+            os.path.join("pkcs7", "signer-infos-misordered.der"),
+            lambda derfile: derfile.read(),
+            mode="rb",
+        )
+        cert, _ = _load_cert_key()
+        with pytest.raises(ValueError, match="InvalidSetOrdering"):
+            pkcs7.pkcs7_smime_capabilities_der(signed, cert)
+
+
+class TestPKCS7Decompress:
+    def _load(self, name):
+        return load_vectors_from_file(
+            os.path.join("pkcs7", name),
+            lambda derfile: derfile.read(),
+            mode="rb",
+        )
+
+    def test_data(self, backend):
+        data = self._load("compressed-data.der")
+        assert pkcs7.pkcs7_decompress_der(data) == b"Hello, world!\n"
+
+    def test_signed_data(self, backend):
+        data = self._load("compressed-signed-data.der")
+        signed = pkcs7.pkcs7_decompress_der(data)
+        cert, _ = _load_cert_key()
+        assert pkcs7.load_der_pkcs7_certificates(signed) == [cert]
+        assert pkcs7.pkcs7_verify_signed_attributes_der(signed, cert) == (
+            True,
+            True,
+            True,
+        )
+
+    def test_unsupported_algorithm(self, backend):
+        data = self._load("compressed-data.der")
+        # Replace id-alg-zlibCompress with an unassigned sibling OID.
+        zlib_oid = bytes.fromhex("060b2a864886f70d0109100308")
+        data = data.replace(zlib_oid, zlib_oid[:-1] + b"\x7f")
+        with raises_unsupported_algorithm(None):
+            pkcs7.pkcs7_decompress_der(data)
+
+    def test_corrupt_content(self, backend):
+        data = self._load("compressed-data.der")
+        # Break the zlib stream's header checksum.
+        data = data.replace(bytes.fromhex("789c"), bytes.fromhex("789d"))
+        with pytest.raises(ValueError):
+            pkcs7.pkcs7_decompress_der(data)
+
+    def test_max_length(self, backend):
+        data = self._load("compressed-data.der")
+        content = b"Hello, world!\n"
+        assert pkcs7.pkcs7_decompress_der(data, len(content)) == content
+        with pytest.raises(ValueError, match="more than 13 bytes"):
+            pkcs7.pkcs7_decompress_der(data, len(content) - 1)
+
+    def test_truncated_content(self, backend):
+        data = self._load("compressed-data.der")
+        # Drop the zlib stream's trailing Adler-32 checksum, and shorten the
+        # lengths of the stream and of every structure that encloses it.
+        data = bytearray(data[:-4])
+        for length_offset in [0x01, 0x10, 0x12, 0x26, 0x33, 0x35]:
+            data[length_offset] -= 4
+        with pytest.raises(ValueError, match="truncated"):
+            pkcs7.pkcs7_decompress_der(bytes(data))
+
+    def test_trailing_content(self, backend):
+        data = self._load("compressed-data.der")
+        # Append garbage after the end of the zlib stream, and lengthen the
+        # stream and every structure that encloses it to match.
+        data = bytearray(data + b"\x00garb")
+        for length_offset in [0x01, 0x10, 0x12, 0x26, 0x33, 0x35]:
+            data[length_offset] += 5
+        with pytest.raises(ValueError, match="trailing data"):
+            pkcs7.pkcs7_decompress_der(bytes(data))
+
+    def test_not_compressed_data(self, backend):
+        data = self._load("amazon-roots.der")
+        with pytest.raises(ValueError):
+            pkcs7.pkcs7_decompress_der(data)
+
+
+class TestPKCS7CRLs:
+    def _load(self, name):
+        return load_vectors_from_file(
+            os.path.join("pkcs7", name),
+            lambda derfile: derfile.read(),
+            mode="rb",
+        )
+
+    def test_crls(self, backend):
+        data = self._load("signed-data-crl.der")
+        crls = pkcs7.pkcs7_crls_der(data)
+        expected = load_vectors_from_file(
+            os.path.join("x509", "custom", "crl_all_reasons.pem"),
+            lambda pemfile: x509.load_pem_x509_crl(pemfile.read()),
+            mode="rb",
+        )
+        assert crls == [expected]
+        assert len(crls[0]) == 12
+        revoked = crls[0].get_revoked_certificate_by_serial_number(1)
+        assert revoked is not None
+        assert revoked.extensions.get_extension_for_class(
+            x509.CRLReason
+        ).value == x509.CRLReason(x509.ReasonFlags.unspecified)
+
+    def test_no_crls(self, backend):
+        data = self._load("amazon-roots.der")
+        assert pkcs7.pkcs7_crls_der(data) == []
+
+    def test_not_signed_data(self, backend):
+        data = self._load("compressed-data.der")
+        with pytest.raises(ValueError):
+            pkcs7.pkcs7_crls_der(data)
+
+
 @pytest.mark.supported(
     only_if=lambda backend: backend.pkcs7_supported(),
     skip_message="Requires OpenSSL with PKCS7 support",
@@ -4,6 +4,7 @@
 
 
 import base64
+import hashlib
 import itertools
 import os
 import textwrap
@@ -32,6 +33,7 @@ from cryptography.hazmat.primitives.serialization import (
     load_der_parameters,
     load_der_private_key,
     load_der_public_key,
+    load_der_public_key_info,
     load_pem_parameters,
     load_pem_private_key,
     load_pem_public_key,
@@ -360,6 +362,69 @@ class TestDERSerialization:
         with pytest.raises(ValueError):
             load_der_public_key(b"invalid data", backend)
 
+    def test_load_der_public_key_info_rsa(self, backend):
+        data = load_vectors_from_file(
+            os.path.join(
+                "asymmetric", "DER_Serialization", "unenc-rsa-pkcs8.pub.der"
+            ),
+            lambda derfile: derfile.read(),
+            mode="rb",
+        )
+        info = load_der_public_key_info(data)
+        assert info.algorithm == "RSA"
+        assert info.key_size == 1024
+        assert info.curve is None
+        assert info.fingerprint == hashlib.sha256(data).digest()
+        assert repr(info) == "<PublicKeyInfo(algorithm=RSA, key_size=1024)>"
+
+    def test_load_der_public_key_info_ec(self, backend):
+        _skip_curve_unsupported(backend, ec.SECP384R1())
+        key = ec.generate_private_key(ec.SECP384R1()).public_key()
+        data = key.public_bytes(
+            Encoding.DER, PublicFormat.SubjectPublicKeyInfo
+        )
+        info = load_der_public_key_info(data)
+        assert info.algorithm == "EC"
+        assert info.key_size == 384
+        assert isinstance(info.curve, ec.SECP384R1)
+        assert info.fingerprint == hashlib.sha256(data).digest()
+
+        # The fingerprint doesn't depend on how the point was encoded.
+        point = key.public_bytes(Encoding.X962, PublicFormat.CompressedPoint)
+        # SEQUENCE { AlgorithmIdentifier, BIT STRING }
+        compressed = b"\x30\x46" + data[2:20] + b"\x03\x32\x00" + point
+        compressed_info = load_der_public_key_info(compressed)
+        assert compressed_info.fingerprint == info.fingerprint
+
+    @pytest.mark.supported(
+        only_if=lambda backend: backend.ed25519_supported(),
+        skip_message="Requires OpenSSL with Ed25519 support",
+    )
+    def test_load_der_public_key_info_ed25519(self, backend):
+        data = load_vectors_from_file(
+            os.path.join("asymmetric", "Ed25519", "ed25519-pub.der"),
+            lambda derfile: derfile.read(),
+            mode="rb",
+        )
+        info = load_der_public_key_info(data)
+        assert info.algorithm == "Ed25519"
+        assert info.key_size is None
+        assert info.curve is None
+        assert info.fingerprint == hashlib.sha256(data).digest()
+        assert repr(info) == "<PublicKeyInfo(algorithm=Ed25519)>"
+
+    def test_load_der_public_key_info_invalid(self, backend):
+        with pytest.raises(ValueError, match="Could not deserialize"):
+            load_der_public_key_info(b"invalid data")
+        # A bare PKCS#1 RSAPublicKey isn't a SubjectPublicKeyInfo.
+        data = load_vectors_from_file(
+            os.path.join("asymmetric", "public", "PKCS1", "rsa.pub.der"),
+            lambda derfile: derfile.read(),
+            mode="rb",
+        )
+        with pytest.raises(ValueError):
+            load_der_public_key_info(data)
+
     @pytest.mark.supported(
         only_if=lambda backend: backend.dsa_supported(),
         skip_message="Does not support DSA.",
@@ -46,6 +46,38 @@ def load_vectors_from_file(
         return loader(vector_file)
 
 
+# Minimal DER helpers, for tests that need to build or pick apart structures
+# our APIs won't produce. Only single byte tags are supported.
+def der_encode(tag: int, body: bytes) -> bytes:
+    if len(body) < 0x80:
+        length = bytes([len(body)])
+    else:
+        n = (len(body).bit_length() + 7) // 8
+        length = bytes([0x80 | n]) + len(body).to_bytes(n, "big")
+    return bytes([tag]) + length + body
+
+
+def der_contents(data: bytes) -> bytes:
+    if data[1] & 0x80:
+        n = data[1] & 0x7F
+        length = int.from_bytes(data[2 : 2 + n], "big")
+        return data[2 + n : 2 + n + length]
+    return data[2 : 2 + data[1]]
+
+
+def der_elements(data: bytes) -> typing.List[bytes]:
+    elements = []
+    while data:
+        if data[1] & 0x80:
+            header = 2 + (data[1] & 0x7F)
+            length = int.from_bytes(data[2:header], "big")
+        else:
+            header, length = 2, data[1]
+        elements.append(data[: header + length])
+        data = data[header + length :]
+    return elements
+
+
 def load_nist_vectors(vector_data):
     test_data = {}
     data = []
@@ -11,14 +11,20 @@ from typing import Optional
 import pytest
 
 from cryptography import utils, x509
-from cryptography.exceptions import UnsupportedAlgorithm
+from cryptography.exceptions import InvalidSignature, UnsupportedAlgorithm
 from cryptography.hazmat.primitives import hashes, serialization
 from cryptography.hazmat.primitives.asymmetric import ec, ed448, ed25519, rsa
 from cryptography.hazmat.primitives.asymmetric.padding import PKCS1v15
 from cryptography.x509 import ocsp
 
 from ..hazmat.primitives.fixtures_ec import EC_KEY_SECP256R1
-from ..utils import load_vectors_from_file, raises_unsupported_algorithm
+from ..utils import (
+    der_contents,
+    der_elements,
+    der_encode,
+    load_vectors_from_file,
+    raises_unsupported_algorithm,
+)
 from .test_x509 import DummyExtension, _load_cert
 
 
@@ -69,6 +75,116 @@ def _generate_root(private_key=None, algorithm=hashes.SHA256()):
     return cert, private_key
 
 
+def _issue_cert(issuer_cert, issuer_key, serial, eku=None):
+    private_key = ec.generate_private_key(ec.SECP256R1())
+    builder = (
+        x509.CertificateBuilder()
+        .serial_number(serial)
+        .issuer_name(issuer_cert.subject)
+        .subject_name(
+            x509.Name(
+                [x509.NameAttribute(x509.NameOID.COMMON_NAME, f"{serial}")]
+            )
+        )
+        .public_key(private_key.public_key())
+        .not_valid_before(datetime.datetime(2025, 1, 1))
+        .not_valid_after(datetime.datetime(2035, 1, 1))
+    )
+    if eku is not None:
+        builder = builder.add_extension(
+            x509.ExtendedKeyUsage(eku), critical=False
+        )
+    return builder.sign(issuer_key, hashes.SHA256()), private_key
+
+
+def _multi_ocsp_response(issuer, issuer_key, entries):
+    # The builder only emits a single SingleResponse, so sign one response
+    # per entry and splice their SingleResponses into a new ResponseData.
+    this_update = datetime.datetime(2025, 6, 1)
+    singles = []
+    for cert, status in entries:
+        revoked = status == ocsp.OCSPCertStatus.REVOKED
+        resp = (
+            ocsp.OCSPResponseBuilder()
+            .responder_id(ocsp.OCSPResponderEncoding.HASH, issuer)
+            .add_response(
+                cert,
+                issuer,
+                hashes.SHA256(),
+                status,
+                this_update,
+                this_update + datetime.timedelta(days=7),
+                this_update if revoked else None,
+                x509.ReasonFlags.key_compromise if revoked else None,
+            )
+            .sign(issuer_key, hashes.SHA256())
+        )
+        responder_id, produced_at, responses = der_elements(
+            der_contents(resp.tbs_response_bytes)
+        )
+        singles.append(der_contents(responses))
+    tbs_response_data = der_encode(
+        0x30, responder_id + produced_at + der_encode(0x30, b"".join(singles))
+    )
+    signature = issuer_key.sign(tbs_response_data, ec.ECDSA(hashes.SHA256()))
+    ecdsa_with_sha256 = bytes.fromhex("300a06082a8648ce3d040302")
+    basic_response = der_encode(
+        0x30,
+        tbs_response_data
+        + ecdsa_with_sha256
+        + der_encode(0x03, b"\x00" + signature),
+    )
+    id_pkix_ocsp_basic = bytes.fromhex("06092b0601050507300101")
+    response_bytes = der_encode(
+        0x30, id_pkix_ocsp_basic + der_encode(0x04, basic_response)
+    )
+    # responseStatus successful(0).
+    return der_encode(0x30, b"\x0a\x01\x00" + der_encode(0xA0, response_bytes))
+
+
+def _signed_ocsp_request(
+    requestor_name=None, tamper=False, malformed_signature=False
+):
+    cert, issuer = _cert_and_issuer()
+    requestor, private_key = _generate_root()
+    if requestor_name is None:
+        requestor_name = requestor.subject
+    unsigned = (
+        ocsp.OCSPRequestBuilder()
+        .add_certificate(cert, issuer, hashes.SHA256())
+        .build()
+        .public_bytes(serialization.Encoding.DER)
+    )
+    # The builder leaves out requestorName, so splice it in ahead of the
+    # requestList that the TBSRequest starts with.
+    tbs_request = der_encode(
+        0x30,
+        der_encode(0xA1, der_encode(0xA4, requestor_name.public_bytes()))
+        + der_contents(der_contents(unsigned)),
+    )
+    signature = private_key.sign(tbs_request, ec.ECDSA(hashes.SHA256()))
+    if tamper:
+        # Flip the last bit of the request's serial number.
+        tbs_request = tbs_request[:-1] + bytes([tbs_request[-1] ^ 0x01])
+    ecdsa_with_sha256 = bytes.fromhex("300a06082a8648ce3d040302")
+    optional_signature = der_encode(
+        0x30,
+        ecdsa_with_sha256
+        + der_encode(0x03, b"\x00" + signature)
+        + der_encode(
+            0xA0,
+            der_encode(
+                0x30, requestor.public_bytes(serialization.Encoding.DER)
+            ),
+        ),
+    )
+    if malformed_signature:
+        # Leave out the signature BIT STRING.
+        optional_signature = der_encode(0x30, ecdsa_with_sha256)
+    data = der_encode(0x30, tbs_request + der_encode(0xA0, optional_signature))
+    return data, requestor
+
+
 def _check_ocsp_response_times(
     ocsp_resp: ocsp.OCSPResponse,
     this_update: datetime.datetime,
@@ -200,6 +316,113 @@ class TestOCSPRequest:
         with pytest.raises(ValueError):
             req.public_bytes(serialization.Encoding.PEM)
 
+    def test_verify_signature(self):
+        data, requestor = _signed_ocsp_request()
+        req = ocsp.load_der_ocsp_request(data)
+        assert req.public_bytes(serialization.Encoding.DER) == data
+        assert req.verify_signature() == requestor
+
+    def test_verify_signature_tampered(self):
+        data, _ = _signed_ocsp_request(tamper=True)
+        req = ocsp.load_der_ocsp_request(data)
+        with pytest.raises(InvalidSignature):
+            req.verify_signature()
+
+    def test_verify_signature_malformed(self):
+        data, _ = _signed_ocsp_request(malformed_signature=True)
+        req = ocsp.load_der_ocsp_request(data)
+        assert req.serial_number is not None
+        with pytest.raises(ValueError):
+            req.verify_signature()
+
+    def test_verify_signature_unsigned(self):
+        req = _load_data(
+            os.path.join("x509", "ocsp", "req-sha1.der"),
+            ocsp.load_der_ocsp_request,
+        )
+        assert req.verify_signature() is None
+
+    def test_verify_signature_requestor_name_mismatch(self):
+        data, _ = _signed_ocsp_request(
+            requestor_name=x509.Name(
+                [x509.NameAttribute(x509.NameOID.COMMON_NAME, "Someone")]
+            )
+        )
+        req = ocsp.load_der_ocsp_request(data)
+        with pytest.raises(ValueError):
+            req.verify_signature()
+
+
+class TestIssuerHashes:
+    def test_matches_response(self):
+        issuer = _load_cert(
+            os.path.join("x509", "letsencryptx3.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        resp = _load_data(
+            os.path.join("x509", "ocsp", "resp-sha256.der"),
+            ocsp.load_der_ocsp_response,
+        )
+        assert isinstance(resp.hash_algorithm, hashes.SHA1)
+        assert ocsp.issuer_hashes(issuer, hashes.SHA1()) == (
+            resp.issuer_name_hash,
+            resp.issuer_key_hash,
+        )
+
+    @pytest.mark.parametrize(
+        "algorithm", [hashes.SHA1(), hashes.SHA256(), hashes.SHA512()]
+    )
+    def test_matches_request(self, algorithm):
+        cert, issuer = _cert_and_issuer()
+        req = (
+            ocsp.OCSPRequestBuilder()
+            .add_certificate(cert, issuer, algorithm)
+            .build()
+        )
+        assert ocsp.issuer_hashes(issuer, algorithm) == (
+            req.issuer_name_hash,
+            req.issuer_key_hash,
+        )
+
+    def test_invalid_algorithm(self):
+        _, issuer = _cert_and_issuer()
+        with pytest.raises(TypeError):
+            ocsp.issuer_hashes(issuer, "notahash")  # type: ignore[arg-type]
+
+
+class TestCreateCertID:
+    @pytest.mark.parametrize(
+        "algorithm", [hashes.SHA1(), hashes.SHA256(), hashes.SHA512()]
+    )
+    def test_matches_request(self, algorithm):
+        cert, issuer = _cert_and_issuer()
+        req = (
+            ocsp.OCSPRequestBuilder()
+            .add_certificate(cert, issuer, algorithm)
+            .build()
+        )
+        # OCSPRequest -> TBSRequest -> requestList -> Request -> reqCert
+        der = req.public_bytes(serialization.Encoding.DER)
+        tbs_request = der_elements(der_contents(der))[0]
+        request_list = der_elements(der_contents(tbs_request))[0]
+        request = der_contents(request_list)
+        req_cert = der_elements(der_contents(request))[0]
+        assert ocsp.create_cert_id(cert, issuer, algorithm) == req_cert
+
+    def test_invalid_algorithm(self):
+        cert, issuer = _cert_and_issuer()
+        with pytest.raises(ValueError):
+            ocsp.create_cert_id(cert, issuer, hashes.MD5())
+
+    def test_not_a_certificate(self):
+        cert, issuer = _cert_and_issuer()
+        with pytest.raises(TypeError):
+            ocsp.create_cert_id(
+                cert,
+                "notacert",  # type: ignore[arg-type]
+                hashes.SHA256(),
+            )
+
 
 class TestOCSPRequestBuilder:
     def test_add_cert_twice(self):
@@ -1417,6 +1640,163 @@ class TestOCSPResponse:
                     2018, 5, 30, 14, 1, 39, tzinfo=datetime.timezone.utc
                 )
 
+    def test_verify_single_response(self):
+        root, root_key = _generate_root()
+        certs = [_issue_cert(root, root_key, n)[0] for n in (1, 2, 3)]
+        resp = ocsp.load_der_ocsp_response(
+            _multi_ocsp_response(
+                root,
+                root_key,
+                zip(
+                    certs,
+                    [
+                        ocsp.OCSPCertStatus.GOOD,
+                        ocsp.OCSPCertStatus.REVOKED,
+                        ocsp.OCSPCertStatus.UNKNOWN,
+                    ],
+                ),
+            )
+        )
+        assert len(list(resp.responses)) == 3
+
+        single_resp = resp.verify_single_response(certs[1], root)
+        assert isinstance(single_resp, ocsp.OCSPSingleResponse)
+        assert single_resp.serial_number == 2
+        assert single_resp.certificate_status == ocsp.OCSPCertStatus.REVOKED
+        assert (
+            single_resp.revocation_reason == x509.ReasonFlags.key_compromise
+        )
+
+        other, _ = _issue_cert(root, root_key, 4)
+        with pytest.raises(ValueError):
+            resp.verify_single_response(other, root)
+
+    def test_verify_single_response_invalid_signature(self):
+        root, root_key = _generate_root()
+        cert, _ = _issue_cert(root, root_key, 1)
+        data = _multi_ocsp_response(
+            root, root_key, [(cert, ocsp.OCSPCertStatus.GOOD)]
+        )
+        # The signature is the last field of the response.
+        data = data[:-1] + bytes([data[-1] ^ 0x01])
+        resp = ocsp.load_der_ocsp_response(data)
+        with pytest.raises(InvalidSignature):
+            resp.verify_single_response(cert, root)
+
+    def test_verify_single_response_delegated(self):
+        root, root_key = _generate_root()
+        cert, _ = _issue_cert(root, root_key, 1)
+        responder, responder_key = _issue_cert(
+            root, root_key, 2, eku=[x509.ExtendedKeyUsageOID.OCSP_SIGNING]
+        )
+        not_responder, not_responder_key = _issue_cert(root, root_key, 3)
+        this_update = datetime.datetime(2025, 6, 1)
+        builder = ocsp.OCSPResponseBuilder().add_response(
+            cert,
+            root,
+            hashes.SHA256(),
+            ocsp.OCSPCertStatus.GOOD,
+            this_update,
+            this_update + datetime.timedelta(days=7),
+            None,
+            None,
+        )
+
+        resp = (
+            builder.responder_id(ocsp.OCSPResponderEncoding.NAME, responder)
+            .certificates([responder])
+            .sign(responder_key, hashes.SHA256())
+        )
+        resp = ocsp.load_der_ocsp_response(
+            resp.public_bytes(serialization.Encoding.DER)
+        )
+        single_resp = resp.verify_single_response(cert, root)
+        assert single_resp.serial_number == 1
+
+        # Responders must be authorized with id-kp-OCSPSigning.
+        resp = (
+            builder.responder_id(
+                ocsp.OCSPResponderEncoding.HASH, not_responder
+            )
+            .certificates([not_responder])
+            .sign(not_responder_key, hashes.SHA256())
+        )
+        with pytest.raises(ValueError):
+            resp.verify_single_response(cert, root)
+
+        # A delegated responder's certificate must be in the response.
+        resp = builder.responder_id(
+            ocsp.OCSPResponderEncoding.NAME, responder
+        ).sign(responder_key, hashes.SHA256())
+        with pytest.raises(ValueError):
+            resp.verify_single_response(cert, root)
+
+    def test_single_response_for_request(self):
+        root, root_key = _generate_root()
+        certs = [_issue_cert(root, root_key, n)[0] for n in (1, 2)]
+        # The response's CertIDs use SHA-256, while the request uses SHA-1.
+        resp = ocsp.load_der_ocsp_response(
+            _multi_ocsp_response(
+                root,
+                root_key,
+                zip(
+                    certs,
+                    [ocsp.OCSPCertStatus.GOOD, ocsp.OCSPCertStatus.REVOKED],
+                ),
+            )
+        )
+        req = (
+            ocsp.OCSPRequestBuilder()
+            .add_certificate(certs[1], root, hashes.SHA1())
+            .build()
+        )
+        single_resp = resp.single_response_for_request(req, root)
+        assert single_resp is not None
+        assert isinstance(single_resp.hash_algorithm, hashes.SHA256)
+        assert single_resp.serial_number == 2
+        assert single_resp.certificate_status == ocsp.OCSPCertStatus.REVOKED
+
+        other, _ = _issue_cert(root, root_key, 3)
+        req = (
+            ocsp.OCSPRequestBuilder()
+            .add_certificate(other, root, hashes.SHA1())
+            .build()
+        )
+        assert resp.single_response_for_request(req, root) is None
+
+    def test_single_response_for_request_different_issuer(self):
+        root, root_key = _generate_root()
+        # Same subject as `root`, but a different key.
+        other_root, other_root_key = _generate_root(
+            ec.generate_private_key(ec.SECP256R1())
+        )
+        cert, _ = _issue_cert(root, root_key, 1)
+        other_cert, _ = _issue_cert(other_root, other_root_key, 1)
+        resp = ocsp.load_der_ocsp_response(
+            _multi_ocsp_response(
+                other_root,
+                other_root_key,
+                [(other_cert, ocsp.OCSPCertStatus.GOOD)],
+            )
+        )
+        req = (
+            ocsp.OCSPRequestBuilder()
+            .add_certificate(cert, root, hashes.SHA1())
+            .build()
+        )
+        assert resp.single_response_for_request(req, root) is None
+        with pytest.raises(ValueError):
+            resp.single_response_for_request(req, other_root)
+
+    def test_verify_single_response_unsuccessful(self):
+        resp = _load_data(
+            os.path.join("x509", "ocsp", "resp-unauthorized.der"),
+            ocsp.load_der_ocsp_response,
+        )
+        cert, issuer = _cert_and_issuer()
+        with pytest.raises(ValueError):
+            resp.verify_single_response(cert, issuer)
+
     def test_load_unauthorized(self):
         resp = _load_data(
             os.path.join("x509", "ocsp", "resp-unauthorized.der"),
@@ -1528,6 +1908,47 @@ class TestOCSPResponse:
         )
         assert resp.revocation_reason is x509.ReasonFlags.superseded
 
+    def test_revoked_certificate_reason(self):
+        resp = _load_data(
+            os.path.join("x509", "ocsp", "resp-revoked-reason.der"),
+            ocsp.load_der_ocsp_response,
+        )
+        revoked = resp.revoked_certificate
+        assert isinstance(revoked, x509.RevokedCertificate)
+        assert revoked.serial_number == resp.serial_number
+        assert revoked.revocation_date_utc == resp.revocation_time_utc
+        reason = revoked.extensions.get_extension_for_class(x509.CRLReason)
+        assert reason.value == x509.CRLReason(x509.ReasonFlags.superseded)
+
+        [single_resp] = resp.responses
+        single_revoked = single_resp.revoked_certificate
+        assert single_revoked is not None
+        assert single_revoked.serial_number == revoked.serial_number
+        assert list(single_revoked.extensions) == list(revoked.extensions)
+
+    def test_revoked_certificate_no_reason(self):
+        resp = _load_data(
+            os.path.join("x509", "ocsp", "resp-revoked.der"),
+            ocsp.load_der_ocsp_response,
+        )
+        revoked = resp.revoked_certificate
+        assert isinstance(revoked, x509.RevokedCertificate)
+        assert revoked.serial_number == resp.serial_number
+        assert revoked.revocation_date_utc == datetime.datetime(
+            2016, 9, 2, 21, 28, 48, tzinfo=datetime.timezone.utc
+        )
+        assert len(revoked.extensions) == 0
+
+    def test_revoked_certificate_not_revoked(self):
+        resp = _load_data(
+            os.path.join("x509", "ocsp", "resp-sha256.der"),
+            ocsp.load_der_ocsp_response,
+        )
+        assert resp.certificate_status == ocsp.OCSPCertStatus.GOOD
+        assert resp.revoked_certificate is None
+        [single_resp] = resp.responses
+        assert single_resp.revoked_certificate is None
+
     def test_load_revoked_no_next_update(self):
         resp = _load_data(
             os.path.join("x509", "ocsp", "resp-revoked-no-next-update.der"),
@@ -1759,3 +2180,53 @@ class TestOCSPEdDSA:
         private_key.public_key().verify(
             resp.signature, resp.tbs_response_bytes
         )
+
+    @pytest.mark.supported(
+        only_if=lambda backend: backend.ed25519_supported(),
+        skip_message="Requires OpenSSL with Ed25519 support / OCSP",
+    )
+    def test_verify_single_response_ed25519(self, backend):
+        root_key = ed25519.Ed25519PrivateKey.generate()
+        root, _ = _generate_root(root_key, None)
+        cert = (
+            x509.CertificateBuilder()
+            .serial_number(1)
+            .issuer_name(root.subject)
+            .subject_name(
+                x509.Name([x509.NameAttribute(x509.NameOID.COMMON_NAME, "1")])
+            )
+            .public_key(ec.generate_private_key(ec.SECP256R1()).public_key())
+            .not_valid_before(datetime.datetime(2025, 1, 1))
+            .not_valid_after(datetime.datetime(2035, 1, 1))
+            .sign(root_key, None)
+        )
+        this_update = datetime.datetime(2025, 6, 1)
+        builder = (
+            ocsp.OCSPResponseBuilder()
+            .responder_id(ocsp.OCSPResponderEncoding.HASH, root)
+            .add_response(
+                cert,
+                root,
+                hashes.SHA256(),
+                ocsp.OCSPCertStatus.GOOD,
+                this_update,
+                this_update + datetime.timedelta(days=7),
+                None,
+                None,
+            )
+        )
+        resp = builder.sign(root_key, None)
+        assert (
+            resp.signature_algorithm_oid == x509.SignatureAlgorithmOID.ED25519
+        )
+        single_resp = resp.verify_single_response(cert, root)
+        assert single_resp.serial_number == 1
+        assert single_resp.certificate_status == ocsp.OCSPCertStatus.GOOD
+
+        # The signature is the last field of the response.
+        data = resp.public_bytes(serialization.Encoding.DER)
+        tampered = ocsp.load_der_ocsp_response(
+            data[:-1] + bytes([data[-1] ^ 0x01])
+        )
+        with pytest.raises(InvalidSignature):
+            tampered.verify_single_response(cert, root)
@@ -7,13 +7,18 @@ import binascii
 import copy
 import datetime
 import ipaddress
+import json
 import os
 import typing
 
 import pytest
 
 from cryptography import utils, x509
-from cryptography.exceptions import InvalidSignature, UnsupportedAlgorithm
+from cryptography.exceptions import (
+    InvalidSignature,
+    UnsupportedAlgorithm,
+    _Reasons,
+)
 from cryptography.hazmat.bindings._rust import test_support
 from cryptography.hazmat.primitives import hashes, serialization
 from cryptography.hazmat.primitives.asymmetric import (
@@ -30,6 +35,7 @@ from cryptography.hazmat.primitives.asymmetric import (
 )
 from cryptography.hazmat.primitives.asymmetric.utils import (
     decode_dss_signature,
+    encode_dss_signature,
 )
 from cryptography.x509.extensions import ExtendedKeyUsage
 from cryptography.x509.name import _ASN1Type
@@ -51,6 +57,7 @@ from ..hazmat.primitives.fixtures_rsa import (
 from ..hazmat.primitives.test_ec import _skip_curve_unsupported
 from ..hazmat.primitives.test_rsa import rsa_key_512, rsa_key_2048
 from ..utils import (
+    der_encode,
     load_nist_vectors,
     load_vectors_from_file,
     raises_unsupported_algorithm,
@@ -375,6 +382,85 @@ class TestCertificateRevocationList:
         assert revoked.serial_number == serial_number
         assert crl.get_revoked_certificate_by_serial_number(500) is None
 
+    def test_is_revoked_sorted(self, backend):
+        # 0 through 11, in order
+        crl = _load_cert(
+            os.path.join("x509", "custom", "crl_all_reasons.pem"),
+            x509.load_pem_x509_crl,
+        )
+        for serial in range(12):
+            revoked = crl.is_revoked(serial)
+            assert revoked is not None
+            assert revoked.serial_number == serial
+        assert crl.is_revoked(12) is None
+        assert crl.is_revoked(2**64) is None
+
+    def test_is_revoked_unsorted(self, rsa_key_2048: rsa.RSAPrivateKey):
+        builder = (
+            x509.CertificateRevocationListBuilder()
+            .issuer_name(
+                x509.Name([x509.NameAttribute(NameOID.COMMON_NAME, "PyCA")])
+            )
+            .last_update(datetime.datetime(2002, 1, 1, 12, 1))
+            .next_update(datetime.datetime(2030, 1, 1, 12, 1))
+        )
+        for serial in [5, 2**80, 3, 128, 1]:
+            builder = builder.add_revoked_certificate(
+                x509.RevokedCertificateBuilder()
+                .serial_number(serial)
+                .revocation_date(datetime.datetime(2012, 1, 1, 1, 1))
+                .build()
+            )
+        crl = builder.sign(rsa_key_2048, hashes.SHA256())
+        for serial in [5, 2**80, 3, 128, 1]:
+            revoked = crl.is_revoked(serial)
+            assert revoked is not None
+            assert revoked.serial_number == serial
+        assert crl.is_revoked(2) is None
+        assert crl[0].serial_number == 5
+
+    def test_is_revoked_large_sorted_crl(self, backend):
+        n = 1_000_000
+
+        # Even serials only, so odd ones are absent. Every entry shares a
+        # revocation date.
+        revocation_date = der_encode(0x17, b"120101010100Z")
+        entries = b"".join(
+            der_encode(
+                0x30,
+                der_encode(
+                    0x02,
+                    (2 * i).to_bytes(((2 * i).bit_length() + 8) // 8, "big"),
+                )
+                + revocation_date,
+            )
+            for i in range(n)
+        )
+        sha256_rsa = der_encode(
+            0x30,
+            der_encode(0x06, bytes.fromhex("2a864886f70d01010b"))
+            + der_encode(0x05, b""),
+        )
+        tbs = der_encode(
+            0x30,
+            der_encode(0x02, b"\x01")
+            + sha256_rsa
+            + der_encode(0x30, b"")
+            + der_encode(0x17, b"020101120100Z")
+            + der_encode(0x30, entries),
+        )
+        crl = x509.load_der_x509_crl(
+            der_encode(0x30, tbs + sha256_rsa + der_encode(0x03, b"\x00"))
+        )
+        assert len(crl) == n
+
+        for serial in [0, 2, 254, 256, 1_000_000, 2 * (n - 1)]:
+            revoked = crl.is_revoked(serial)
+            assert revoked is not None
+            assert revoked.serial_number == serial
+        for serial in [1, 255, 999_999, 2 * n, 2**64]:
+            assert crl.is_revoked(serial) is None
+
     def test_revoked_cert_retrieval_retain_only_revoked(self, backend):
         """
         This test attempts to trigger the crash condition described in
@@ -431,6 +517,18 @@ class TestCertificateRevocationList:
             [x509.UniformResourceIdentifier("https://cryptography.io")]
         )
 
+    def test_has_extension(self, backend):
+        crl = _load_cert(
+            os.path.join("x509", "custom", "crl_ian_aia_aki.pem"),
+            x509.load_pem_x509_crl,
+        )
+        assert crl.extension_count == len(crl.extensions)
+        assert crl.has_extension(ExtensionOID.CRL_NUMBER)
+        assert crl.has_extension(ExtensionOID.ISSUER_ALTERNATIVE_NAME)
+        assert not crl.has_extension(ExtensionOID.DELTA_CRL_INDICATOR)
+        with pytest.raises(TypeError):
+            crl.has_extension("2.5.29.20")  # type: ignore[arg-type]
+
     def test_delta_crl_indicator(self, backend):
         crl = _load_cert(
             os.path.join("x509", "custom", "crl_delta_crl_indicator.pem"),
@@ -547,6 +645,24 @@ class TestCertificateRevocationList:
         serialized = crl.public_bytes(encoding)
         assert serialized == crl_bytes
 
+    def test_public_bytes_pem_matches_der(self, backend):
+        crl_der = load_vectors_from_file(
+            os.path.join("x509", "PKITS_data", "crls", "GoodCACRL.crl"),
+            lambda derfile: derfile.read(),
+            mode="rb",
+        )
+        crl = x509.load_der_x509_crl(crl_der)
+        assert crl.public_bytes(serialization.Encoding.DER) == crl_der
+
+        pem = crl.public_bytes(serialization.Encoding.PEM)
+        lines = pem.splitlines()
+        assert lines[0] == b"-----BEGIN X509 CRL-----"
+        assert lines[-1] == b"-----END X509 CRL-----"
+        assert binascii.a2b_base64(b"".join(lines[1:-1])) == crl_der
+        assert x509.load_pem_x509_crl(pem).public_bytes(
+            serialization.Encoding.DER
+        ) == crl_der
+
     def test_public_bytes_invalid_encoding(self, backend):
         crl = _load_cert(
             os.path.join("x509", "custom", "crl_empty.pem"),
@@ -604,6 +720,346 @@ class TestCertificateRevocationList:
         with pytest.raises(TypeError):
             crl.is_signature_valid(object)  # type: ignore[arg-type]
 
+    @pytest.mark.supported(
+        only_if=lambda backend: backend.ed25519_supported(),
+        skip_message="Requires OpenSSL with Ed25519 support",
+    )
+    def test_verify_ed25519(self, backend):
+        private_key = ed25519.Ed25519PrivateKey.generate()
+        crl = (
+            x509.CertificateRevocationListBuilder()
+            .issuer_name(
+                x509.Name([x509.NameAttribute(NameOID.COMMON_NAME, "CA")])
+            )
+            .last_update(datetime.datetime(2020, 1, 1))
+            .next_update(datetime.datetime(2030, 1, 1))
+            .sign(private_key, None)
+        )
+        assert crl.signature_algorithm_oid == SignatureAlgorithmOID.ED25519
+        assert crl.is_signature_valid(private_key.public_key())
+
+        other_key = ed25519.Ed25519PrivateKey.generate()
+        assert not crl.is_signature_valid(other_key.public_key())
+        ec_key = ec.generate_private_key(ec.SECP256R1())
+        assert not crl.is_signature_valid(ec_key.public_key())
+
+    @pytest.mark.supported(
+        only_if=lambda backend: backend.ed448_supported(),
+        skip_message="Requires OpenSSL with Ed448 support",
+    )
+    def test_verify_ed448(self, backend):
+        private_key = ed448.Ed448PrivateKey.generate()
+        crl = (
+            x509.CertificateRevocationListBuilder()
+            .issuer_name(
+                x509.Name([x509.NameAttribute(NameOID.COMMON_NAME, "CA")])
+            )
+            .last_update(datetime.datetime(2020, 1, 1))
+            .next_update(datetime.datetime(2030, 1, 1))
+            .sign(private_key, None)
+        )
+        assert crl.signature_algorithm_oid == SignatureAlgorithmOID.ED448
+        assert crl.is_signature_valid(private_key.public_key())
+
+        other_key = ed448.Ed448PrivateKey.generate()
+        assert not crl.is_signature_valid(other_key.public_key())
+
+    def _issued_by_irina(self, private_key, ca):
+        return (
+            x509.CertificateBuilder()
+            .subject_name(
+                x509.Name([x509.NameAttribute(NameOID.COMMON_NAME, "subject")])
+            )
+            .issuer_name(
+                x509.Name(
+                    [x509.NameAttribute(NameOID.COMMON_NAME, "Irina CA")]
+                )
+            )
+            .public_key(private_key.public_key())
+            .serial_number(1)
+            .not_valid_before(datetime.datetime(2020, 1, 1))
+            .not_valid_after(datetime.datetime(2030, 1, 1))
+            .add_extension(
+                x509.BasicConstraints(ca=ca, path_length=None), critical=True
+            )
+            .sign(private_key, hashes.SHA256())
+        )
+
+    @pytest.mark.parametrize(
+        ("filename", "applies_to_ca", "applies_to_leaf"),
+        [
+            ("crl_idp_fullname_only_user.pem", False, True),
+            ("crl_idp_only_ca.pem", True, False),
+            ("crl_idp_fullname_only_aa.pem", False, False),
+            ("crl_idp_fullname_indirect_crl.pem", True, True),
+        ],
+    )
+    def test_applies_to(
+        self,
+        filename,
+        applies_to_ca,
+        applies_to_leaf,
+        rsa_key_2048: rsa.RSAPrivateKey,
+        backend,
+    ):
+        crl = _load_cert(
+            os.path.join("x509", "custom", filename),
+            x509.load_pem_x509_crl,
+        )
+        ca = self._issued_by_irina(rsa_key_2048, ca=True)
+        leaf = self._issued_by_irina(rsa_key_2048, ca=False)
+        assert crl.applies_to(ca) is applies_to_ca
+        assert crl.applies_to(leaf) is applies_to_leaf
+
+    def test_applies_to_issuer_mismatch(self, backend):
+        crl = _load_cert(
+            os.path.join("x509", "custom", "crl_idp_fullname_only_user.pem"),
+            x509.load_pem_x509_crl,
+        )
+        cert = _load_cert(
+            os.path.join("x509", "cryptography.io.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        assert crl.applies_to(cert) is False
+
+    def test_applies_to_no_idp(
+        self, rsa_key_2048: rsa.RSAPrivateKey, backend
+    ):
+        crl = _load_cert(
+            os.path.join("x509", "custom", "crl_all_reasons.pem"),
+            x509.load_pem_x509_crl,
+        )
+        cert = (
+            x509.CertificateBuilder()
+            .subject_name(
+                x509.Name([x509.NameAttribute(NameOID.COMMON_NAME, "subject")])
+            )
+            .issuer_name(crl.issuer)
+            .public_key(rsa_key_2048.public_key())
+            .serial_number(1)
+            .not_valid_before(datetime.datetime(2020, 1, 1))
+            .not_valid_after(datetime.datetime(2030, 1, 1))
+            .sign(rsa_key_2048, hashes.SHA256())
+        )
+        assert crl.applies_to(cert) is True
+
+    def test_applies_to_not_a_certificate(self, backend):
+        crl = _load_cert(
+            os.path.join("x509", "custom", "crl_all_reasons.pem"),
+            x509.load_pem_x509_crl,
+        )
+        with pytest.raises(TypeError):
+            crl.applies_to("not a cert")  # type: ignore[arg-type]
+
+    def test_applies_to_indirect_crl_other_issuer(self, backend):
+        crl = _load_cert(
+            os.path.join(
+                "x509", "custom", "crl_idp_fullname_indirect_crl.pem"
+            ),
+            x509.load_pem_x509_crl,
+        )
+        cert = _load_cert(
+            os.path.join("x509", "cryptography.io.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        # None of the CRL's entries name the certificate's issuer.
+        assert crl.applies_to(cert) is False
+        assert crl.covered_reasons(cert) == frozenset()
+        with pytest.raises(ValueError):
+            crl.is_revoked(cert)
+
+    def test_indirect_crl_certificate_issuer(
+        self, rsa_key_2048: rsa.RSAPrivateKey, backend
+    ):
+        other = _load_cert(
+            os.path.join("x509", "cryptography.io.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        leaf = self._issued_by_irina(rsa_key_2048, ca=False)
+
+        def revoked(serial_number, *extensions):
+            builder = (
+                x509.RevokedCertificateBuilder()
+                .serial_number(serial_number)
+                .revocation_date(datetime.datetime(2020, 1, 1))
+            )
+            for extension in extensions:
+                builder = builder.add_extension(extension, critical=False)
+            return builder.build()
+
+        crl = (
+            x509.CertificateRevocationListBuilder()
+            .issuer_name(leaf.issuer)
+            .last_update(datetime.datetime(2020, 1, 1))
+            .next_update(datetime.datetime(2030, 1, 1))
+            .add_extension(
+                x509.IssuingDistributionPoint(
+                    full_name=None,
+                    relative_name=None,
+                    only_contains_user_certs=False,
+                    only_contains_ca_certs=False,
+                    only_some_reasons=None,
+                    indirect_crl=True,
+                    only_contains_attribute_certs=False,
+                ),
+                critical=True,
+            )
+            .add_revoked_certificate(revoked(2))
+            .add_revoked_certificate(
+                revoked(
+                    other.serial_number,
+                    x509.CertificateIssuer([x509.DirectoryName(other.issuer)]),
+                )
+            )
+            # Also for the other issuer, as the previous entry's issuer is
+            # carried over.
+            .add_revoked_certificate(revoked(leaf.serial_number))
+            .sign(rsa_key_2048, hashes.SHA256())
+        )
+
+        assert crl.applies_to(other) is True
+        assert crl.applies_to(leaf) is True
+        entry = crl.is_revoked(other)
+        assert entry is not None
+        assert entry.serial_number == other.serial_number
+        assert crl.is_revoked(leaf.serial_number) is not None
+        assert crl.is_revoked(leaf) is None
+
+    def test_is_revoked_certificate_out_of_scope(
+        self, rsa_key_2048: rsa.RSAPrivateKey, backend
+    ):
+        crl = _load_cert(
+            os.path.join("x509", "custom", "crl_idp_fullname_only_user.pem"),
+            x509.load_pem_x509_crl,
+        )
+        ca = self._issued_by_irina(rsa_key_2048, ca=True)
+        leaf = self._issued_by_irina(rsa_key_2048, ca=False)
+        # A CRL of user certificates can't tell whether a CA is revoked.
+        with pytest.raises(ValueError, match="does not cover"):
+            crl.is_revoked(ca)
+        assert crl.is_revoked(leaf) is None
+
+    def test_covered_reasons(self, rsa_key_2048: rsa.RSAPrivateKey, backend):
+        leaf = self._issued_by_irina(rsa_key_2048, ca=False)
+        crl = _load_cert(
+            os.path.join("x509", "custom", "crl_idp_reasons_only.pem"),
+            x509.load_pem_x509_crl,
+        )
+        assert crl.covered_reasons(leaf) == frozenset(
+            [x509.ReasonFlags.key_compromise]
+        )
+
+        crl = _load_cert(
+            os.path.join("x509", "custom", "crl_idp_fullname_only_user.pem"),
+            x509.load_pem_x509_crl,
+        )
+        assert crl.covered_reasons(leaf) == frozenset(
+            [
+                x509.ReasonFlags.key_compromise,
+                x509.ReasonFlags.ca_compromise,
+                x509.ReasonFlags.affiliation_changed,
+                x509.ReasonFlags.superseded,
+                x509.ReasonFlags.cessation_of_operation,
+                x509.ReasonFlags.certificate_hold,
+                x509.ReasonFlags.privilege_withdrawn,
+                x509.ReasonFlags.aa_compromise,
+            ]
+        )
+        ca = self._issued_by_irina(rsa_key_2048, ca=True)
+        assert crl.covered_reasons(ca) == frozenset()
+
+    def _numbered_crl(self, private_key, issuer, crl_number):
+        builder = (
+            x509.CertificateRevocationListBuilder()
+            .issuer_name(
+                x509.Name([x509.NameAttribute(NameOID.COMMON_NAME, issuer)])
+            )
+            .last_update(datetime.datetime(2020, 1, 1))
+            .next_update(datetime.datetime(2030, 1, 1))
+        )
+        if crl_number is not None:
+            builder = builder.add_extension(
+                x509.CRLNumber(crl_number), critical=False
+            )
+        return builder.sign(private_key, hashes.SHA256())
+
+    def test_compare_crl_number(self, rsa_key_2048: rsa.RSAPrivateKey):
+        older = self._numbered_crl(rsa_key_2048, "Irina CA", 255)
+        newer = self._numbered_crl(rsa_key_2048, "Irina CA", 256)
+        assert older.compare_crl_number(newer) == -1
+        assert newer.compare_crl_number(older) == 1
+        assert newer.compare_crl_number(newer) == 0
+
+    def test_compare_crl_number_missing(
+        self, rsa_key_2048: rsa.RSAPrivateKey
+    ):
+        numbered = self._numbered_crl(rsa_key_2048, "Irina CA", 1)
+        unnumbered = self._numbered_crl(rsa_key_2048, "Irina CA", None)
+        assert numbered.compare_crl_number(unnumbered) is None
+        assert unnumbered.compare_crl_number(numbered) is None
+
+    def test_compare_crl_number_issuer_mismatch(
+        self, rsa_key_2048: rsa.RSAPrivateKey
+    ):
+        crl = self._numbered_crl(rsa_key_2048, "Irina CA", 1)
+        other = self._numbered_crl(rsa_key_2048, "Other CA", 2)
+        with pytest.raises(ValueError):
+            crl.compare_crl_number(other)
+
+    def _crl_revoking(self, private_key, crl_number, serials):
+        builder = (
+            x509.CertificateRevocationListBuilder()
+            .issuer_name(
+                x509.Name(
+                    [x509.NameAttribute(NameOID.COMMON_NAME, "Irina CA")]
+                )
+            )
+            .last_update(datetime.datetime(2020, 1, 1))
+            .next_update(datetime.datetime(2030, 1, 1))
+            .add_extension(x509.CRLNumber(crl_number), critical=False)
+        )
+        for serial in serials:
+            builder = builder.add_revoked_certificate(
+                x509.RevokedCertificateBuilder()
+                .serial_number(serial)
+                .revocation_date(datetime.datetime(2020, 1, 1))
+                .build()
+            )
+        return builder.sign(private_key, hashes.SHA256())
+
+    def test_revocation_changes(self, rsa_key_2048: rsa.RSAPrivateKey):
+        older = self._crl_revoking(rsa_key_2048, 1, [1, 2, 3])
+        newer = self._crl_revoking(rsa_key_2048, 2, [1, 3, 4, 2**100])
+        assert older.revocation_changes(newer) == ({4, 2**100}, {2})
+        assert older.revocation_changes(
+            newer, require_newer_crl_number=True
+        ) == ({4, 2**100}, {2})
+        assert newer.revocation_changes(newer) == (set(), set())
+
+    def test_revocation_changes_crl_number(
+        self, rsa_key_2048: rsa.RSAPrivateKey
+    ):
+        older = self._crl_revoking(rsa_key_2048, 1, [1])
+        newer = self._crl_revoking(rsa_key_2048, 2, [2])
+        assert newer.revocation_changes(older) == ({1}, {2})
+        with pytest.raises(ValueError):
+            newer.revocation_changes(older, require_newer_crl_number=True)
+        with pytest.raises(ValueError):
+            older.revocation_changes(older, require_newer_crl_number=True)
+        unnumbered = self._numbered_crl(rsa_key_2048, "Irina CA", None)
+        with pytest.raises(ValueError):
+            older.revocation_changes(
+                unnumbered, require_newer_crl_number=True
+            )
+
+    def test_revocation_changes_issuer_mismatch(
+        self, rsa_key_2048: rsa.RSAPrivateKey
+    ):
+        crl = self._crl_revoking(rsa_key_2048, 1, [1])
+        other = self._numbered_crl(rsa_key_2048, "Other CA", 2)
+        with pytest.raises(ValueError):
+            crl.revocation_changes(other)
+
 
 class TestRevokedCertificate:
     def test_revoked_basics(self, backend):
@@ -893,6 +1349,45 @@ class TestRSAPSSCertificate:
         assert pss._salt_length == 20
         assert isinstance(cert.signature_hash_algorithm, hashes.SHA1)
 
+    def test_load_pss_sha3_256(self, backend):
+        cert = _load_cert(
+            os.path.join("x509", "custom", "rsa_pss_sha3_256.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        pss = cert.signature_algorithm_parameters
+        assert isinstance(pss, padding.PSS)
+        assert isinstance(pss._mgf, padding.MGF1)
+        assert isinstance(pss._mgf._algorithm, hashes.SHA3_256)
+        assert pss._salt_length == 32
+        assert isinstance(cert.signature_hash_algorithm, hashes.SHA3_256)
+
+    @pytest.mark.supported(
+        only_if=lambda backend: backend.signature_hash_supported(
+            hashes.SHA3_256()
+        ),
+        skip_message="Does not support SHA3-256 signature.",
+    )
+    def test_verify_pss_sha3_256(self, backend):
+        cert = _load_cert(
+            os.path.join("x509", "custom", "rsa_pss_sha3_256.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        cert.verify_directly_issued_by(cert)
+
+    @pytest.mark.supported(
+        only_if=lambda backend: not backend.signature_hash_supported(
+            hashes.SHA3_256()
+        ),
+        skip_message="Requires a backend without SHA3-256 support.",
+    )
+    def test_verify_pss_sha3_256_unsupported(self, backend):
+        cert = _load_cert(
+            os.path.join("x509", "custom", "rsa_pss_sha3_256.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        with raises_unsupported_algorithm(_Reasons.UNSUPPORTED_HASH):
+            cert.verify_directly_issued_by(cert)
+
     def test_invalid_mgf(self, backend):
         cert = _load_cert(
             os.path.join("x509", "custom", "rsa_pss_cert_invalid_mgf.der"),
@@ -1138,6 +1633,31 @@ class TestRSACertificate:
             cert.signature_hash_algorithm,
         )
 
+    def test_der_field_spans(self, backend):
+        cert = _load_cert(
+            os.path.join("x509", "custom", "post2000utctime.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        der = cert.public_bytes(serialization.Encoding.DER)
+        spans = cert.der_field_spans
+        assert spans == {
+            "certificate": (0, 1012),
+            "tbs_certificate": (4, 732),
+            "signature_algorithm": (736, 15),
+            "signature": (751, 261),
+        }
+
+        def field(name):
+            offset, length = spans[name]
+            return der[offset : offset + length]
+
+        assert field("certificate") == der
+        assert field("tbs_certificate") == cert.tbs_certificate_bytes
+        assert field("signature_algorithm") == bytes.fromhex(
+            "300d06092a864886f70d0101050500"
+        )
+        assert field("signature")[-len(cert.signature) :] == cert.signature
+
     def test_tbs_precertificate_bytes_duplicate_extensions_raises(
         self, backend
     ):
@@ -1194,39 +1714,236 @@ class TestRSACertificate:
         )
         assert cert.tbs_precertificate_bytes != cert.tbs_certificate_bytes
 
-    def test_issuer(self, backend):
+    def test_has_extension(self, backend):
         cert = _load_cert(
-            os.path.join(
-                "x509",
-                "PKITS_data",
-                "certs",
-                "Validpre2000UTCnotBeforeDateTest3EE.crt",
-            ),
-            x509.load_der_x509_certificate,
+            os.path.join("x509", "cryptography.io.pem"),
+            x509.load_pem_x509_certificate,
         )
-        issuer = cert.issuer
-        assert isinstance(issuer, x509.Name)
-        assert list(issuer) == [
-            x509.NameAttribute(NameOID.COUNTRY_NAME, "US"),
-            x509.NameAttribute(
-                NameOID.ORGANIZATION_NAME, "Test Certificates 2011"
-            ),
-            x509.NameAttribute(NameOID.COMMON_NAME, "Good CA"),
-        ]
-        assert issuer.get_attributes_for_oid(NameOID.COMMON_NAME) == [
-            x509.NameAttribute(NameOID.COMMON_NAME, "Good CA")
-        ]
+        assert cert.extension_count == len(cert.extensions)
+        assert cert.has_extension(ExtensionOID.BASIC_CONSTRAINTS)
+        assert cert.has_extension(ExtensionOID.SUBJECT_ALTERNATIVE_NAME)
+        assert not cert.has_extension(ExtensionOID.NAME_CONSTRAINTS)
+        with pytest.raises(TypeError):
+            cert.has_extension("2.5.29.19")  # type: ignore[arg-type]
 
-    def test_all_issuer_name_types(self, backend):
+    def test_has_extension_unrecognized(self, backend):
         cert = _load_cert(
-            os.path.join("x509", "custom", "all_supported_names.pem"),
+            os.path.join("x509", "custom", "unsupported_extension.pem"),
             x509.load_pem_x509_certificate,
         )
-        issuer = cert.issuer
+        assert cert.extension_count == 1
+        assert cert.has_extension(x509.ObjectIdentifier("1.2.3.4"))
 
-        assert isinstance(issuer, x509.Name)
-        assert list(issuer) == [
-            x509.NameAttribute(NameOID.COUNTRY_NAME, "US"),
+    def test_has_extension_duplicate(self, backend):
+        cert = _load_cert(
+            os.path.join("x509", "custom", "two_basic_constraints.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        with pytest.raises(x509.DuplicateExtension):
+            cert.extensions
+        assert cert.extension_count == 2
+        assert cert.has_extension(ExtensionOID.BASIC_CONSTRAINTS)
+
+    def test_has_extension_no_extensions(self, backend):
+        cert = _load_cert(
+            os.path.join("x509", "v1_cert.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        assert cert.extension_count == 0
+        assert not cert.has_extension(ExtensionOID.BASIC_CONSTRAINTS)
+
+    def test_extension_bytes(self, backend):
+        cert = _load_cert(
+            os.path.join("x509", "cryptography.io.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        result = cert.extension_bytes(ExtensionOID.SUBJECT_ALTERNATIVE_NAME)
+        assert result is not None
+        wrapped, unwrapped = result
+        # The inner DER is the SAN's SEQUENCE OF GeneralName.
+        san = cert.extensions.get_extension_for_class(
+            x509.SubjectAlternativeName
+        )
+        assert unwrapped[0] == 0x30
+        assert unwrapped == san.value.public_bytes()
+        assert wrapped == b"\x04" + bytes([len(unwrapped)]) + unwrapped
+
+        assert cert.extension_bytes(ExtensionOID.NAME_CONSTRAINTS) is None
+        with pytest.raises(TypeError):
+            cert.extension_bytes("2.5.29.17")  # type: ignore[arg-type]
+
+    def test_extension_bytes_unrecognized(self, backend):
+        cert = _load_cert(
+            os.path.join("x509", "custom", "unsupported_extension.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        result = cert.extension_bytes(x509.ObjectIdentifier("1.2.3.4"))
+        assert result is not None
+        ext = cert.extensions.get_extension_for_oid(
+            x509.ObjectIdentifier("1.2.3.4")
+        )
+        assert result[1] == ext.value.value
+
+    def test_extension_bytes_duplicate(self, backend):
+        cert = _load_cert(
+            os.path.join("x509", "custom", "two_basic_constraints.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        with pytest.raises(x509.DuplicateExtension):
+            cert.extension_bytes(ExtensionOID.BASIC_CONSTRAINTS)
+
+    def test_unrecognized_extension_oids(
+        self, rsa_key_2048: rsa.RSAPrivateKey, backend
+    ):
+        name = x509.Name([x509.NameAttribute(NameOID.COMMON_NAME, "PyCA")])
+        cert = (
+            x509.CertificateBuilder()
+            .subject_name(name)
+            .issuer_name(name)
+            .public_key(rsa_key_2048.public_key())
+            .serial_number(1)
+            .not_valid_before(datetime.datetime(2020, 1, 1))
+            .not_valid_after(datetime.datetime(2030, 1, 1))
+            .add_extension(
+                x509.BasicConstraints(ca=False, path_length=None),
+                critical=True,
+            )
+            .add_extension(
+                x509.UnrecognizedExtension(
+                    x509.ObjectIdentifier("1.2.3.4"), b"\x05\x00"
+                ),
+                critical=False,
+            )
+            .sign(rsa_key_2048, hashes.SHA256())
+        )
+        assert cert.unrecognized_extension_oids() == [
+            x509.ObjectIdentifier("1.2.3.4")
+        ]
+
+        cert = _load_cert(
+            os.path.join("x509", "cryptography.io.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        assert cert.unrecognized_extension_oids() == []
+
+    @pytest.mark.parametrize(
+        "filename", ["ct_precert.pem", "ct_leaf_embedded_sct.pem"]
+    )
+    def test_precertificate_signed_data(self, filename, backend):
+        issuer = _load_cert(
+            os.path.join("x509", "custom", "ct_issuer.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        leaf = _load_cert(
+            os.path.join("x509", "custom", "ct_leaf_embedded_sct.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        log_key = load_vectors_from_file(
+            os.path.join("x509", "custom", "ct_log_pubkey.pem"),
+            lambda f: serialization.load_pem_public_key(f.read()),
+            mode="rb",
+        )
+        assert isinstance(log_key, ec.EllipticCurvePublicKey)
+        sct = leaf.extensions.get_extension_for_class(
+            x509.PrecertificateSignedCertificateTimestamps
+        ).value[0]
+        cert = _load_cert(
+            os.path.join("x509", "custom", filename),
+            x509.load_pem_x509_certificate,
+        )
+
+        data = cert.precertificate_signed_data(issuer, sct)
+        log_key.verify(sct.signature, data, ec.ECDSA(hashes.SHA256()))
+        tbs = leaf.tbs_precertificate_bytes
+        assert data.startswith(b"\x00\x00")
+        assert data.endswith(len(tbs).to_bytes(3, "big") + tbs + b"\x00\x00")
+        assert cert.precertificate_signed_data(leaf, sct) != data
+
+    def test_ct_issuer_key_hash(self, backend):
+        issuer = _load_cert(
+            os.path.join("x509", "custom", "ct_issuer.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        leaf = _load_cert(
+            os.path.join("x509", "custom", "ct_leaf_embedded_sct.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        log_key = load_vectors_from_file(
+            os.path.join("x509", "custom", "ct_log_pubkey.pem"),
+            lambda f: serialization.load_pem_public_key(f.read()),
+            mode="rb",
+        )
+        assert isinstance(log_key, ec.EllipticCurvePublicKey)
+        sct = leaf.extensions.get_extension_for_class(
+            x509.PrecertificateSignedCertificateTimestamps
+        ).value[0]
+
+        spki = issuer.public_key().public_bytes(
+            serialization.Encoding.DER,
+            serialization.PublicFormat.SubjectPublicKeyInfo,
+        )
+        digest = hashes.Hash(hashes.SHA256())
+        digest.update(spki)
+        assert issuer.ct_issuer_key_hash == digest.finalize()
+
+        # The log signed over: version, signature type, timestamp, entry
+        # type, then the issuer key hash.
+        data = leaf.precertificate_signed_data(issuer, sct)
+        assert data[12:44] == issuer.ct_issuer_key_hash
+        log_key.verify(sct.signature, data, ec.ECDSA(hashes.SHA256()))
+        assert leaf.ct_issuer_key_hash != issuer.ct_issuer_key_hash
+
+    def test_precertificate_signed_data_no_ct_extensions(self, backend):
+        cert = _load_cert(
+            os.path.join("x509", "cryptography.io.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        leaf = _load_cert(
+            os.path.join("x509", "custom", "ct_leaf_embedded_sct.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        sct = leaf.extensions.get_extension_for_class(
+            x509.PrecertificateSignedCertificateTimestamps
+        ).value[0]
+        with pytest.raises(
+            ValueError,
+            match="Could not find pre-certificate poison or SCT list",
+        ):
+            cert.precertificate_signed_data(leaf, sct)
+
+    def test_issuer(self, backend):
+        cert = _load_cert(
+            os.path.join(
+                "x509",
+                "PKITS_data",
+                "certs",
+                "Validpre2000UTCnotBeforeDateTest3EE.crt",
+            ),
+            x509.load_der_x509_certificate,
+        )
+        issuer = cert.issuer
+        assert isinstance(issuer, x509.Name)
+        assert list(issuer) == [
+            x509.NameAttribute(NameOID.COUNTRY_NAME, "US"),
+            x509.NameAttribute(
+                NameOID.ORGANIZATION_NAME, "Test Certificates 2011"
+            ),
+            x509.NameAttribute(NameOID.COMMON_NAME, "Good CA"),
+        ]
+        assert issuer.get_attributes_for_oid(NameOID.COMMON_NAME) == [
+            x509.NameAttribute(NameOID.COMMON_NAME, "Good CA")
+        ]
+
+    def test_all_issuer_name_types(self, backend):
+        cert = _load_cert(
+            os.path.join("x509", "custom", "all_supported_names.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        issuer = cert.issuer
+
+        assert isinstance(issuer, x509.Name)
+        assert list(issuer) == [
+            x509.NameAttribute(NameOID.COUNTRY_NAME, "US"),
             x509.NameAttribute(NameOID.COUNTRY_NAME, "CA"),
             x509.NameAttribute(NameOID.STATE_OR_PROVINCE_NAME, "Texas"),
             x509.NameAttribute(NameOID.STATE_OR_PROVINCE_NAME, "Illinois"),
@@ -1287,6 +2004,38 @@ class TestRSACertificate:
             )
         ]
 
+    def test_issuer_subject_bytes(self, backend):
+        cert = _load_cert(
+            os.path.join("x509", "cryptography.io.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        assert cert.subject_bytes == cert.subject.public_bytes()
+        assert cert.issuer_bytes == cert.issuer.public_bytes()
+        assert cert.subject_bytes != cert.issuer_bytes
+
+    def test_issuer_subject_bytes_self_signed(self, backend):
+        cert = _load_cert(
+            os.path.join("x509", "custom", "utf8_common_name.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        assert cert.subject_bytes == cert.issuer_bytes
+        assert cert.subject_bytes in cert.tbs_certificate_bytes
+
+    def test_validity_bytes(self, backend):
+        cert = _load_cert(
+            os.path.join(
+                "x509",
+                "PKITS_data",
+                "certs",
+                "ValidGeneralizedTimenotAfterDateTest8EE.crt",
+            ),
+            x509.load_der_x509_certificate,
+        )
+        assert cert.not_valid_before_bytes == b"\x17\x0d100101083000Z"
+        assert cert.not_valid_after_bytes == b"\x18\x0f20500101120100Z"
+        validity = cert.not_valid_before_bytes + cert.not_valid_after_bytes
+        assert validity in cert.tbs_certificate_bytes
+
     def test_unicode_name(self, backend):
         cert = _load_cert(
             os.path.join("x509", "custom", "utf8_common_name.pem"),
@@ -1471,6 +2220,35 @@ class TestRSACertificate:
         )
         assert cert.version is x509.Version.v3
 
+    def test_time_until_expiry(self, backend):
+        cert = _load_cert(
+            os.path.join(
+                "x509",
+                "PKITS_data",
+                "certs",
+                "ValidGeneralizedTimenotAfterDateTest8EE.crt",
+            ),
+            x509.load_der_x509_certificate,
+        )
+        assert cert.time_until_expiry(
+            datetime.datetime(2049, 12, 31, 12, 1)
+        ) == datetime.timedelta(days=1)
+        tz = datetime.timezone(datetime.timedelta(hours=1))
+        assert cert.time_until_expiry(
+            datetime.datetime(2050, 1, 1, 13, 1, tzinfo=tz)
+        ) == datetime.timedelta(0)
+
+    def test_time_until_expiry_expired(self, backend):
+        cert = _load_cert(
+            os.path.join("x509", "custom", "post2000utctime.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        delta = cert.time_until_expiry(
+            datetime.datetime(2014, 12, 27, 21, 41, 20, 500000)
+        )
+        assert delta == datetime.timedelta(days=-1, microseconds=-500000)
+        assert delta.total_seconds() < 0
+
     def test_invalid_version_cert(self, backend):
         with pytest.raises(x509.InvalidVersion) as exc:
             _load_cert(
@@ -1759,6 +2537,224 @@ class TestRSACertificate:
         )
         cert.verify_directly_issued_by(ca)
 
+    def test_to_dict(self):
+        cert = _load_cert(
+            os.path.join("x509", "badssl-sct.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        d = cert.to_dict()
+        assert list(d) == [
+            "version",
+            "serial_number",
+            "signature_algorithm",
+            "issuer",
+            "not_valid_before",
+            "not_valid_after",
+            "subject",
+            "public_key_algorithm",
+            "extensions",
+        ]
+        assert d["version"] == "v3"
+        assert d["serial_number"] == cert.serial_number
+        assert d["signature_algorithm"] == "1.2.840.113549.1.1.11"
+        assert d["not_valid_before"] == "2016-11-17T00:00:00+00:00"
+        assert d["subject"] == [
+            [
+                {
+                    "oid": "2.5.4.3",
+                    "name": "commonName",
+                    "value": "invalid-expected-sct.badssl.com",
+                    "type": "UTF8String",
+                }
+            ]
+        ]
+
+        extensions = {ext["name"]: ext for ext in d["extensions"]}
+        assert extensions["subjectAltName"] == {
+            "oid": "2.5.29.17",
+            "name": "subjectAltName",
+            "critical": False,
+            "value": [
+                {"type": "DNSName", "value": "invalid-expected-sct.badssl.com"}
+            ],
+        }
+        assert extensions["basicConstraints"]["value"] == {
+            "ca": False,
+            "path_length": None,
+        }
+        # encipher_only and decipher_only are undefined without
+        # key_agreement.
+        assert extensions["keyUsage"]["value"] == {
+            "digital_signature": True,
+            "content_commitment": False,
+            "key_encipherment": True,
+            "data_encipherment": False,
+            "key_agreement": False,
+            "key_cert_sign": False,
+            "crl_sign": False,
+        }
+        assert extensions["cRLDistributionPoints"]["value"] == [
+            {
+                "full_name": [
+                    {
+                        "type": "UniformResourceIdentifier",
+                        "value": "http://gp.symcb.com/gp.crl",
+                    }
+                ],
+                "relative_name": None,
+                "reasons": None,
+                "crl_issuer": None,
+            }
+        ]
+        [sct] = extensions["signedCertificateTimestampList"]["value"]
+        assert sct["signature_hash_algorithm"] == "sha256"
+        assert sct["log_id"] == (
+            "a7ce4a4e6207e0addee5fdaa4b1f86768767b5d002a55d47310e7e670a95eab2"
+        )
+        json.dumps(d)
+
+    def test_to_dict_unrecognized_extension(
+        self, rsa_key_2048: rsa.RSAPrivateKey
+    ):
+        cert = (
+            x509.CertificateBuilder()
+            .subject_name(x509.Name([]))
+            .issuer_name(x509.Name([]))
+            .public_key(rsa_key_2048.public_key())
+            .serial_number(1)
+            .not_valid_before(datetime.datetime(2020, 1, 1))
+            .not_valid_after(datetime.datetime(2030, 1, 1))
+            .add_extension(
+                x509.UnrecognizedExtension(
+                    x509.ObjectIdentifier("1.2.3.4"), b"\x05\x00"
+                ),
+                critical=False,
+            )
+            .sign(rsa_key_2048, hashes.SHA256())
+        )
+        assert cert.to_dict()["extensions"] == [
+            {
+                "oid": "1.2.3.4",
+                "name": "Unknown OID",
+                "critical": False,
+                "value": "0500",
+            }
+        ]
+
+    def test_to_dict_malformed_extension(
+        self, rsa_key_2048: rsa.RSAPrivateKey
+    ):
+        cert = (
+            x509.CertificateBuilder()
+            .subject_name(x509.Name([]))
+            .issuer_name(x509.Name([]))
+            .public_key(rsa_key_2048.public_key())
+            .serial_number(1)
+            .not_valid_before(datetime.datetime(2020, 1, 1))
+            .not_valid_after(datetime.datetime(2030, 1, 1))
+            .add_extension(
+                x509.UnrecognizedExtension(
+                    ExtensionOID.BASIC_CONSTRAINTS, b"\x05\x00"
+                ),
+                critical=True,
+            )
+            .sign(rsa_key_2048, hashes.SHA256())
+        )
+        with pytest.raises(ValueError):
+            cert.extensions
+        assert cert.to_dict()["extensions"] == [
+            {
+                "oid": "2.5.29.19",
+                "name": "basicConstraints",
+                "critical": True,
+                "value": "0500",
+            }
+        ]
+
+    def test_issuer_matches(self, rsa_key_2048: rsa.RSAPrivateKey):
+        issuer = x509.Name(
+            [
+                x509.RelativeDistinguishedName(
+                    [x509.NameAttribute(NameOID.COUNTRY_NAME, "US")]
+                ),
+                x509.RelativeDistinguishedName(
+                    [
+                        x509.NameAttribute(
+                            NameOID.ORGANIZATION_NAME,
+                            "Python  Cryptographic Authority",
+                            _ASN1Type.PrintableString,
+                        ),
+                        x509.NameAttribute(NameOID.COMMON_NAME, "PyCA CA"),
+                    ]
+                ),
+            ]
+        )
+        cert = (
+            x509.CertificateBuilder()
+            .subject_name(
+                x509.Name([x509.NameAttribute(NameOID.COMMON_NAME, "leaf")])
+            )
+            .issuer_name(issuer)
+            .public_key(rsa_key_2048.public_key())
+            .serial_number(1)
+            .not_valid_before(datetime.datetime(2020, 1, 1))
+            .not_valid_after(datetime.datetime(2030, 1, 1))
+            .sign(rsa_key_2048, hashes.SHA256())
+        )
+
+        assert cert.issuer_matches(issuer)
+        # String type, case and insignificant whitespace are ignored, as is
+        # the order of attributes within an RDN.
+        assert cert.issuer_matches(
+            "CN=pyca ca+O=python cryptographic authority,C=US"
+        )
+        assert not cert.issuer_matches(
+            "CN=PyCA CA+O=Python Cryptographic Authority,C=GB"
+        )
+        assert not cert.issuer_matches(
+            "CN=PyCA CA,O=Python Cryptographic Authority,C=US"
+        )
+        assert not cert.issuer_matches(x509.Name([]))
+        with pytest.raises(TypeError):
+            cert.issuer_matches(b"C=US")  # type: ignore[arg-type]
+
+    def test_is_self_signed_and_self_issued(
+        self, rsa_key_2048: rsa.RSAPrivateKey
+    ):
+        root = _load_cert(
+            os.path.join("x509", "custom", "ca", "ca.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        assert root.is_self_issued()
+        assert root.is_self_signed()
+
+        # A cross-certificate with the same subject and issuer name, but
+        # signed by a different key.
+        name = x509.Name([x509.NameAttribute(NameOID.COMMON_NAME, "PyCA CA")])
+        cross = (
+            x509.CertificateBuilder()
+            .subject_name(name)
+            .issuer_name(name)
+            .public_key(
+                RSA_KEY_2048_ALT.private_key(
+                    unsafe_skip_rsa_key_validation=True
+                ).public_key()
+            )
+            .serial_number(1)
+            .not_valid_before(datetime.datetime(2020, 1, 1))
+            .not_valid_after(datetime.datetime(2030, 1, 1))
+            .sign(rsa_key_2048, hashes.SHA256())
+        )
+        assert cross.is_self_issued()
+        assert not cross.is_self_signed()
+
+        leaf = _load_cert(
+            os.path.join("x509", "cryptography.io.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        assert not leaf.is_self_issued()
+        assert not leaf.is_self_signed()
+
     def test_verify_directly_issued_by_rsa_bad_sig(
         self, rsa_key_2048: rsa.RSAPrivateKey
     ):
@@ -1800,6 +2796,94 @@ class TestRSACertificate:
             "Issuer certificate subject does not match certificate issuer."
         )
 
+    def test_verify_signature_with_spki(
+        self, rsa_key_2048: rsa.RSAPrivateKey
+    ):
+        subject_private_key = RSA_KEY_2048_ALT.private_key(
+            unsafe_skip_rsa_key_validation=True
+        )
+        ca, cert = _generate_ca_and_leaf(rsa_key_2048, subject_private_key)
+        spki = rsa_key_2048.public_key().public_bytes(
+            serialization.Encoding.DER,
+            serialization.PublicFormat.SubjectPublicKeyInfo,
+        )
+        cert.verify_signature_with_spki(spki)
+        # The CA is self-signed by the same key.
+        ca.verify_signature_with_spki(spki)
+
+    def test_verify_signature_with_spki_wrong_key(
+        self, rsa_key_2048: rsa.RSAPrivateKey
+    ):
+        subject_private_key = RSA_KEY_2048_ALT.private_key(
+            unsafe_skip_rsa_key_validation=True
+        )
+        _, cert = _generate_ca_and_leaf(rsa_key_2048, subject_private_key)
+        wrong_spki = subject_private_key.public_key().public_bytes(
+            serialization.Encoding.DER,
+            serialization.PublicFormat.SubjectPublicKeyInfo,
+        )
+        with pytest.raises(InvalidSignature):
+            cert.verify_signature_with_spki(wrong_spki)
+
+        ec_spki = (
+            ec.generate_private_key(ec.SECP256R1())
+            .public_key()
+            .public_bytes(
+                serialization.Encoding.DER,
+                serialization.PublicFormat.SubjectPublicKeyInfo,
+            )
+        )
+        with pytest.raises(ValueError):
+            cert.verify_signature_with_spki(ec_spki)
+
+    def test_verify_signature_with_spki_no_name_check(
+        self, rsa_key_2048: rsa.RSAPrivateKey
+    ):
+        # The issuer name doesn't correspond to any certificate, which would
+        # make verify_directly_issued_by fail.
+        cert = (
+            x509.CertificateBuilder()
+            .subject_name(
+                x509.Name([x509.NameAttribute(NameOID.COMMON_NAME, "leaf")])
+            )
+            .issuer_name(
+                x509.Name([x509.NameAttribute(NameOID.COMMON_NAME, "bare")])
+            )
+            .public_key(rsa_key_2048.public_key())
+            .serial_number(1)
+            .not_valid_before(datetime.datetime(2020, 1, 1))
+            .not_valid_after(datetime.datetime(2030, 1, 1))
+            .sign(rsa_key_2048, hashes.SHA256())
+        )
+        cert.verify_signature_with_spki(
+            rsa_key_2048.public_key().public_bytes(
+                serialization.Encoding.DER,
+                serialization.PublicFormat.SubjectPublicKeyInfo,
+            )
+        )
+
+    def test_verify_signature_with_spki_invalid(self):
+        cert = _load_cert(
+            os.path.join("x509", "cryptography.io.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        with pytest.raises(ValueError):
+            cert.verify_signature_with_spki(b"not a key")
+
+    def test_verify_signature_with_spki_mismatched_inner_out_oid(self):
+        cert = _load_cert(
+            os.path.join(
+                "x509", "custom", "mismatch_inner_outer_sig_algorithm.der"
+            ),
+            x509.load_der_x509_certificate,
+        )
+        spki = cert.public_key().public_bytes(
+            serialization.Encoding.DER,
+            serialization.PublicFormat.SubjectPublicKeyInfo,
+        )
+        with pytest.raises(ValueError, match="Inner and outer"):
+            cert.verify_signature_with_spki(spki)
+
     def test_verify_directly_issued_by_algorithm_mismatch(
         self, rsa_key_2048: rsa.RSAPrivateKey
     ):
@@ -1818,6 +2902,41 @@ class TestRSACertificate:
         with pytest.raises(ValueError):
             cert.verify_directly_issued_by(ca2)
 
+    def test_signature_algorithm_matches_key(
+        self, rsa_key_2048: rsa.RSAPrivateKey
+    ):
+        subject_private_key = RSA_KEY_2048_ALT.private_key(
+            unsafe_skip_rsa_key_validation=True
+        )
+        ca, cert = _generate_ca_and_leaf(rsa_key_2048, subject_private_key)
+        ec_key = ec.generate_private_key(ec.SECP256R1()).public_key()
+        assert ca.signature_algorithm_matches_key(ca.public_key())
+        assert cert.signature_algorithm_matches_key(ca.public_key())
+        assert not cert.signature_algorithm_matches_key(ec_key)
+
+        with pytest.raises(TypeError):
+            cert.signature_algorithm_matches_key(
+                b"not a key"  # type: ignore[arg-type]
+            )
+
+    def test_signature_algorithm_matches_key_inconsistent(self):
+        cert = _load_cert(
+            os.path.join(
+                "x509", "custom", "ec_key_rsa_signature_algorithm.pem"
+            ),
+            x509.load_pem_x509_certificate,
+        )
+        assert isinstance(cert.public_key(), ec.EllipticCurvePublicKey)
+        assert (
+            cert.signature_algorithm_oid
+            == SignatureAlgorithmOID.RSA_WITH_SHA256
+        )
+        assert not cert.signature_algorithm_matches_key(cert.public_key())
+        rsa_public_key = RSA_KEY_2048_ALT.private_key(
+            unsafe_skip_rsa_key_validation=True
+        ).public_key()
+        assert cert.signature_algorithm_matches_key(rsa_public_key)
+
     @pytest.mark.supported(
         only_if=lambda backend: (
             backend.ed25519_supported() and backend.x25519_supported()
@@ -2110,12 +3229,23 @@ class TestRSACertificateRequest:
             x509.ObjectIdentifier("1.2.3.4"), b"value"
         )
 
+    def test_has_extension(self, backend):
+        request = _load_cert(
+            os.path.join("x509", "requests", "unsupported_extension.pem"),
+            x509.load_pem_x509_csr,
+        )
+        assert request.extension_count == 1
+        assert request.has_extension(x509.ObjectIdentifier("1.2.3.4"))
+        assert not request.has_extension(ExtensionOID.BASIC_CONSTRAINTS)
+
     def test_no_extension_with_other_attributes(self, backend):
         request = _load_cert(
             os.path.join("x509", "requests", "challenge-unstructured.pem"),
             x509.load_pem_x509_csr,
         )
         assert len(request.extensions) == 0
+        assert request.extension_count == 0
+        assert not request.has_extension(ExtensionOID.BASIC_CONSTRAINTS)
 
     def test_request_basic_constraints(self, backend):
         request = _load_cert(
@@ -2797,6 +3927,108 @@ class TestCertificateBuilder:
             NameOID.SURNAME, "RDNs"
         )
 
+    def test_smime_capabilities(
+        self, rsa_key_2048: rsa.RSAPrivateKey, backend
+    ):
+        aes256_cbc = bytes.fromhex("060960864801650304012a")
+        rc2_cbc = bytes.fromhex("06082a864886f70d0302")
+        rc2_key_bits = bytes.fromhex("02020080")
+        capabilities = (
+            b"\x30\x1d"
+            + b"\x30\x0b"
+            + aes256_cbc
+            + b"\x30\x0e"
+            + rc2_cbc
+            + rc2_key_bits
+        )
+        name = x509.Name([x509.NameAttribute(NameOID.COMMON_NAME, "test")])
+        builder = (
+            x509.CertificateBuilder()
+            .serial_number(1)
+            .issuer_name(name)
+            .subject_name(name)
+            .public_key(rsa_key_2048.public_key())
+            .not_valid_before(datetime.datetime(2020, 1, 1))
+            .not_valid_after(datetime.datetime(2038, 1, 1))
+        )
+        cert = builder.sign(rsa_key_2048, hashes.SHA256(), backend)
+        assert cert.smime_capabilities is None
+
+        cert = builder.add_extension(
+            x509.UnrecognizedExtension(
+                x509.ObjectIdentifier("1.2.840.113549.1.9.15"), capabilities
+            ),
+            critical=False,
+        ).sign(rsa_key_2048, hashes.SHA256(), backend)
+        assert cert.smime_capabilities == [
+            (x509.ObjectIdentifier("2.16.840.1.101.3.4.1.42"), None),
+            (x509.ObjectIdentifier("1.2.840.113549.3.2"), rc2_key_bits),
+        ]
+
+    def test_name_attributes_preserve_string_type(
+        self, rsa_key_2048: rsa.RSAPrivateKey, backend
+    ):
+        subject = x509.Name(
+            [
+                x509.RelativeDistinguishedName(
+                    [
+                        x509.NameAttribute(
+                            NameOID.COUNTRY_NAME,
+                            "US",
+                            _ASN1Type.PrintableString,
+                        )
+                    ]
+                ),
+                x509.RelativeDistinguishedName(
+                    [
+                        x509.NameAttribute(
+                            NameOID.ORGANIZATION_NAME,
+                            "Acme",
+                            _ASN1Type.PrintableString,
+                        ),
+                        x509.NameAttribute(
+                            NameOID.COMMON_NAME,
+                            "caf\u00e9",
+                            _ASN1Type.UTF8String,
+                        ),
+                    ]
+                ),
+            ]
+        )
+        issuer = x509.Name(
+            [
+                x509.NameAttribute(
+                    NameOID.COMMON_NAME, "CA", _ASN1Type.PrintableString
+                )
+            ]
+        )
+        cert = (
+            x509.CertificateBuilder()
+            .serial_number(1)
+            .issuer_name(issuer)
+            .subject_name(subject)
+            .public_key(rsa_key_2048.public_key())
+            .not_valid_before(datetime.datetime(2020, 1, 1))
+            .not_valid_after(datetime.datetime(2038, 1, 1))
+            .sign(rsa_key_2048, hashes.SHA256(), backend)
+        )
+        loaded_cert = x509.load_der_x509_certificate(
+            cert.public_bytes(serialization.Encoding.DER)
+        )
+
+        assert loaded_cert.issuer_attributes == [
+            [(NameOID.COMMON_NAME, "CA", _ASN1Type.PrintableString)]
+        ]
+        attributes = loaded_cert.subject_attributes
+        assert len(attributes) == 2
+        assert attributes[0] == [
+            (NameOID.COUNTRY_NAME, "US", _ASN1Type.PrintableString)
+        ]
+        assert sorted(attributes[1], key=lambda a: a[0].dotted_string) == [
+            (NameOID.ORGANIZATION_NAME, "Acme", _ASN1Type.PrintableString),
+            (NameOID.COMMON_NAME, "caf\u00e9", _ASN1Type.UTF8String),
+        ]
+
     @pytest.mark.parametrize(
         ("alg", "mgf_alg"),
         [
@@ -5630,6 +6862,23 @@ class TestECDSACertificate:
             cert.signature_algorithm_parameters,
         )
 
+    def test_ecdsa_signature_components(self, backend):
+        cert = _load_cert(
+            os.path.join("x509", "ecdsa_root.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        components = cert.ecdsa_signature_components
+        assert components is not None
+        r, s = components
+        assert encode_dss_signature(r, s) == cert.signature
+
+    def test_ecdsa_signature_components_non_ecdsa(self, backend):
+        cert = _load_cert(
+            os.path.join("x509", "cryptography.io.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        assert cert.ecdsa_signature_components is None
+
     def test_load_ecdsa_cert_null_alg_params(self, backend):
         """
         This test verifies that we successfully load certificates with encoded
@@ -5645,6 +6894,17 @@ class TestECDSACertificate:
             assert isinstance(cert.signature_hash_algorithm, hashes.SHA256)
             assert isinstance(cert.public_key(), ec.EllipticCurvePublicKey)
 
+    def test_load_ecdsa_sha1_cert_null_alg_params(self, backend):
+        with pytest.warns(utils.DeprecatedIn41):
+            cert = _load_cert(
+                os.path.join("x509", "custom", "ecdsa_sha1_null_alg.pem"),
+                x509.load_pem_x509_certificate,
+            )
+        assert (
+            cert.signature_algorithm_oid
+            == SignatureAlgorithmOID.ECDSA_WITH_SHA1
+        )
+
     def test_load_bitstring_dn(self, backend):
         cert = _load_cert(
             os.path.join("x509", "scottishpower-bitstring-dn.pem"),
@@ -5677,6 +6937,15 @@ class TestECDSACertificate:
         with pytest.raises(ValueError, match="Long-form"):
             cert.issuer
 
+    def test_load_name_misordered_set_of(self, backend):
+        # SET OF elements must be sorted by their DER encoding, and this is
+        # always enforced at parse time.
+        with pytest.raises(ValueError, match="InvalidSetOrdering"):
+            _load_cert(
+                os.path.join("x509", "custom", "rdn_set_misordered.pem"),
+                x509.load_pem_x509_certificate,
+            )
+
     def test_ms_certificate_template(self, backend):
         cert = _load_cert(
             os.path.join("x509", "custom", "ms-certificate-template.pem"),
@@ -5693,6 +6962,492 @@ class TestECDSACertificate:
             minor_version=None,
         )
 
+    def test_acme_identifier(self, backend):
+        cert = _load_cert(
+            os.path.join("x509", "custom", "acme_tls_alpn_01.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        assert cert.is_acme_challenge is True
+        assert cert.acme_identifier == binascii.unhexlify(
+            b"eb5ac167fe249c76e53b430da3116c5d9fde4c5507ba6d01e885516e1bd49f92"
+        )
+
+    def test_acme_identifier_not_present(self, backend):
+        cert = _load_cert(
+            os.path.join("x509", "ecdsa_root.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        assert cert.is_acme_challenge is False
+        assert cert.acme_identifier is None
+
+    def test_acme_identifier_invalid_length(self, backend):
+        private_key = ec.generate_private_key(ec.SECP256R1())
+        name = x509.Name(
+            [x509.NameAttribute(NameOID.COMMON_NAME, "ACME challenge")]
+        )
+        cert = (
+            x509.CertificateBuilder()
+            .subject_name(name)
+            .issuer_name(name)
+            .public_key(private_key.public_key())
+            .serial_number(1)
+            .not_valid_before(datetime.datetime(2025, 1, 1))
+            .not_valid_after(datetime.datetime(2035, 1, 1))
+            .add_extension(
+                x509.UnrecognizedExtension(
+                    x509.ObjectIdentifier("1.3.6.1.5.5.7.1.31"),
+                    b"\x04\x10" + b"\x00" * 16,
+                ),
+                critical=True,
+            )
+            .sign(private_key, hashes.SHA256())
+        )
+        assert cert.is_acme_challenge is True
+        with pytest.raises(ValueError, match="32 byte"):
+            cert.acme_identifier
+
+    def test_tn_auth_list(self, backend):
+        cert = _load_cert(
+            os.path.join("x509", "custom", "shaken_spc.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        assert cert.tn_auth_list == [("spc", "1234")]
+
+    def test_tn_auth_list_numbers(self, backend):
+        private_key = ec.generate_private_key(ec.SECP256R1())
+        name = x509.Name([x509.NameAttribute(NameOID.COMMON_NAME, "SHAKEN")])
+        cert = (
+            x509.CertificateBuilder()
+            .subject_name(name)
+            .issuer_name(name)
+            .public_key(private_key.public_key())
+            .serial_number(1)
+            .not_valid_before(datetime.datetime(2025, 1, 1))
+            .not_valid_after(datetime.datetime(2035, 1, 1))
+            .add_extension(
+                x509.UnrecognizedExtension(
+                    x509.ObjectIdentifier("1.3.6.1.5.5.7.1.26"),
+                    binascii.unhexlify(
+                        b"3021a111300f160a32303235353530313030020164"
+                        b"a20c160a32303235353530313939"
+                    ),
+                ),
+                critical=False,
+            )
+            .sign(private_key, hashes.SHA256())
+        )
+        assert cert.tn_auth_list == [
+            ("range", ("2025550100", 100)),
+            ("one", "2025550199"),
+        ]
+
+    def test_tn_auth_list_not_present(self, backend):
+        cert = _load_cert(
+            os.path.join("x509", "ecdsa_root.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        assert cert.tn_auth_list is None
+
+    def _ca_with_access(self, extensions):
+        private_key = ec.generate_private_key(ec.SECP256R1())
+        name = x509.Name([x509.NameAttribute(NameOID.COMMON_NAME, "PyCA CA")])
+        builder = (
+            x509.CertificateBuilder()
+            .subject_name(name)
+            .issuer_name(name)
+            .public_key(private_key.public_key())
+            .serial_number(1)
+            .not_valid_before(datetime.datetime(2025, 1, 1))
+            .not_valid_after(datetime.datetime(2035, 1, 1))
+            .add_extension(
+                x509.BasicConstraints(ca=True, path_length=None),
+                critical=True,
+            )
+        )
+        for ext in extensions:
+            builder = builder.add_extension(ext, critical=False)
+        return builder.sign(private_key, hashes.SHA256())
+
+    def test_ocsp_responder_urls_aia(self, backend):
+        cert = _load_cert(
+            os.path.join("x509", "cryptography.io.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        assert cert.ocsp_responder_urls == ["http://gv.symcd.com"]
+
+    def test_ocsp_responder_urls_multiple(self, backend):
+        cert = self._ca_with_access(
+            [
+                x509.AuthorityInformationAccess(
+                    [
+                        x509.AccessDescription(
+                            AuthorityInformationAccessOID.OCSP,
+                            x509.UniformResourceIdentifier(
+                                "http://ocsp1.example.com"
+                            ),
+                        ),
+                        x509.AccessDescription(
+                            AuthorityInformationAccessOID.CA_ISSUERS,
+                            x509.UniformResourceIdentifier(
+                                "http://ca.example.com/issuer.crt"
+                            ),
+                        ),
+                        x509.AccessDescription(
+                            AuthorityInformationAccessOID.OCSP,
+                            x509.UniformResourceIdentifier(
+                                "http://ocsp2.example.com"
+                            ),
+                        ),
+                        x509.AccessDescription(
+                            AuthorityInformationAccessOID.OCSP,
+                            x509.UniformResourceIdentifier(
+                                "ldap://ldap.example.com/cn=ocsp"
+                            ),
+                        ),
+                    ]
+                ),
+                x509.SubjectInformationAccess(
+                    [
+                        x509.AccessDescription(
+                            AuthorityInformationAccessOID.OCSP,
+                            x509.UniformResourceIdentifier(
+                                "http://sia.example.com"
+                            ),
+                        )
+                    ]
+                ),
+            ]
+        )
+        assert cert.ocsp_responder_urls == [
+            "http://ocsp1.example.com",
+            "http://ocsp2.example.com",
+            "ldap://ldap.example.com/cn=ocsp",
+        ]
+
+    def test_ocsp_responder_urls_sia_only(self, backend):
+        cert = self._ca_with_access(
+            [
+                x509.AuthorityInformationAccess(
+                    [
+                        x509.AccessDescription(
+                            AuthorityInformationAccessOID.CA_ISSUERS,
+                            x509.UniformResourceIdentifier(
+                                "http://ca.example.com/issuer.crt"
+                            ),
+                        )
+                    ]
+                ),
+                x509.SubjectInformationAccess(
+                    [
+                        x509.AccessDescription(
+                            AuthorityInformationAccessOID.OCSP,
+                            x509.UniformResourceIdentifier(
+                                "http://ocsp.example.com"
+                            ),
+                        )
+                    ]
+                ),
+            ]
+        )
+        assert cert.ocsp_responder_urls == ["http://ocsp.example.com"]
+
+    def test_ocsp_responder_urls_aia_precedence(self, backend):
+        def access(url):
+            return [
+                x509.AccessDescription(
+                    AuthorityInformationAccessOID.OCSP,
+                    x509.DNSName("ocsp.example.com"),
+                ),
+                x509.AccessDescription(
+                    AuthorityInformationAccessOID.OCSP,
+                    x509.UniformResourceIdentifier(url),
+                ),
+            ]
+
+        cert = self._ca_with_access(
+            [
+                x509.SubjectInformationAccess(access("http://sia.example")),
+                x509.AuthorityInformationAccess(access("http://aia.example")),
+            ]
+        )
+        assert cert.ocsp_responder_urls == ["http://aia.example"]
+
+    def test_ocsp_responder_urls_not_present(self, backend):
+        assert self._ca_with_access([]).ocsp_responder_urls == []
+        cert = _load_cert(
+            os.path.join("x509", "custom", "sia.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        assert cert.ocsp_responder_urls == []
+
+    def test_timestamping_urls(self, backend):
+        cert = self._ca_with_access(
+            [
+                x509.AuthorityInformationAccess(
+                    [
+                        x509.AccessDescription(
+                            SubjectInformationAccessOID.TIME_STAMPING,
+                            x509.UniformResourceIdentifier(
+                                "http://aia.example.com/tsa"
+                            ),
+                        )
+                    ]
+                ),
+                x509.SubjectInformationAccess(
+                    [
+                        x509.AccessDescription(
+                            SubjectInformationAccessOID.TIME_STAMPING,
+                            x509.UniformResourceIdentifier(
+                                "http://tsa.example.com"
+                            ),
+                        ),
+                        x509.AccessDescription(
+                            SubjectInformationAccessOID.CA_REPOSITORY,
+                            x509.UniformResourceIdentifier(
+                                "http://ca.example.com/repository"
+                            ),
+                        ),
+                        x509.AccessDescription(
+                            SubjectInformationAccessOID.TIME_STAMPING,
+                            x509.DNSName("tsa.example.com"),
+                        ),
+                        x509.AccessDescription(
+                            SubjectInformationAccessOID.TIME_STAMPING,
+                            x509.UniformResourceIdentifier(
+                                "http://tsa2.example.com"
+                            ),
+                        ),
+                    ]
+                ),
+            ]
+        )
+        assert cert.timestamping_urls == [
+            "http://tsa.example.com",
+            "http://tsa2.example.com",
+        ]
+
+    def test_general_names(self, backend):
+        crl_issuer = x509.DirectoryName(
+            x509.Name([x509.NameAttribute(NameOID.COMMON_NAME, "PyCA CRLs")])
+        )
+        cert = self._ca_with_access(
+            [
+                x509.SubjectAlternativeName(
+                    [
+                        x509.DNSName("cryptography.io"),
+                        x509.IPAddress(ipaddress.IPv4Address("127.0.0.1")),
+                    ]
+                ),
+                x509.SubjectKeyIdentifier(b"\x00" * 20),
+                x509.CRLDistributionPoints(
+                    [
+                        x509.DistributionPoint(
+                            full_name=[
+                                x509.UniformResourceIdentifier(
+                                    "http://crl.example.com/ca.crl"
+                                )
+                            ],
+                            relative_name=None,
+                            reasons=None,
+                            crl_issuer=[crl_issuer],
+                        )
+                    ]
+                ),
+                x509.AuthorityInformationAccess(
+                    [
+                        x509.AccessDescription(
+                            AuthorityInformationAccessOID.OCSP,
+                            x509.UniformResourceIdentifier(
+                                "http://ocsp.example.com"
+                            ),
+                        ),
+                        x509.AccessDescription(
+                            AuthorityInformationAccessOID.CA_ISSUERS,
+                            x509.UniformResourceIdentifier(
+                                "http://ca.example.com/issuer.crt"
+                            ),
+                        ),
+                    ]
+                ),
+            ]
+        )
+        assert cert.general_names == [
+            (
+                ExtensionOID.SUBJECT_ALTERNATIVE_NAME,
+                x509.DNSName("cryptography.io"),
+            ),
+            (
+                ExtensionOID.SUBJECT_ALTERNATIVE_NAME,
+                x509.IPAddress(ipaddress.IPv4Address("127.0.0.1")),
+            ),
+            (
+                ExtensionOID.CRL_DISTRIBUTION_POINTS,
+                x509.UniformResourceIdentifier(
+                    "http://crl.example.com/ca.crl"
+                ),
+            ),
+            (ExtensionOID.CRL_DISTRIBUTION_POINTS, crl_issuer),
+            (
+                ExtensionOID.AUTHORITY_INFORMATION_ACCESS,
+                x509.UniformResourceIdentifier("http://ocsp.example.com"),
+            ),
+            (
+                ExtensionOID.AUTHORITY_INFORMATION_ACCESS,
+                x509.UniformResourceIdentifier(
+                    "http://ca.example.com/issuer.crt"
+                ),
+            ),
+        ]
+
+    def test_general_names_name_constraints(self, backend):
+        cert = _load_cert(
+            os.path.join("x509", "custom", "nc_permitted_excluded_2.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        nc = cert.extensions.get_extension_for_class(
+            x509.NameConstraints
+        ).value
+        assert nc.permitted_subtrees is not None
+        assert nc.excluded_subtrees is not None
+        assert cert.general_names == [
+            (ExtensionOID.NAME_CONSTRAINTS, gn)
+            for gn in list(nc.permitted_subtrees) + list(nc.excluded_subtrees)
+        ]
+
+    def test_general_names_not_present(self, backend):
+        assert self._ca_with_access([]).general_names == []
+
+    def test_timestamping_urls_not_present(self, backend):
+        assert self._ca_with_access([]).timestamping_urls == []
+        cert = _load_cert(
+            os.path.join("x509", "custom", "sia.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        assert cert.timestamping_urls == []
+
+    @pytest.mark.parametrize(
+        ("filename", "expected"),
+        [
+            ("ecdsa_root.pem", {"cert_sign", "crl_sign"}),
+            ("rapidssl_sha256_ca_g3.pem", {"cert_sign", "crl_sign"}),
+            ("cryptography.io.pem", {"tls_server", "tls_client"}),
+            # No keyUsage, extendedKeyUsage or basicConstraints
+            (
+                os.path.join("custom", "san_email_dns_ip_dirname_uri.pem"),
+                set(),
+            ),
+        ],
+    )
+    def test_capabilities(self, filename, expected, backend):
+        cert = _load_cert(
+            os.path.join("x509", filename),
+            x509.load_pem_x509_certificate,
+        )
+        assert cert.capabilities == frozenset(expected)
+
+    def test_capabilities_key_usage_restricts_eku(self, backend):
+        eku = x509.ExtendedKeyUsage(
+            [
+                ExtendedKeyUsageOID.SERVER_AUTH,
+                ExtendedKeyUsageOID.CLIENT_AUTH,
+                ExtendedKeyUsageOID.EMAIL_PROTECTION,
+            ]
+        )
+        # A CA without a keyUsage isn't assumed to sign anything.
+        cert = self._ca_with_access([])
+        assert cert.capabilities == frozenset()
+
+        private_key = ec.generate_private_key(ec.SECP256R1())
+        name = x509.Name([x509.NameAttribute(NameOID.COMMON_NAME, "leaf")])
+        builder = (
+            x509.CertificateBuilder()
+            .subject_name(name)
+            .issuer_name(name)
+            .public_key(private_key.public_key())
+            .serial_number(1)
+            .not_valid_before(datetime.datetime(2025, 1, 1))
+            .not_valid_after(datetime.datetime(2035, 1, 1))
+            .add_extension(eku, critical=False)
+        )
+        # Without a keyUsage, the extendedKeyUsage alone decides.
+        cert = builder.sign(private_key, hashes.SHA256())
+        assert cert.capabilities == frozenset(
+            {"tls_server", "tls_client", "email_protection"}
+        )
+
+        cert = builder.add_extension(
+            x509.KeyUsage(
+                digital_signature=False,
+                content_commitment=True,
+                key_encipherment=False,
+                data_encipherment=False,
+                key_agreement=False,
+                key_cert_sign=True,
+                crl_sign=False,
+                encipher_only=False,
+                decipher_only=False,
+            ),
+            critical=True,
+        ).sign(private_key, hashes.SHA256())
+        # keyCertSign without basicConstraints cA doesn't make a CA.
+        assert cert.capabilities == frozenset({"email_protection"})
+
+        cert = builder.add_extension(
+            x509.KeyUsage(
+                digital_signature=False,
+                content_commitment=False,
+                key_encipherment=False,
+                data_encipherment=False,
+                key_agreement=True,
+                key_cert_sign=False,
+                crl_sign=False,
+                encipher_only=False,
+                decipher_only=False,
+            ),
+            critical=True,
+        ).sign(private_key, hashes.SHA256())
+        # Server verification rejects a keyAgreement-only leaf, so it isn't
+        # a TLS server either.
+        assert cert.capabilities == frozenset(
+            {"tls_client", "email_protection"}
+        )
+
+    def test_dns_name_patterns(self, backend):
+        private_key = ec.generate_private_key(ec.SECP256R1())
+        name = x509.Name([x509.NameAttribute(NameOID.COMMON_NAME, "leaf")])
+        builder = (
+            x509.CertificateBuilder()
+            .subject_name(name)
+            .issuer_name(name)
+            .public_key(private_key.public_key())
+            .serial_number(1)
+            .not_valid_before(datetime.datetime(2025, 1, 1))
+            .not_valid_after(datetime.datetime(2035, 1, 1))
+        )
+        cert = builder.sign(private_key, hashes.SHA256())
+        assert cert.dns_name_patterns == []
+
+        cert = builder.add_extension(
+            x509.SubjectAlternativeName(
+                [
+                    x509.DNSName("*.example.com"),
+                    x509.DNSName("example.com"),
+                    x509.IPAddress(ipaddress.IPv4Address("127.0.0.1")),
+                    x509.DNSName("*.*.example.com"),
+                    x509.DNSName("foo*.example.com"),
+                    x509.DNSName("bad..example.com"),
+                ]
+            ),
+            critical=False,
+        ).sign(private_key, hashes.SHA256())
+        assert cert.dns_name_patterns == [
+            ("*.example.com", True, "example.com"),
+            ("example.com", False, "example.com"),
+            ("*.*.example.com", True, None),
+            ("foo*.example.com", True, None),
+            ("bad..example.com", False, None),
+        ]
+
     def test_signature(self, backend):
         cert = _load_cert(
             os.path.join("x509", "ecdsa_root.pem"),
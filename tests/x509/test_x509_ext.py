@@ -1904,6 +1904,36 @@ class TestDNSName:
         assert hash(n1) != hash(n2)
         assert hash(n2) == hash(n3)
 
+    @pytest.mark.parametrize(
+        ("value", "expected"),
+        [
+            ("cryptography.io", "cryptography.io"),
+            ("xn--k4h.ws", "\u2623.ws"),
+            ("XN--K4H.ws", "\u2623.ws"),
+            ("XN--BCHER-KVA.example", "b\xfccher.example"),
+            ("xn--BcHeR-kVa.example", "b\xfccher.example"),
+            ("*.xn--4ca7aey.example.com", "*.\xf5\xe4\xf6\xfc.example.com"),
+        ],
+    )
+    def test_to_unicode(self, value, expected):
+        assert x509.DNSName(value).to_unicode() == (expected, True)
+
+    @pytest.mark.parametrize(
+        "value",
+        [
+            # Not valid punycode
+            "xn--zzzzzzzzzz9.example.com",
+            # Decodes to nothing
+            "xn--.example.com",
+            # Decodes to ASCII, which shouldn't have been encoded
+            "xn--a-.example.com",
+            # Decodes to control characters
+            "xn--abc.example.com",
+        ],
+    )
+    def test_to_unicode_invalid(self, value):
+        assert x509.DNSName(value).to_unicode() == (value, False)
+
 
 class TestDirectoryName:
     def test_not_name(self):
@@ -2493,6 +2523,13 @@ class TestRSASubjectAlternativeNameExtension:
         dns = ext.value.get_values_for_type(x509.DNSName)
         assert dns == ["*.xn--80ato2c.cryptography"]
 
+        [name] = ext.value
+        assert isinstance(name, x509.DNSName)
+        assert name.to_unicode() == (
+            "*.\u043f\u044b\u043a\u0430.cryptography",
+            True,
+        )
+
     def test_unsupported_gn(self, backend):
         cert = _load_cert(
             os.path.join("x509", "san_x400address.der"),
@@ -2576,6 +2613,20 @@ class TestRSASubjectAlternativeNameExtension:
             )
         ] == dirname
 
+    def test_dirname_attributes(self, backend):
+        cert = _load_cert(
+            os.path.join("x509", "custom", "san_dirname.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        san = cert.extensions.get_extension_for_class(
+            x509.SubjectAlternativeName
+        ).value
+        [dirname] = san.get_values_for_type(x509.DirectoryName)
+        assert isinstance(dirname, x509.Name)
+        [cn] = dirname.get_attributes_for_oid(NameOID.COMMON_NAME)
+        assert cn.value == "test"
+        assert dirname.rfc4514_string() == "ST=Texas,O=Org,CN=test"
+
     def test_rfc822name(self, backend):
         cert = _load_cert(
             os.path.join("x509", "custom", "san_rfc822_idna.pem"),
@@ -3532,6 +3583,12 @@ class TestAuthorityKeyIdentifierExtension:
             )
         ]
         assert ext.value.authority_cert_serial_number == 3
+        assert isinstance(ext.value.key_identifier, bytes)
+        assert isinstance(ext.value.authority_cert_serial_number, int)
+        assert all(
+            isinstance(gn, x509.GeneralName)
+            for gn in ext.value.authority_cert_issuer
+        )
 
     def test_aki_no_keyid(self, backend):
         cert = _load_cert(
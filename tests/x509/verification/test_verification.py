@@ -11,16 +11,43 @@ from typing import Optional, Type
 import pytest
 
 from cryptography import utils, x509
-from cryptography.hazmat._oid import ExtendedKeyUsageOID
-from cryptography.x509 import ExtensionType
+from cryptography.exceptions import InvalidSignature
+from cryptography.hazmat._oid import (
+    ExtendedKeyUsageOID,
+    ExtensionOID,
+    NameOID,
+    SignatureAlgorithmOID,
+)
+from cryptography.hazmat.primitives import hashes, serialization
+from cryptography.hazmat.primitives.asymmetric import ec, padding, rsa
+from cryptography.hazmat.primitives.serialization import pkcs7
+from cryptography.x509 import ExtensionType, ocsp
 from cryptography.x509.general_name import DNSName, IPAddress
 from cryptography.x509.verification import (
     Criticality,
     ExtensionPolicy,
+    OCSPVerificationError,
+    OCSPVerificationStage,
     Policy,
     PolicyBuilder,
     Store,
+    ValidityStatus,
     VerificationError,
+    VerificationStep,
+    TLSVerificationError,
+    TLSVerificationStage,
+    VerificationWarning,
+    matching_subjects,
+    verify_ocsp_response,
+    verify_timestamp_token,
+    verify_timestamp_token_within_validity,
+    verify_tls_server,
+)
+from tests.utils import (
+    der_contents,
+    der_elements,
+    der_encode,
+    load_vectors_from_file,
 )
 from tests.x509.test_x509 import _load_cert
 
@@ -36,6 +63,104 @@ def dummy_store() -> Store:
     return Store([cert])
 
 
+def _resign_with_sha1(cert, issuer_key):
+    # CertificateBuilder no longer signs with SHA-1, so swap the signature
+    # algorithm in the TBSCertificate and sign it by hand.
+    if isinstance(issuer_key, rsa.RSAPrivateKey):
+        # sha1WithRSAEncryption
+        algorithm = bytes.fromhex("300d06092a864886f70d0101050500")
+    else:
+        # ecdsa-with-SHA1
+        algorithm = bytes.fromhex("300906072a8648ce3d0401")
+    tbs_fields = der_elements(der_contents(cert.tbs_certificate_bytes))
+    # version, serialNumber, signature, ...
+    tbs_fields[2] = algorithm
+    tbs = der_encode(0x30, b"".join(tbs_fields))
+    if isinstance(issuer_key, rsa.RSAPrivateKey):
+        signature = issuer_key.sign(tbs, padding.PKCS1v15(), hashes.SHA1())
+    else:
+        signature = issuer_key.sign(tbs, ec.ECDSA(hashes.SHA1()))
+    return x509.load_der_x509_certificate(
+        der_encode(
+            0x30, tbs + algorithm + der_encode(0x03, b"\x00" + signature)
+        )
+    )
+
+
+_SAN = x509.SubjectAlternativeName([DNSName("cryptography.io")])
+
+
+def _cert_builder(
+    subject,
+    key,
+    issuer,
+    issuer_key,
+    *,
+    ca,
+    now=datetime.datetime(2025, 1, 1),
+):
+    builder = (
+        x509.CertificateBuilder()
+        .subject_name(
+            x509.Name([x509.NameAttribute(NameOID.COMMON_NAME, subject)])
+        )
+        .issuer_name(
+            x509.Name([x509.NameAttribute(NameOID.COMMON_NAME, issuer)])
+        )
+        .public_key(key.public_key())
+        .serial_number(x509.random_serial_number())
+        .not_valid_before(now)
+        .not_valid_after(now + datetime.timedelta(days=365))
+        .add_extension(
+            x509.SubjectKeyIdentifier.from_public_key(key.public_key()),
+            critical=False,
+        )
+        .add_extension(
+            x509.AuthorityKeyIdentifier.from_issuer_public_key(
+                issuer_key.public_key()
+            ),
+            critical=False,
+        )
+    )
+    if ca:
+        builder = builder.add_extension(
+            x509.BasicConstraints(ca=True, path_length=None),
+            critical=True,
+        ).add_extension(
+            x509.KeyUsage(
+                digital_signature=False,
+                content_commitment=False,
+                key_encipherment=False,
+                data_encipherment=False,
+                key_agreement=False,
+                key_cert_sign=True,
+                crl_sign=True,
+                encipher_only=False,
+                decipher_only=False,
+            ),
+            critical=True,
+        )
+    return builder
+
+
+def _issue(
+    subject,
+    key,
+    issuer,
+    issuer_key,
+    *,
+    ca,
+    now=datetime.datetime(2025, 1, 1),
+):
+    builder = _cert_builder(subject, key, issuer, issuer_key, ca=ca, now=now)
+    if not ca:
+        builder = builder.add_extension(_SAN, critical=False).add_extension(
+            x509.ExtendedKeyUsage([ExtendedKeyUsageOID.SERVER_AUTH]),
+            critical=False,
+        )
+    return builder.sign(issuer_key, hashes.SHA256())
+
+
 class TestPolicyBuilder:
     def test_time_already_set(self):
         with pytest.raises(ValueError):
@@ -51,6 +176,25 @@ class TestPolicyBuilder:
         with pytest.raises(ValueError):
             PolicyBuilder().max_chain_depth(8).max_chain_depth(9)
 
+    @pytest.mark.parametrize(
+        "option",
+        [
+            "warnings_as_errors",
+            "allow_name_only_issuer_matching",
+            "enforce_eku_chaining",
+            "ignore_trust_anchor_validity",
+            "allow_leaf_any_extended_key_usage",
+            "allow_leaf_non_tls_key_usage",
+            "require_nested_validity",
+            "require_shaken_leaf",
+        ],
+    )
+    def test_boolean_option_idempotent(self, option):
+        # Options without arguments can only be turned on, so setting them
+        # again has no effect rather than being an error.
+        builder = getattr(PolicyBuilder(), option)()
+        assert isinstance(getattr(builder, option)(), PolicyBuilder)
+
     def test_ipaddress_subject(self):
         verifier = (
             PolicyBuilder()
@@ -121,6 +265,86 @@ class TestPolicyBuilder:
         ):
             PolicyBuilder().build_server_verifier(DNSName("cryptography.io"))
 
+    def test_expiry_warning_threshold_already_set(self):
+        with pytest.raises(ValueError):
+            PolicyBuilder().expiry_warning_threshold(
+                datetime.timedelta(days=1)
+            ).expiry_warning_threshold(datetime.timedelta(days=2))
+
+    def test_expiry_warning_threshold_bad_type(self):
+        with pytest.raises(TypeError):
+            PolicyBuilder().expiry_warning_threshold(1)  # type: ignore[arg-type]
+
+    def test_client_subject_types_already_set(self):
+        with pytest.raises(ValueError):
+            PolicyBuilder().client_subject_types(
+                [x509.RFC822Name]
+            ).client_subject_types([x509.DNSName])
+
+    def test_client_subject_types_bad_values(self):
+        with pytest.raises(ValueError):
+            PolicyBuilder().client_subject_types([])
+        with pytest.raises(TypeError):
+            PolicyBuilder().client_subject_types(
+                [x509.SubjectAlternativeName]  # type: ignore[list-item]
+            )
+
+    def test_client_subject_types_server_verifier(self):
+        with pytest.raises(
+            ValueError,
+            match="may only be used with a client verifier",
+        ):
+            PolicyBuilder().store(dummy_store()).client_subject_types(
+                [x509.DNSName]
+            ).build_server_verifier(DNSName("cryptography.io"))
+
+    def test_require_subject_organization_already_set(self):
+        with pytest.raises(ValueError):
+            PolicyBuilder().require_subject_organization(
+                ["PyCA"]
+            ).require_subject_organization(["PyCA"])
+
+    def test_require_subject_organization_bad_values(self):
+        with pytest.raises(ValueError):
+            PolicyBuilder().require_subject_organization([])
+        with pytest.raises(TypeError):
+            PolicyBuilder().require_subject_organization(
+                [1]  # type: ignore[list-item]
+            )
+
+    def test_signature_algorithms_already_set(self):
+        oids = [SignatureAlgorithmOID.ECDSA_WITH_SHA1]
+        with pytest.raises(ValueError):
+            PolicyBuilder().permit_signature_algorithms(
+                oids
+            ).permit_signature_algorithms(oids)
+        with pytest.raises(ValueError):
+            PolicyBuilder().deny_signature_algorithms(
+                oids
+            ).deny_signature_algorithms(oids)
+
+    def test_signature_algorithms_bad_values(self):
+        for method in [
+            PolicyBuilder().permit_signature_algorithms,
+            PolicyBuilder().deny_signature_algorithms,
+        ]:
+            with pytest.raises(ValueError):
+                method([])
+            with pytest.raises(TypeError):
+                method(["1.2.840.10045.4.1"])  # type: ignore[list-item]
+
+    def test_min_signature_strength_already_set(self):
+        with pytest.raises(ValueError):
+            PolicyBuilder().min_signature_strength(
+                hashes.SHA256()
+            ).min_signature_strength(hashes.SHA256())
+
+    def test_min_signature_strength_bad_value(self):
+        with pytest.raises(TypeError):
+            PolicyBuilder().min_signature_strength(
+                "SHA256"  # type: ignore[arg-type]
+            )
+
 
 class TestStore:
     def test_store_rejects_empty_list(self):
@@ -131,6 +355,55 @@ class TestStore:
         with pytest.raises(TypeError):
             Store(["not a cert"])  # type: ignore[list-item]
 
+    def test_store_from_directory(self, tmp_path):
+        pem_root_key = ec.generate_private_key(ec.SECP256R1())
+        der_root_key = ec.generate_private_key(ec.SECP256R1())
+        leaf_key = ec.generate_private_key(ec.SECP256R1())
+        pem_root = _issue(
+            "PEM Root", pem_root_key, "PEM Root", pem_root_key, ca=True
+        )
+        der_root = _issue(
+            "DER Root", der_root_key, "DER Root", der_root_key, ca=True
+        )
+
+        (tmp_path / "pem_root.pem").write_bytes(
+            pem_root.public_bytes(serialization.Encoding.PEM)
+        )
+        (tmp_path / "der_root.der").write_bytes(
+            der_root.public_bytes(serialization.Encoding.DER)
+        )
+        # A c_rehash style link, which must not load the root twice.
+        (tmp_path / "a1b2c3d4.0").symlink_to(tmp_path / "pem_root.pem")
+        (tmp_path / "README").write_text("not a certificate")
+        (tmp_path / "empty.pem").write_bytes(b"")
+        (tmp_path / "bad.pem").write_bytes(
+            b"-----BEGIN CERTIFICATE-----\nAAAA\n-----END CERTIFICATE-----\n"
+        )
+        (tmp_path / "dangling.0").symlink_to(tmp_path / "missing.pem")
+        (tmp_path / "subdir").mkdir()
+
+        for path in [tmp_path, str(tmp_path)]:
+            builder = PolicyBuilder().store(Store.from_directory(path))
+            builder = builder.time(datetime.datetime(2025, 6, 1))
+            verifier = builder.build_server_verifier(
+                DNSName("cryptography.io")
+            )
+            for issuer, root, root_key in [
+                ("PEM Root", pem_root, pem_root_key),
+                ("DER Root", der_root, der_root_key),
+            ]:
+                leaf = _issue("leaf", leaf_key, issuer, root_key, ca=False)
+                assert verifier.verify(leaf, []) == [leaf, root]
+
+    def test_store_from_directory_no_certificates(self, tmp_path):
+        (tmp_path / "README").write_text("not a certificate")
+        with pytest.raises(ValueError):
+            Store.from_directory(tmp_path)
+
+    def test_store_from_directory_missing(self, tmp_path):
+        with pytest.raises(FileNotFoundError):
+            Store.from_directory(tmp_path / "missing")
+
 
 class TestClientVerifier:
     def test_build_client_verifier_missing_store(self):
@@ -187,6 +460,34 @@ class TestClientVerifier:
         assert x509.DNSName("www.cryptography.io") in verified_client.subjects
         assert x509.DNSName("cryptography.io") in verified_client.subjects
         assert len(verified_client.subjects) == 2
+        assert verified_client.trust_anchor == leaf
+
+    def test_trust_anchor(self):
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        other_root_key = ec.generate_private_key(ec.SECP256R1())
+        leaf_key = ec.generate_private_key(ec.SECP256R1())
+
+        root = _issue("Root", root_key, "Root", root_key, ca=True)
+        # Shares the root's subject, but didn't sign the leaf.
+        other_root = _issue(
+            "Root", other_root_key, "Root", other_root_key, ca=True
+        )
+        leaf = (
+            _cert_builder("leaf", leaf_key, "Root", root_key, ca=False)
+            .add_extension(_SAN, critical=False)
+            .add_extension(
+                x509.ExtendedKeyUsage([ExtendedKeyUsageOID.CLIENT_AUTH]),
+                critical=False,
+            )
+            .sign(root_key, hashes.SHA256())
+        )
+
+        builder = PolicyBuilder().store(Store([other_root, root]))
+        builder = builder.time(datetime.datetime(2025, 6, 1))
+        verified_client = builder.build_client_verifier().verify(leaf, [])
+        assert verified_client.chain == [leaf, root]
+        assert verified_client.trust_anchor == root
+        assert verified_client.trust_anchor != other_root
 
     def test_verify_fails_renders_oid(self):
         leaf = _load_cert(
@@ -214,6 +515,174 @@ class TestClientVerifier:
         ):
             verifier.verify(leaf, [])
 
+    def test_client_subject_types(self):
+        leaf = _load_cert(
+            os.path.join("x509", "custom", "san_email_dns_ip_dirname_uri.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        builder = (
+            PolicyBuilder()
+            .store(Store([leaf]))
+            .time(leaf.not_valid_before_utc)
+            .extension_policies(
+                ca_policy=ExtensionPolicy.webpki_defaults_ca(),
+                ee_policy=ExtensionPolicy.permit_all(),
+            )
+        )
+
+        verified_client = (
+            builder.client_subject_types([x509.RFC822Name])
+            .build_client_verifier()
+            .verify(leaf, [])
+        )
+        assert verified_client.subjects == [
+            x509.RFC822Name("user@cryptography.io")
+        ]
+
+        verified_client = (
+            builder.client_subject_types([x509.RFC822Name, x509.DNSName])
+            .build_client_verifier()
+            .verify(leaf, [])
+        )
+        assert verified_client.subjects == [
+            x509.RFC822Name("user@cryptography.io"),
+            x509.DNSName("cryptography.io"),
+        ]
+
+    def test_client_subject_types_rejects_dns_only(self):
+        # expires 2018-11-16 01:15:03 UTC
+        leaf = _load_cert(
+            os.path.join("x509", "cryptography.io.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        builder = (
+            PolicyBuilder()
+            .store(Store([leaf]))
+            .time(
+                datetime.datetime.fromisoformat("2018-11-16T00:00:00+00:00")
+            )
+        )
+        verifier = builder.client_subject_types(
+            [x509.RFC822Name]
+        ).build_client_verifier()
+
+        with pytest.raises(
+            VerificationError,
+            match="no subjectAltName entries of an allowed client subject",
+        ):
+            verifier.verify(leaf, [])
+
+        verified_client = (
+            builder.client_subject_types([x509.DNSName])
+            .build_client_verifier()
+            .verify(leaf, [])
+        )
+        assert verified_client.subjects == [
+            x509.DNSName("www.cryptography.io"),
+            x509.DNSName("cryptography.io"),
+        ]
+
+    def test_require_subject_organization(self):
+        # O=PyCA
+        leaf = _load_cert(
+            os.path.join("x509", "custom", "san_email_dns_ip_dirname_uri.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        builder = (
+            PolicyBuilder()
+            .store(Store([leaf]))
+            .time(leaf.not_valid_before_utc)
+            .extension_policies(
+                ca_policy=ExtensionPolicy.webpki_defaults_ca(),
+                ee_policy=ExtensionPolicy.permit_all(),
+            )
+        )
+
+        verifier = builder.require_subject_organization(
+            ["Other Org", "PyCA"]
+        ).build_client_verifier()
+        assert verifier.verify(leaf, []).chain == [leaf]
+
+        verifier = builder.require_subject_organization(
+            ["Other Org", "pyca"]
+        ).build_client_verifier()
+        with pytest.raises(
+            VerificationError,
+            match="subject organization is not allowed",
+        ):
+            verifier.verify(leaf, [])
+        # The leaf is checked while building the chain.
+        [step] = verifier.trace(leaf, [])
+        assert step.kind == "ee_policy"
+        assert step.error == (
+            "leaf certificate's subject organization is not allowed"
+        )
+
+    def test_require_shaken_leaf(self):
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        leaf_key = ec.generate_private_key(ec.SECP256R1())
+        jwt = x509.ObjectIdentifier("1.3.6.1.5.5.7.3.37")
+        tn_auth_list = x509.UnrecognizedExtension(
+            x509.ObjectIdentifier("1.3.6.1.5.5.7.1.26"),
+            b"\x30\x08\xa0\x06\x16\x041234",
+        )
+
+        root = _issue("Root", root_key, "Root", root_key, ca=True)
+        shaken_leaf = (
+            _cert_builder("SHAKEN 1234", leaf_key, "Root", root_key, ca=False)
+            .add_extension(_SAN, critical=False)
+            .add_extension(x509.ExtendedKeyUsage([jwt]), critical=False)
+            .add_extension(tn_auth_list, critical=False)
+            .sign(root_key, hashes.SHA256())
+        )
+        tls_leaf = _issue("leaf", leaf_key, "Root", root_key, ca=False)
+        any_eku_leaf = (
+            _cert_builder("SHAKEN 1234", leaf_key, "Root", root_key, ca=False)
+            .add_extension(_SAN, critical=False)
+            .add_extension(
+                x509.ExtendedKeyUsage(
+                    [ExtendedKeyUsageOID.ANY_EXTENDED_KEY_USAGE]
+                ),
+                critical=False,
+            )
+            .add_extension(tn_auth_list, critical=False)
+            .sign(root_key, hashes.SHA256())
+        )
+
+        builder = (
+            PolicyBuilder()
+            .store(Store([root]))
+            .time(datetime.datetime(2025, 6, 1))
+            .require_shaken_leaf()
+        )
+        verifier = builder.build_client_verifier()
+        assert verifier.verify(shaken_leaf, []).chain == [shaken_leaf, root]
+        with pytest.raises(VerificationError, match="required EKU not found"):
+            verifier.verify(tls_leaf, [])
+        # anyExtendedKeyUsage doesn't stand in for id-kp-jwt.
+        any_eku_verifier = (
+            builder.allow_leaf_any_extended_key_usage().build_client_verifier()
+        )
+        with pytest.raises(
+            VerificationError, match="does not include id-kp-jwt"
+        ):
+            any_eku_verifier.verify(any_eku_leaf, [])
+
+        leaf_without_tn_auth_list = (
+            _cert_builder("leaf", leaf_key, "Root", root_key, ca=False)
+            .add_extension(_SAN, critical=False)
+            .add_extension(x509.ExtendedKeyUsage([jwt]), critical=False)
+            .sign(root_key, hashes.SHA256())
+        )
+        with pytest.raises(VerificationError, match="has no TNAuthList"):
+            verifier.verify(leaf_without_tn_auth_list, [])
+        [step] = verifier.trace(leaf_without_tn_auth_list, [])
+        assert step.kind == "ee_policy"
+        assert step.error == "leaf certificate has no TNAuthList"
+
+        with pytest.raises(ValueError, match="only be required with a client"):
+            builder.build_server_verifier(DNSName("cryptography.io"))
+
 
 class TestServerVerifier:
     @pytest.mark.parametrize(
@@ -267,6 +736,1790 @@ class TestServerVerifier:
         ):
             verifier.verify(leaf, [])
 
+    def test_additional_anchors(self):
+        leaf = _load_cert(
+            os.path.join("x509", "cryptography.io.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        intermediate = _load_cert(
+            os.path.join("x509", "rapidssl_sha256_ca_g3.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        unrelated = _load_cert(
+            os.path.join("x509", "ecdsa_root.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        builder = PolicyBuilder().store(Store([unrelated]))
+        builder = builder.time(datetime.datetime(2018, 1, 1))
+        verifier = builder.build_server_verifier(DNSName("cryptography.io"))
+
+        with pytest.raises(x509.verification.VerificationError):
+            verifier.verify(leaf, [intermediate])
+
+        chain = verifier.verify(leaf, [], additional_anchors=[intermediate])
+        assert chain == [leaf, intermediate]
+        chain, warnings = verifier.verify_with_warnings(
+            leaf, [], additional_anchors=[intermediate]
+        )
+        assert chain == [leaf, intermediate]
+        assert warnings == []
+
+        # The anchor is only trusted for the call it was supplied to.
+        with pytest.raises(x509.verification.VerificationError):
+            verifier.verify(leaf, [intermediate])
+
+    def test_additional_anchors_empty(self):
+        leaf = _load_cert(
+            os.path.join("x509", "cryptography.io.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        builder = PolicyBuilder().store(Store([leaf]))
+        builder = builder.time(datetime.datetime(2018, 1, 1))
+        verifier = builder.build_server_verifier(DNSName("cryptography.io"))
+
+        assert verifier.verify(leaf, [], additional_anchors=[]) == [leaf]
+
+    def test_cross_signed_intermediate(self):
+        old_root_key = ec.generate_private_key(ec.SECP256R1())
+        new_root_key = ec.generate_private_key(ec.SECP256R1())
+        intermediate_key = ec.generate_private_key(ec.SECP256R1())
+        leaf_key = ec.generate_private_key(ec.SECP256R1())
+
+        old_root = _issue(
+            "Old Root", old_root_key, "Old Root", old_root_key, ca=True
+        )
+        new_root = _issue(
+            "New Root", new_root_key, "New Root", new_root_key, ca=True
+        )
+        # The same intermediate, signed by each root.
+        via_old_root = _issue(
+            "Intermediate", intermediate_key, "Old Root", old_root_key, ca=True
+        )
+        via_new_root = _issue(
+            "Intermediate", intermediate_key, "New Root", new_root_key, ca=True
+        )
+        leaf = _issue(
+            "leaf", leaf_key, "Intermediate", intermediate_key, ca=False
+        )
+
+        # Only the old root is trusted. The leaf chains to both versions of
+        # the intermediate, and the untrusted path is tried first.
+        builder = PolicyBuilder().store(Store([old_root]))
+        builder = builder.time(datetime.datetime(2025, 6, 1))
+        verifier = builder.build_server_verifier(DNSName("cryptography.io"))
+
+        chain = verifier.verify(leaf, [via_new_root, new_root, via_old_root])
+        assert chain == [leaf, via_old_root, old_root]
+
+        # Without the cross-signed intermediate there's no path.
+        with pytest.raises(VerificationError):
+            verifier.verify(leaf, [via_new_root, new_root])
+
+    @pytest.mark.parametrize(
+        "encoding", [serialization.Encoding.DER, serialization.Encoding.PEM]
+    )
+    def test_pkcs7_intermediates(self, encoding):
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        intermediate_key = ec.generate_private_key(ec.SECP256R1())
+        leaf_key = ec.generate_private_key(ec.SECP256R1())
+
+        root = _issue("Root", root_key, "Root", root_key, ca=True)
+        intermediate = _issue(
+            "Intermediate", intermediate_key, "Root", root_key, ca=True
+        )
+        leaf = _issue(
+            "leaf", leaf_key, "Intermediate", intermediate_key, ca=False
+        )
+        p7b = pkcs7.serialize_certificates([intermediate], encoding)
+
+        builder = PolicyBuilder().store(Store([root]))
+        builder = builder.time(datetime.datetime(2025, 6, 1))
+        verifier = builder.build_server_verifier(DNSName("cryptography.io"))
+        assert verifier.verify(leaf, p7b) == [leaf, intermediate, root]
+
+        with pytest.raises(ValueError):
+            verifier.verify(leaf, b"not a pkcs7 structure")
+
+    def test_key_identifier_mismatch(self):
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        leaf_key = ec.generate_private_key(ec.SECP256R1())
+        other_key = ec.generate_private_key(ec.SECP256R1())
+
+        root = _issue("Root", root_key, "Root", root_key, ca=True)
+        # Signed by the root, with a matching issuer DN, but an AKI that
+        # identifies some other key.
+        leaf = (
+            _cert_builder("leaf", leaf_key, "Root", other_key, ca=False)
+            .add_extension(_SAN, critical=False)
+            .add_extension(
+                x509.ExtendedKeyUsage([ExtendedKeyUsageOID.SERVER_AUTH]),
+                critical=False,
+            )
+            .sign(root_key, hashes.SHA256())
+        )
+
+        builder = PolicyBuilder().store(Store([root]))
+        builder = builder.time(datetime.datetime(2025, 6, 1))
+
+        verifier = builder.build_server_verifier(DNSName("cryptography.io"))
+        with pytest.raises(
+            VerificationError,
+            match="authorityKeyIdentifier does not match the issuer's "
+            "subjectKeyIdentifier",
+        ):
+            verifier.verify(leaf, [])
+
+        verifier = builder.allow_name_only_issuer_matching()
+        verifier = verifier.build_server_verifier(DNSName("cryptography.io"))
+        assert verifier.verify(leaf, []) == [leaf, root]
+
+    @pytest.mark.parametrize(
+        ("intermediate_eku", "valid"),
+        [
+            (None, True),
+            ([ExtendedKeyUsageOID.SERVER_AUTH], True),
+            ([ExtendedKeyUsageOID.ANY_EXTENDED_KEY_USAGE], True),
+            ([ExtendedKeyUsageOID.CLIENT_AUTH], False),
+        ],
+    )
+    def test_eku_chaining(self, intermediate_eku, valid):
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        intermediate_key = ec.generate_private_key(ec.SECP256R1())
+        leaf_key = ec.generate_private_key(ec.SECP256R1())
+
+        root = _issue("Root", root_key, "Root", root_key, ca=True)
+        builder = _cert_builder(
+            "Intermediate", intermediate_key, "Root", root_key, ca=True
+        )
+        if intermediate_eku is not None:
+            builder = builder.add_extension(
+                x509.ExtendedKeyUsage(intermediate_eku), critical=False
+            )
+        intermediate = builder.sign(root_key, hashes.SHA256())
+        leaf = _issue(
+            "leaf", leaf_key, "Intermediate", intermediate_key, ca=False
+        )
+
+        # A CA extension policy that doesn't look at EKUs, so that only
+        # EKU chaining can reject the intermediate.
+        ca_policy = ExtensionPolicy.webpki_defaults_ca().may_be_present(
+            x509.ExtendedKeyUsage, Criticality.AGNOSTIC, None
+        )
+        builder = PolicyBuilder().store(Store([root]))
+        builder = builder.time(datetime.datetime(2025, 6, 1))
+        builder = builder.extension_policies(
+            ca_policy=ca_policy,
+            ee_policy=ExtensionPolicy.webpki_defaults_ee(),
+        )
+
+        verifier = builder.build_server_verifier(DNSName("cryptography.io"))
+        assert verifier.verify(leaf, [intermediate]) == [
+            leaf,
+            intermediate,
+            root,
+        ]
+
+        verifier = builder.enforce_eku_chaining().build_server_verifier(
+            DNSName("cryptography.io")
+        )
+        if valid:
+            assert verifier.verify(leaf, [intermediate]) == [
+                leaf,
+                intermediate,
+                root,
+            ]
+        else:
+            with pytest.raises(
+                VerificationError,
+                match="EKU chaining: CA does not permit the required EKU",
+            ):
+                verifier.verify(leaf, [intermediate])
+
+    def test_ignore_trust_anchor_validity(self):
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        intermediate_key = ec.generate_private_key(ec.SECP256R1())
+        leaf_key = ec.generate_private_key(ec.SECP256R1())
+
+        # The root expired at the start of 2024.
+        expired = datetime.datetime(2023, 1, 1)
+        root = _issue("Root", root_key, "Root", root_key, ca=True, now=expired)
+        intermediate = _issue(
+            "Intermediate", intermediate_key, "Root", root_key, ca=True
+        )
+        expired_intermediate = _issue(
+            "Intermediate",
+            intermediate_key,
+            "Root",
+            root_key,
+            ca=True,
+            now=expired,
+        )
+        leaf = _issue(
+            "leaf", leaf_key, "Intermediate", intermediate_key, ca=False
+        )
+
+        builder = PolicyBuilder().store(Store([root]))
+        builder = builder.time(datetime.datetime(2025, 6, 1))
+
+        verifier = builder.build_server_verifier(DNSName("cryptography.io"))
+        with pytest.raises(
+            VerificationError, match="cert is not valid at validation time"
+        ):
+            verifier.verify(leaf, [intermediate])
+
+        builder = builder.ignore_trust_anchor_validity()
+        verifier = builder.build_server_verifier(DNSName("cryptography.io"))
+        assert verifier.verify(leaf, [intermediate]) == [
+            leaf,
+            intermediate,
+            root,
+        ]
+        # Only the trust anchor's validity is ignored.
+        with pytest.raises(
+            VerificationError, match="cert is not valid at validation time"
+        ):
+            verifier.verify(leaf, [expired_intermediate])
+
+    def test_allow_leaf_any_extended_key_usage(self):
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        intermediate_key = ec.generate_private_key(ec.SECP256R1())
+        leaf_key = ec.generate_private_key(ec.SECP256R1())
+
+        any_eku = [ExtendedKeyUsageOID.ANY_EXTENDED_KEY_USAGE]
+        root = _issue("Root", root_key, "Root", root_key, ca=True)
+        # anyExtendedKeyUsage always satisfies the required EKU in a CA.
+        intermediate = (
+            _cert_builder(
+                "Intermediate", intermediate_key, "Root", root_key, ca=True
+            )
+            .add_extension(x509.ExtendedKeyUsage(any_eku), critical=False)
+            .sign(root_key, hashes.SHA256())
+        )
+        leaf = (
+            _cert_builder(
+                "leaf", leaf_key, "Intermediate", intermediate_key, ca=False
+            )
+            .add_extension(_SAN, critical=False)
+            .add_extension(x509.ExtendedKeyUsage(any_eku), critical=False)
+            .sign(intermediate_key, hashes.SHA256())
+        )
+
+        builder = PolicyBuilder().store(Store([root]))
+        builder = builder.time(datetime.datetime(2025, 6, 1))
+
+        verifier = builder.build_server_verifier(DNSName("cryptography.io"))
+        with pytest.raises(VerificationError, match="required EKU not found"):
+            verifier.verify(leaf, [intermediate])
+
+        builder = builder.allow_leaf_any_extended_key_usage()
+        verifier = builder.build_server_verifier(DNSName("cryptography.io"))
+        assert verifier.verify(leaf, [intermediate]) == [
+            leaf,
+            intermediate,
+            root,
+        ]
+
+        # The option doesn't accept a leaf that lists only other EKUs.
+        leaf = (
+            _cert_builder(
+                "leaf", leaf_key, "Intermediate", intermediate_key, ca=False
+            )
+            .add_extension(_SAN, critical=False)
+            .add_extension(
+                x509.ExtendedKeyUsage([ExtendedKeyUsageOID.CLIENT_AUTH]),
+                critical=False,
+            )
+            .sign(intermediate_key, hashes.SHA256())
+        )
+        with pytest.raises(VerificationError, match="required EKU not found"):
+            verifier.verify(leaf, [intermediate])
+
+    def test_leaf_tls_key_usage(self):
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        leaf_key = ec.generate_private_key(ec.SECP256R1())
+
+        def key_usage(**kwargs):
+            usages = dict.fromkeys(
+                [
+                    "digital_signature",
+                    "content_commitment",
+                    "key_encipherment",
+                    "data_encipherment",
+                    "key_agreement",
+                    "key_cert_sign",
+                    "crl_sign",
+                    "encipher_only",
+                    "decipher_only",
+                ],
+                False,
+            )
+            return x509.KeyUsage(**{**usages, **kwargs})
+
+        root = _issue("Root", root_key, "Root", root_key, ca=True)
+        key_agreement_leaf = (
+            _cert_builder("leaf", leaf_key, "Root", root_key, ca=False)
+            .add_extension(_SAN, critical=False)
+            .add_extension(
+                x509.ExtendedKeyUsage([ExtendedKeyUsageOID.SERVER_AUTH]),
+                critical=False,
+            )
+            .add_extension(key_usage(key_agreement=True), critical=False)
+            .sign(root_key, hashes.SHA256())
+        )
+        signing_leaf = (
+            _cert_builder("leaf", leaf_key, "Root", root_key, ca=False)
+            .add_extension(_SAN, critical=False)
+            .add_extension(
+                x509.ExtendedKeyUsage([ExtendedKeyUsageOID.SERVER_AUTH]),
+                critical=False,
+            )
+            .add_extension(key_usage(digital_signature=True), critical=False)
+            .sign(root_key, hashes.SHA256())
+        )
+
+        builder = PolicyBuilder().store(Store([root]))
+        builder = builder.time(datetime.datetime(2025, 6, 1))
+        verifier = builder.build_server_verifier(DNSName("cryptography.io"))
+        assert verifier.verify(signing_leaf, []) == [signing_leaf, root]
+        with pytest.raises(
+            VerificationError,
+            match="must assert digitalSignature or keyEncipherment",
+        ):
+            verifier.verify(key_agreement_leaf, [])
+
+        # Client verifiers don't check the leaf's key usage for TLS.
+        client_leaf = (
+            _cert_builder("leaf", leaf_key, "Root", root_key, ca=False)
+            .add_extension(_SAN, critical=False)
+            .add_extension(
+                x509.ExtendedKeyUsage([ExtendedKeyUsageOID.CLIENT_AUTH]),
+                critical=False,
+            )
+            .add_extension(key_usage(key_agreement=True), critical=False)
+            .sign(root_key, hashes.SHA256())
+        )
+        client_verifier = builder.build_client_verifier()
+        assert client_verifier.verify(client_leaf, []).chain == [
+            client_leaf,
+            root,
+        ]
+
+        builder = builder.allow_leaf_non_tls_key_usage()
+        verifier = builder.build_server_verifier(DNSName("cryptography.io"))
+        assert verifier.verify(key_agreement_leaf, []) == [
+            key_agreement_leaf,
+            root,
+        ]
+
+        with pytest.raises(ValueError, match="only be allowed with a server"):
+            builder.build_client_verifier()
+
+    def test_require_nested_validity(self):
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        intermediate_key = ec.generate_private_key(ec.SECP256R1())
+        leaf_key = ec.generate_private_key(ec.SECP256R1())
+
+        root = _issue("Root", root_key, "Root", root_key, ca=True)
+        nested = _issue(
+            "Intermediate", intermediate_key, "Root", root_key, ca=True
+        )
+        # Issued a month after the root, so it outlives the root.
+        outliving = _issue(
+            "Intermediate",
+            intermediate_key,
+            "Root",
+            root_key,
+            ca=True,
+            now=datetime.datetime(2025, 2, 1),
+        )
+        leaf = _issue(
+            "leaf",
+            leaf_key,
+            "Intermediate",
+            intermediate_key,
+            ca=False,
+            now=datetime.datetime(2025, 2, 1),
+        )
+
+        builder = PolicyBuilder().store(Store([root]))
+        builder = builder.time(datetime.datetime(2025, 6, 1))
+        verifier = builder.build_server_verifier(DNSName("cryptography.io"))
+        verifier.verify(leaf, [outliving])
+
+        builder = builder.require_nested_validity()
+        verifier = builder.build_server_verifier(DNSName("cryptography.io"))
+        # The leaf outlives the nested intermediate too.
+        with pytest.raises(
+            VerificationError,
+            match="not within its issuer's validity period.*<Certificate",
+        ):
+            verifier.verify(leaf, [nested])
+        with pytest.raises(
+            VerificationError,
+            match="not within its issuer's validity period",
+        ):
+            verifier.verify(leaf, [outliving])
+
+        leaf = _issue(
+            "leaf", leaf_key, "Intermediate", intermediate_key, ca=False
+        )
+        assert verifier.verify(leaf, [nested]) == [
+            leaf,
+            nested,
+            root,
+        ]
+        # A candidate issuer failing the check is passed over for the next.
+        assert verifier.verify(leaf, [outliving, nested]) == [
+            leaf,
+            nested,
+            root,
+        ]
+
+    def test_revocation_checker(self):
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        intermediate_key = ec.generate_private_key(ec.SECP256R1())
+        leaf_key = ec.generate_private_key(ec.SECP256R1())
+
+        root = _issue("Root", root_key, "Root", root_key, ca=True)
+        intermediate = _issue(
+            "Intermediate", intermediate_key, "Root", root_key, ca=True
+        )
+        leaf = _issue(
+            "leaf", leaf_key, "Intermediate", intermediate_key, ca=False
+        )
+
+        calls = []
+
+        def checker(status):
+            def check(cert, issuer):
+                calls.append((cert, issuer))
+                return status if cert == leaf else ocsp.OCSPCertStatus.GOOD
+
+            return check
+
+        builder = (
+            PolicyBuilder()
+            .store(Store([root]))
+            .time(datetime.datetime(2025, 6, 1))
+        )
+
+        verifier = builder.revocation_checker(
+            checker(ocsp.OCSPCertStatus.GOOD)
+        ).build_server_verifier(DNSName("cryptography.io"))
+        assert verifier.verify(leaf, [intermediate]) == [
+            leaf,
+            intermediate,
+            root,
+        ]
+        # The trust anchor isn't checked.
+        assert calls == [(leaf, intermediate), (intermediate, root)]
+
+        verifier = builder.revocation_checker(
+            checker(ocsp.OCSPCertStatus.REVOKED)
+        ).build_server_verifier(DNSName("cryptography.io"))
+        with pytest.raises(
+            VerificationError, match="certificate is revoked.*CN=leaf"
+        ):
+            verifier.verify(leaf, [intermediate])
+
+        verifier = builder.revocation_checker(
+            checker(ocsp.OCSPCertStatus.UNKNOWN)
+        ).build_server_verifier(DNSName("cryptography.io"))
+        verifier.verify(leaf, [intermediate])
+
+        verifier = builder.revocation_checker(
+            checker(ocsp.OCSPCertStatus.UNKNOWN), fail_closed=True
+        ).build_server_verifier(DNSName("cryptography.io"))
+        with pytest.raises(
+            VerificationError, match="revocation status is unknown"
+        ):
+            verifier.verify(leaf, [intermediate])
+
+        verifier = builder.revocation_checker(
+            lambda cert, issuer: None,  # type: ignore[arg-type,return-value]
+        ).build_server_verifier(DNSName("cryptography.io"))
+        with pytest.raises(TypeError, match="must return an OCSPCertStatus"):
+            verifier.verify(leaf, [intermediate])
+
+        def failing_checker(cert, issuer):
+            raise ValueError("no revocation information")
+
+        verifier = builder.revocation_checker(
+            failing_checker
+        ).build_server_verifier(DNSName("cryptography.io"))
+        with pytest.raises(ValueError, match="no revocation information"):
+            verifier.verify(leaf, [intermediate])
+        # The trace is lazy, so the checker is only called on iteration.
+        steps = verifier.trace(leaf, [intermediate])
+        with pytest.raises(ValueError, match="no revocation information"):
+            list(steps)
+
+    def test_revocation_checker_backtracks(self):
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        intermediate_key = ec.generate_private_key(ec.SECP256R1())
+        leaf_key = ec.generate_private_key(ec.SECP256R1())
+
+        root = _issue("Root", root_key, "Root", root_key, ca=True)
+        revoked = _issue(
+            "Intermediate", intermediate_key, "Root", root_key, ca=True
+        )
+        intermediate = _issue(
+            "Intermediate", intermediate_key, "Root", root_key, ca=True
+        )
+        leaf = _issue(
+            "leaf", leaf_key, "Intermediate", intermediate_key, ca=False
+        )
+
+        calls = []
+
+        def checker(cert, issuer):
+            calls.append((cert, issuer))
+            if cert == revoked:
+                return ocsp.OCSPCertStatus.REVOKED
+            return ocsp.OCSPCertStatus.GOOD
+
+        verifier = (
+            PolicyBuilder()
+            .store(Store([root]))
+            .time(datetime.datetime(2025, 6, 1))
+            .revocation_checker(checker)
+            .build_server_verifier(DNSName("cryptography.io"))
+        )
+        # The revoked intermediate is passed over for the other candidate.
+        assert verifier.verify(leaf, [revoked, intermediate]) == [
+            leaf,
+            intermediate,
+            root,
+        ]
+        assert calls == [
+            (leaf, revoked),
+            (revoked, root),
+            (leaf, intermediate),
+            (intermediate, root),
+        ]
+
+        with pytest.raises(
+            VerificationError, match="certificate is revoked.*CN=Intermediate"
+        ):
+            verifier.verify(leaf, [revoked])
+
+    def test_revocation_checker_invalid(self):
+        with pytest.raises(TypeError, match="checker must be callable"):
+            PolicyBuilder().revocation_checker(
+                ocsp.OCSPCertStatus.GOOD  # type: ignore[arg-type]
+            )
+        builder = PolicyBuilder().revocation_checker(
+            lambda cert, issuer: ocsp.OCSPCertStatus.GOOD
+        )
+        with pytest.raises(ValueError, match="may only be set once"):
+            builder.revocation_checker(
+                lambda cert, issuer: ocsp.OCSPCertStatus.GOOD
+            )
+
+    def test_max_dns_name_labels(self):
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        leaf_key = ec.generate_private_key(ec.SECP256R1())
+        root = _issue("Root", root_key, "Root", root_key, ca=True)
+
+        builder = PolicyBuilder().store(Store([root]))
+        builder = builder.time(datetime.datetime(2025, 6, 1))
+        builder = builder.max_dns_name_labels(4)
+        with pytest.raises(ValueError, match="may only be set once"):
+            builder.max_dns_name_labels(4)
+        verifier = builder.build_server_verifier(DNSName("cryptography.io"))
+
+        shallow = (
+            _cert_builder("leaf", leaf_key, "Root", root_key, ca=False)
+            .add_extension(
+                x509.SubjectAlternativeName(
+                    [DNSName("cryptography.io"), DNSName("*.cryptography.io")]
+                ),
+                critical=False,
+            )
+            .add_extension(
+                x509.ExtendedKeyUsage([ExtendedKeyUsageOID.SERVER_AUTH]),
+                critical=False,
+            )
+            .sign(root_key, hashes.SHA256())
+        )
+        assert verifier.verify(shallow, []) == [shallow, root]
+
+        # Only the dNSName matching the subject counts.
+        deep = (
+            _cert_builder("leaf", leaf_key, "Root", root_key, ca=False)
+            .add_extension(
+                x509.SubjectAlternativeName(
+                    [
+                        DNSName("cryptography.io"),
+                        DNSName("a.b.c.d.cryptography.io"),
+                    ]
+                ),
+                critical=False,
+            )
+            .add_extension(
+                x509.ExtendedKeyUsage(
+                    [
+                        ExtendedKeyUsageOID.SERVER_AUTH,
+                        ExtendedKeyUsageOID.CLIENT_AUTH,
+                    ]
+                ),
+                critical=False,
+            )
+            .sign(root_key, hashes.SHA256())
+        )
+        assert verifier.verify(deep, []) == [deep, root]
+        deep_verifier = builder.build_server_verifier(
+            DNSName("a.b.c.d.cryptography.io")
+        )
+        with pytest.raises(
+            VerificationError,
+            match="a.b.c.d.cryptography.io has 6 labels",
+        ):
+            deep_verifier.verify(deep, [])
+
+        wildcard = (
+            _cert_builder("leaf", leaf_key, "Root", root_key, ca=False)
+            .add_extension(
+                x509.SubjectAlternativeName(
+                    [
+                        DNSName("cryptography.io"),
+                        DNSName("*.b.c.cryptography.io"),
+                    ]
+                ),
+                critical=False,
+            )
+            .add_extension(
+                x509.ExtendedKeyUsage([ExtendedKeyUsageOID.SERVER_AUTH]),
+                critical=False,
+            )
+            .sign(root_key, hashes.SHA256())
+        )
+        assert verifier.verify(wildcard, []) == [wildcard, root]
+        wildcard_verifier = builder.build_server_verifier(
+            DNSName("a.b.c.cryptography.io")
+        )
+        with pytest.raises(VerificationError, match="has 5 labels"):
+            wildcard_verifier.verify(wildcard, [])
+
+        with pytest.raises(ValueError, match="all_names=True"):
+            builder.build_client_verifier()
+
+        # With all_names, every dNSName counts.
+        builder = (
+            PolicyBuilder()
+            .store(Store([root]))
+            .time(datetime.datetime(2025, 6, 1))
+            .max_dns_name_labels(4, all_names=True)
+        )
+        verifier = builder.build_server_verifier(DNSName("cryptography.io"))
+        assert verifier.verify(shallow, []) == [shallow, root]
+        with pytest.raises(VerificationError, match="has 6 labels"):
+            verifier.verify(deep, [])
+        client_verifier = builder.build_client_verifier()
+        with pytest.raises(VerificationError, match="has 6 labels"):
+            client_verifier.verify(deep, [])
+
+    def test_max_dns_name_labels_invalid(self):
+        with pytest.raises(ValueError, match="at least 1"):
+            PolicyBuilder().max_dns_name_labels(0)
+
+    def test_verify_with_matched_name(self):
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        leaf_key = ec.generate_private_key(ec.SECP256R1())
+        root = _issue("Root", root_key, "Root", root_key, ca=True)
+        leaf = (
+            _cert_builder("leaf", leaf_key, "Root", root_key, ca=False)
+            .add_extension(
+                x509.SubjectAlternativeName(
+                    [
+                        DNSName("example.com"),
+                        DNSName("*.example.com"),
+                        IPAddress(IPv4Address("127.0.0.1")),
+                    ]
+                ),
+                critical=False,
+            )
+            .add_extension(
+                x509.ExtendedKeyUsage([ExtendedKeyUsageOID.SERVER_AUTH]),
+                critical=False,
+            )
+            .sign(root_key, hashes.SHA256())
+        )
+        builder = PolicyBuilder().store(Store([root]))
+        builder = builder.time(datetime.datetime(2025, 6, 1))
+
+        verifier = builder.build_server_verifier(DNSName("host.example.com"))
+        chain, matched = verifier.verify_with_matched_name(leaf, [])
+        assert chain == [leaf, root]
+        assert matched == DNSName("*.example.com")
+
+        verifier = builder.build_server_verifier(DNSName("example.com"))
+        _, matched = verifier.verify_with_matched_name(leaf, [])
+        assert matched == DNSName("example.com")
+
+        verifier = builder.build_server_verifier(
+            IPAddress(IPv4Address("127.0.0.1"))
+        )
+        _, matched = verifier.verify_with_matched_name(leaf, [])
+        assert matched == IPAddress(IPv4Address("127.0.0.1"))
+
+        verifier = builder.build_server_verifier(DNSName("example.org"))
+        with pytest.raises(VerificationError):
+            verifier.verify_with_matched_name(leaf, [])
+
+    def test_validity_status(self):
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        intermediate_key = ec.generate_private_key(ec.SECP256R1())
+        leaf_key = ec.generate_private_key(ec.SECP256R1())
+
+        root = _issue("Root", root_key, "Root", root_key, ca=True)
+        # Expired at the start of 2024.
+        intermediate = _issue(
+            "Intermediate",
+            intermediate_key,
+            "Root",
+            root_key,
+            ca=True,
+            now=datetime.datetime(2023, 1, 1),
+        )
+        leaf = _issue(
+            "leaf", leaf_key, "Intermediate", intermediate_key, ca=False
+        )
+        future_leaf = _issue(
+            "leaf",
+            leaf_key,
+            "Intermediate",
+            intermediate_key,
+            ca=False,
+            now=datetime.datetime(2026, 1, 1),
+        )
+
+        builder = PolicyBuilder().store(Store([root]))
+        builder = builder.time(datetime.datetime(2025, 6, 1))
+        verifier = builder.build_server_verifier(DNSName("cryptography.io"))
+        with pytest.raises(
+            VerificationError, match="cert is not valid at validation time"
+        ):
+            verifier.verify_with_warnings(leaf, [intermediate])
+
+        statuses = [
+            verifier.policy.validity_status(c)
+            for c in [leaf, intermediate, root]
+        ]
+        assert statuses == [
+            ValidityStatus.VALID,
+            ValidityStatus.EXPIRED,
+            ValidityStatus.VALID,
+        ]
+        assert (
+            verifier.policy.validity_status(future_leaf)
+            == ValidityStatus.NOT_YET_VALID
+        )
+
+        # A trace reports the status of each certificate considered, even
+        # though verification fails.
+        steps = verifier.trace(leaf, [intermediate])
+        assert {s.certificate: s.validity_status for s in steps} == {
+            leaf: ValidityStatus.VALID,
+            intermediate: ValidityStatus.EXPIRED,
+        }
+
+        # Expired at the start of 2024 too, but ignored as a trust anchor.
+        expired_root = _issue(
+            "Root",
+            root_key,
+            "Root",
+            root_key,
+            ca=True,
+            now=datetime.datetime(2023, 1, 1),
+        )
+        client_leaf = (
+            _cert_builder("leaf", leaf_key, "Root", root_key, ca=False)
+            .add_extension(_SAN, critical=False)
+            .add_extension(
+                x509.ExtendedKeyUsage([ExtendedKeyUsageOID.CLIENT_AUTH]),
+                critical=False,
+            )
+            .sign(root_key, hashes.SHA256())
+        )
+        client_verifier = (
+            PolicyBuilder()
+            .store(Store([expired_root]))
+            .time(datetime.datetime(2025, 6, 1))
+            .ignore_trust_anchor_validity()
+            .build_client_verifier()
+        )
+        verified = client_verifier.verify(client_leaf, [])
+        assert verified.chain == [client_leaf, expired_root]
+        assert verified.validity_statuses == [
+            ValidityStatus.VALID,
+            ValidityStatus.EXPIRED,
+        ]
+
+    def test_validity_time_encoding(self):
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        leaf_key = ec.generate_private_key(ec.SECP256R1())
+        root = _issue("Root", root_key, "Root", root_key, ca=True)
+        leaf = _issue("leaf", leaf_key, "Root", root_key, ca=False)
+
+        builder = PolicyBuilder().store(Store([root]))
+        builder = builder.time(datetime.datetime(2025, 6, 1))
+        verifier = builder.build_server_verifier(DNSName("cryptography.io"))
+        # CertificateBuilder encodes dates before 2050 as UTCTime.
+        assert verifier.verify(leaf, []) == [leaf, root]
+
+        # Re-encode the leaf's notBefore as a GeneralizedTime, which DER
+        # doesn't allow for dates before 2050.
+        tbs_fields = der_elements(der_contents(leaf.tbs_certificate_bytes))
+        # version, serialNumber, signature, issuer, validity, ...
+        not_after = der_elements(der_contents(tbs_fields[4]))[1]
+        tbs_fields[4] = der_encode(
+            0x30, der_encode(0x18, b"20250101000000Z") + not_after
+        )
+        tbs = der_encode(0x30, b"".join(tbs_fields))
+        leaf_der = leaf.public_bytes(serialization.Encoding.DER)
+        algorithm = der_elements(der_contents(leaf_der))[1]
+        signature = root_key.sign(tbs, ec.ECDSA(hashes.SHA256()))
+        generalized_leaf = x509.load_der_x509_certificate(
+            der_encode(
+                0x30, tbs + algorithm + der_encode(0x03, b"\x00" + signature)
+            )
+        )
+        assert generalized_leaf.not_valid_before_utc == datetime.datetime(
+            2025, 1, 1, tzinfo=datetime.timezone.utc
+        )
+
+        with pytest.raises(
+            VerificationError,
+            match="validity dates between 1950 and 2049 must be UtcTime",
+        ):
+            verifier.verify(generalized_leaf, [])
+
+    def test_signature_algorithms(self):
+        rsa_root_key = rsa.generate_private_key(65537, 2048)
+        ec_root_key = ec.generate_private_key(ec.SECP256R1())
+        leaf_key = ec.generate_private_key(ec.SECP256R1())
+
+        rsa_root = _issue(
+            "RSA Root", rsa_root_key, "RSA Root", rsa_root_key, ca=True
+        )
+        ec_root = _issue(
+            "EC Root", ec_root_key, "EC Root", ec_root_key, ca=True
+        )
+        rsa_leaf = _resign_with_sha1(
+            _issue("leaf", leaf_key, "RSA Root", rsa_root_key, ca=False),
+            rsa_root_key,
+        )
+        ec_leaf = _resign_with_sha1(
+            _issue("leaf", leaf_key, "EC Root", ec_root_key, ca=False),
+            ec_root_key,
+        )
+        assert (
+            rsa_leaf.signature_algorithm_oid
+            == SignatureAlgorithmOID.RSA_WITH_SHA1
+        )
+        assert (
+            ec_leaf.signature_algorithm_oid
+            == SignatureAlgorithmOID.ECDSA_WITH_SHA1
+        )
+
+        builder = PolicyBuilder().store(Store([rsa_root, ec_root]))
+        builder = builder.time(datetime.datetime(2025, 6, 1))
+
+        verifier = builder.build_server_verifier(DNSName("cryptography.io"))
+        for leaf in [rsa_leaf, ec_leaf]:
+            with pytest.raises(
+                VerificationError, match="Forbidden signature algorithm"
+            ):
+                verifier.verify(leaf, [])
+
+        builder = builder.permit_signature_algorithms(
+            [
+                SignatureAlgorithmOID.RSA_WITH_SHA1,
+                SignatureAlgorithmOID.ECDSA_WITH_SHA1,
+            ]
+        )
+        verifier = builder.build_server_verifier(DNSName("cryptography.io"))
+        assert verifier.verify(rsa_leaf, []) == [rsa_leaf, rsa_root]
+        assert verifier.verify(ec_leaf, []) == [ec_leaf, ec_root]
+
+        builder = builder.deny_signature_algorithms(
+            [SignatureAlgorithmOID.RSA_WITH_SHA1]
+        )
+        verifier = builder.build_server_verifier(DNSName("cryptography.io"))
+        with pytest.raises(
+            VerificationError, match="Denied signature algorithm"
+        ):
+            verifier.verify(rsa_leaf, [])
+        assert verifier.verify(ec_leaf, []) == [ec_leaf, ec_root]
+
+    def test_min_signature_strength(self):
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        intermediate_key = ec.generate_private_key(ec.SECP256R1())
+        leaf_key = ec.generate_private_key(ec.SECP256R1())
+        root = _issue("Root", root_key, "Root", root_key, ca=True)
+        intermediate = _issue(
+            "Intermediate", intermediate_key, "Root", root_key, ca=True
+        )
+        sha1_intermediate = _resign_with_sha1(intermediate, root_key)
+        leaf = _issue(
+            "leaf", leaf_key, "Intermediate", intermediate_key, ca=False
+        )
+
+        builder = (
+            PolicyBuilder()
+            .store(Store([root]))
+            .time(datetime.datetime(2025, 6, 1))
+            .permit_signature_algorithms(
+                [SignatureAlgorithmOID.ECDSA_WITH_SHA1]
+            )
+        )
+        verifier = builder.build_server_verifier(DNSName("cryptography.io"))
+        assert verifier.verify(leaf, [sha1_intermediate]) == [
+            leaf,
+            sha1_intermediate,
+            root,
+        ]
+
+        builder = builder.min_signature_strength(hashes.SHA256())
+        verifier = builder.build_server_verifier(DNSName("cryptography.io"))
+        assert verifier.verify(leaf, [intermediate]) == [
+            leaf,
+            intermediate,
+            root,
+        ]
+        with pytest.raises(
+            VerificationError, match="weaker than the minimum signature"
+        ):
+            verifier.verify(leaf, [sha1_intermediate])
+
+        verifier = (
+            PolicyBuilder()
+            .store(Store([root]))
+            .time(datetime.datetime(2025, 6, 1))
+            .min_signature_strength(hashes.SHA384())
+            .build_server_verifier(DNSName("cryptography.io"))
+        )
+        with pytest.raises(
+            VerificationError, match="weaker than the minimum signature"
+        ):
+            verifier.verify(leaf, [intermediate])
+
+    def test_deny_default_signature_algorithm(self):
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        leaf_key = ec.generate_private_key(ec.SECP256R1())
+        root = _issue("Root", root_key, "Root", root_key, ca=True)
+        leaf = _issue("leaf", leaf_key, "Root", root_key, ca=False)
+
+        builder = PolicyBuilder().store(Store([root]))
+        builder = builder.time(datetime.datetime(2025, 6, 1))
+        builder = builder.deny_signature_algorithms(
+            [SignatureAlgorithmOID.ECDSA_WITH_SHA384]
+        )
+        verifier = builder.build_server_verifier(DNSName("cryptography.io"))
+        assert verifier.verify(leaf, []) == [leaf, root]
+
+        verifier = (
+            PolicyBuilder()
+            .store(Store([root]))
+            .time(datetime.datetime(2025, 6, 1))
+            .deny_signature_algorithms(
+                [SignatureAlgorithmOID.ECDSA_WITH_SHA256]
+            )
+            .build_server_verifier(DNSName("cryptography.io"))
+        )
+        with pytest.raises(
+            VerificationError, match="Denied signature algorithm"
+        ):
+            verifier.verify(leaf, [])
+
+    def test_require_subject_organization_missing(self):
+        # The subject has no O attribute.
+        leaf = _load_cert(
+            os.path.join("x509", "cryptography.io.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        builder = PolicyBuilder().store(Store([leaf]))
+        builder = builder.time(datetime.datetime(2018, 1, 1))
+        verifier = builder.require_subject_organization(
+            ["GeoTrust Inc."]
+        ).build_server_verifier(DNSName("cryptography.io"))
+
+        with pytest.raises(
+            VerificationError,
+            match="leaf certificate has no subject organization",
+        ):
+            verifier.verify(leaf, [])
+        with pytest.raises(
+            VerificationError,
+            match="leaf certificate has no subject organization",
+        ):
+            verifier.verify_with_warnings(leaf, [])
+
+
+class TestVerificationWarnings:
+    # expires 2018-11-16 01:15:03 UTC
+    leaf = _load_cert(
+        os.path.join("x509", "cryptography.io.pem"),
+        x509.load_pem_x509_certificate,
+    )
+    validation_time = datetime.datetime.fromisoformat(
+        "2018-11-16T00:00:00+00:00"
+    )
+
+    def test_expiry_warning(self):
+        builder = (
+            PolicyBuilder()
+            .store(Store([self.leaf]))
+            .time(self.validation_time)
+            .expiry_warning_threshold(datetime.timedelta(days=1))
+        )
+        verifier = builder.build_server_verifier(DNSName("cryptography.io"))
+
+        chain, warnings = verifier.verify_with_warnings(self.leaf, [])
+        assert chain == [self.leaf]
+        assert len(warnings) == 1
+        assert isinstance(warnings[0], VerificationWarning)
+        assert warnings[0].certificate == self.leaf
+        assert "expires within" in warnings[0].message
+
+        client_verifier = builder.build_client_verifier()
+        verified_client, warnings = client_verifier.verify_with_warnings(
+            self.leaf, []
+        )
+        assert verified_client.chain == [self.leaf]
+        assert len(warnings) == 1
+
+    def test_expiry_outside_threshold(self):
+        verifier = (
+            PolicyBuilder()
+            .store(Store([self.leaf]))
+            .time(self.validation_time)
+            .expiry_warning_threshold(datetime.timedelta(minutes=30))
+            .build_server_verifier(DNSName("cryptography.io"))
+        )
+        chain, warnings = verifier.verify_with_warnings(self.leaf, [])
+        assert chain == [self.leaf]
+        assert warnings == []
+
+    def test_no_threshold(self):
+        verifier = (
+            PolicyBuilder()
+            .store(Store([self.leaf]))
+            .time(self.validation_time)
+            .build_server_verifier(DNSName("cryptography.io"))
+        )
+        chain, warnings = verifier.verify_with_warnings(self.leaf, [])
+        assert chain == [self.leaf]
+        assert warnings == []
+
+    def test_warnings_as_errors(self):
+        builder = (
+            PolicyBuilder()
+            .store(Store([self.leaf]))
+            .time(self.validation_time)
+            .expiry_warning_threshold(datetime.timedelta(days=1))
+            .warnings_as_errors()
+        )
+        verifier = builder.build_server_verifier(DNSName("cryptography.io"))
+        with pytest.raises(VerificationError, match="expires within"):
+            verifier.verify(self.leaf, [])
+        with pytest.raises(VerificationError, match="expires within"):
+            verifier.verify_with_warnings(self.leaf, [])
+        with pytest.raises(VerificationError, match="expires within"):
+            builder.build_client_verifier().verify(self.leaf, [])
+
+
+class TestVerificationTrace:
+    def test_trace(self):
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        leaf_key = ec.generate_private_key(ec.SECP256R1())
+        root = _issue("Root", root_key, "Root", root_key, ca=True)
+        leaf = _issue("leaf", leaf_key, "Root", root_key, ca=False)
+
+        verifier = (
+            PolicyBuilder()
+            .store(Store([root]))
+            .time(datetime.datetime(2025, 6, 1))
+            .build_server_verifier(DNSName("cryptography.io"))
+        )
+        steps = verifier.trace(leaf, [])
+        assert iter(steps) is steps
+        steps = list(steps)
+        assert all(isinstance(s, VerificationStep) for s in steps)
+        assert [
+            (s.kind, s.certificate, s.issuer, s.error) for s in steps
+        ] == [
+            ("ee_policy", leaf, None, None),
+            ("candidate", leaf, root, None),
+            ("ca_policy", root, None, None),
+            ("signature", leaf, root, None),
+            ("trust_anchor", root, None, None),
+        ]
+        assert repr(steps[0]).startswith(
+            '<VerificationStep(kind="ee_policy", certificate=<Certificate('
+        )
+
+    def test_trace_failure(self):
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        other_key = ec.generate_private_key(ec.SECP256R1())
+        root = _issue("Root", root_key, "Root", root_key, ca=True)
+        # Claims to be issued by the root, but isn't.
+        leaf = (
+            _cert_builder("leaf", other_key, "Root", root_key, ca=False)
+            .add_extension(_SAN, critical=False)
+            .add_extension(
+                x509.ExtendedKeyUsage([ExtendedKeyUsageOID.CLIENT_AUTH]),
+                critical=False,
+            )
+            .sign(other_key, hashes.SHA256())
+        )
+
+        verifier = (
+            PolicyBuilder()
+            .store(Store([root]))
+            .time(datetime.datetime(2025, 6, 1))
+            .build_client_verifier()
+        )
+        steps = list(verifier.trace(leaf, []))
+        assert [(s.kind, s.error) for s in steps] == [
+            ("ee_policy", None),
+            ("candidate", None),
+            ("ca_policy", None),
+            ("signature", "signature does not match"),
+            (
+                "candidates_exhausted",
+                "candidates exhausted: signature does not match",
+            ),
+        ]
+        assert 'error="signature does not match"' in repr(steps[3])
+
+        expired = datetime.datetime(2030, 1, 1)
+        verifier = (
+            PolicyBuilder()
+            .store(Store([root]))
+            .time(expired)
+            .build_client_verifier()
+        )
+        steps = list(verifier.trace(leaf, []))
+        assert [(s.kind, s.error) for s in steps] == [
+            ("ee_policy", "cert is not valid at validation time"),
+        ]
+
+    def test_trace_is_lazy(self):
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        leaf_key = ec.generate_private_key(ec.SECP256R1())
+        root = _issue("Root", root_key, "Root", root_key, ca=True)
+        leaf = _issue("leaf", leaf_key, "Root", root_key, ca=False)
+
+        checked = []
+
+        def checker(cert, issuer):
+            checked.append(cert)
+            return ocsp.OCSPCertStatus.GOOD
+
+        verifier = (
+            PolicyBuilder()
+            .store(Store([root]))
+            .time(datetime.datetime(2025, 6, 1))
+            .revocation_checker(checker)
+            .build_server_verifier(DNSName("cryptography.io"))
+        )
+        steps = verifier.trace(leaf, [])
+        assert next(steps).kind == "ee_policy"
+        assert checked == []
+        assert [s.kind for s in steps] == [
+            "candidate",
+            "ca_policy",
+            "signature",
+            "trust_anchor",
+        ]
+        assert checked == [leaf]
+        assert list(steps) == []
+
+    def test_trace_client_subject_types(self):
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        leaf_key = ec.generate_private_key(ec.SECP256R1())
+        root = _issue("Root", root_key, "Root", root_key, ca=True)
+        leaf = (
+            _cert_builder("leaf", leaf_key, "Root", root_key, ca=False)
+            .add_extension(_SAN, critical=False)
+            .add_extension(
+                x509.ExtendedKeyUsage([ExtendedKeyUsageOID.CLIENT_AUTH]),
+                critical=False,
+            )
+            .sign(root_key, hashes.SHA256())
+        )
+
+        verifier = (
+            PolicyBuilder()
+            .store(Store([root]))
+            .time(datetime.datetime(2025, 6, 1))
+            .client_subject_types([x509.RFC822Name])
+            .build_client_verifier()
+        )
+        steps = list(verifier.trace(leaf, []))
+        assert [s.kind for s in steps][-2:] == [
+            "trust_anchor",
+            "chain_policy",
+        ]
+        assert steps[-1].certificate == leaf
+        assert steps[-1].issuer is None
+        assert steps[-1].error == (
+            "leaf certificate has no subjectAltName entries of an allowed "
+            "client subject type"
+        )
+
+    def test_trace_warnings_as_errors(self):
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        leaf_key = ec.generate_private_key(ec.SECP256R1())
+        root = _issue("Root", root_key, "Root", root_key, ca=True)
+        leaf = _issue("leaf", leaf_key, "Root", root_key, ca=False)
+
+        builder = (
+            PolicyBuilder()
+            .store(Store([root]))
+            .time(datetime.datetime(2025, 6, 1))
+            .expiry_warning_threshold(datetime.timedelta(days=365))
+        )
+        verifier = builder.build_server_verifier(DNSName("cryptography.io"))
+        steps = list(verifier.trace(leaf, []))
+        assert steps[-1].kind == "trust_anchor"
+
+        verifier = builder.warnings_as_errors().build_server_verifier(
+            DNSName("cryptography.io")
+        )
+        steps = list(verifier.trace(leaf, []))
+        assert [s.kind for s in steps][-2:] == [
+            "trust_anchor",
+            "chain_policy",
+        ]
+        assert steps[-1].certificate == leaf
+        assert steps[-1].error == (
+            "certificate expires within the configured warning threshold"
+        )
+
+
+class TestVerifyTimestampToken:
+    root = _load_cert(
+        os.path.join("x509", "custom", "tsa_root.pem"),
+        x509.load_pem_x509_certificate,
+    )
+    token = load_vectors_from_file(
+        os.path.join("x509", "custom", "tsa_token.der"),
+        lambda f: f.read(),
+        mode="rb",
+    )
+    data = b"timestamp me\n"
+
+    def test_valid(self):
+        gen_time = verify_timestamp_token(
+            self.token, self.data, Store([self.root])
+        )
+        assert gen_time == datetime.datetime(
+            2026, 10, 14, 12, 12, 42, tzinfo=datetime.timezone.utc
+        )
+
+    def test_altered_data(self):
+        with pytest.raises(InvalidSignature):
+            verify_timestamp_token(
+                self.token, self.data + b"!", Store([self.root])
+            )
+
+    def test_untrusted_tsa(self):
+        with pytest.raises(VerificationError):
+            verify_timestamp_token(self.token, self.data, dummy_store())
+
+    def test_invalid_signature(self):
+        token = bytearray(self.token)
+        token[-1] ^= 1
+        with pytest.raises(
+            VerificationError, match="TSA signature is invalid"
+        ):
+            verify_timestamp_token(
+                bytes(token), self.data, Store([self.root])
+            )
+
+    def test_not_a_token(self):
+        with pytest.raises(ValueError):
+            verify_timestamp_token(b"\x00", self.data, Store([self.root]))
+
+    @pytest.mark.parametrize(
+        ("not_valid_before", "valid"),
+        [
+            # The token's genTime is 2026-10-14.
+            (datetime.datetime(2026, 1, 1), True),
+            (datetime.datetime(2025, 1, 1), False),
+            (datetime.datetime(2027, 1, 1), False),
+        ],
+    )
+    def test_within_validity(self, not_valid_before, valid):
+        key = ec.generate_private_key(ec.SECP256R1())
+        signer = _issue(
+            "signer", key, "signer", key, ca=False, now=not_valid_before
+        )
+        store = Store([self.root])
+        if valid:
+            gen_time = verify_timestamp_token_within_validity(
+                self.token, self.data, store, signer
+            )
+            assert gen_time == datetime.datetime(
+                2026, 10, 14, 12, 12, 42, tzinfo=datetime.timezone.utc
+            )
+        else:
+            with pytest.raises(
+                VerificationError,
+                match="timestamp is not within the certificate's validity",
+            ):
+                verify_timestamp_token_within_validity(
+                    self.token, self.data, store, signer
+                )
+
+    def test_within_validity_untrusted_tsa(self):
+        key = ec.generate_private_key(ec.SECP256R1())
+        signer = _issue(
+            "signer",
+            key,
+            "signer",
+            key,
+            ca=False,
+            now=datetime.datetime(2026, 1, 1),
+        )
+        with pytest.raises(VerificationError):
+            verify_timestamp_token_within_validity(
+                self.token, self.data, dummy_store(), signer
+            )
+
+
+class TestMatchingSubjects:
+    @staticmethod
+    def _leaf(san):
+        key = ec.generate_private_key(ec.SECP256R1())
+        name = x509.Name([x509.NameAttribute(NameOID.COMMON_NAME, "leaf")])
+        builder = (
+            x509.CertificateBuilder()
+            .subject_name(name)
+            .issuer_name(name)
+            .public_key(key.public_key())
+            .serial_number(1)
+            .not_valid_before(datetime.datetime(2025, 1, 1))
+            .not_valid_after(datetime.datetime(2026, 1, 1))
+        )
+        if san is not None:
+            builder = builder.add_extension(
+                x509.SubjectAlternativeName(san), critical=False
+            )
+        return builder.sign(key, hashes.SHA256())
+
+    def test_wildcard(self):
+        leaf = self._leaf(
+            [DNSName("*.cryptography.io"), IPAddress(IPv4Address("127.0.0.1"))]
+        )
+        candidates = [
+            DNSName("cryptography.io"),
+            DNSName("www.cryptography.io"),
+            DNSName("a.b.cryptography.io"),
+            DNSName("docs.cryptography.io"),
+        ]
+        assert matching_subjects(leaf, candidates) == [
+            DNSName("www.cryptography.io"),
+            DNSName("docs.cryptography.io"),
+        ]
+
+    def test_ip_address(self):
+        leaf = self._leaf(
+            [DNSName("*.cryptography.io"), IPAddress(IPv4Address("127.0.0.1"))]
+        )
+        candidates = [
+            IPAddress(IPv4Address("127.0.0.2")),
+            DNSName("www.cryptography.io"),
+            IPAddress(IPv4Address("127.0.0.1")),
+        ]
+        assert matching_subjects(leaf, candidates) == [
+            DNSName("www.cryptography.io"),
+            IPAddress(IPv4Address("127.0.0.1")),
+        ]
+        assert matching_subjects(leaf, []) == []
+
+    def test_no_san(self):
+        leaf = self._leaf(None)
+        assert matching_subjects(leaf, [DNSName("cryptography.io")]) == []
+
+    def test_invalid_subjects(self):
+        leaf = self._leaf(None)
+        with pytest.raises(ValueError, match="invalid domain name"):
+            matching_subjects(leaf, [DNSName("cryptography.io"), DNSName("")])
+        email = [x509.RFC822Name("user@cryptography.io")]
+        with pytest.raises(TypeError, match="unsupported subject type"):
+            matching_subjects(leaf, email)  # type: ignore[arg-type]
+
+
+def _log_id(log_key):
+    log_id = hashes.Hash(hashes.SHA256())
+    log_id.update(
+        log_key.public_key().public_bytes(
+            serialization.Encoding.DER,
+            serialization.PublicFormat.SubjectPublicKeyInfo,
+        )
+    )
+    return log_id.finalize()
+
+
+def _sct(log_key, signature=b""):
+    return (
+        b"\x00"
+        + _log_id(log_key)
+        # 2025-01-01T00:00:00Z, in milliseconds
+        + (1735689600000).to_bytes(8, "big")
+        # No extensions
+        + b"\x00\x00"
+        # SHA256, ECDSA
+        + b"\x04\x03"
+        + len(signature).to_bytes(2, "big")
+        + signature
+    )
+
+
+def _sct_extension(scts):
+    scts = b"".join(len(sct).to_bytes(2, "big") + sct for sct in scts)
+    return x509.UnrecognizedExtension(
+        ExtensionOID.PRECERT_SIGNED_CERTIFICATE_TIMESTAMPS,
+        der_encode(0x04, len(scts).to_bytes(2, "big") + scts),
+    )
+
+
+class TestVerifyTLSServer:
+    now = datetime.datetime(2025, 6, 1)
+    ca_key = ec.generate_private_key(ec.SECP256R1())
+    ca = _issue("ca", ca_key, "ca", ca_key, ca=True)
+    log_keys = [ec.generate_private_key(ec.SECP256R1()) for _ in range(2)]
+
+    def _leaf(self, *, must_staple=False):
+        key = ec.generate_private_key(ec.SECP256R1())
+        builder = (
+            _cert_builder("leaf", key, "ca", self.ca_key, ca=False)
+            .add_extension(_SAN, critical=False)
+            .add_extension(
+                x509.ExtendedKeyUsage([ExtendedKeyUsageOID.SERVER_AUTH]),
+                critical=False,
+            )
+        )
+        if must_staple:
+            builder = builder.add_extension(
+                x509.TLSFeature([x509.TLSFeatureType.status_request]),
+                critical=False,
+            )
+
+        def issue(scts):
+            return builder.add_extension(
+                _sct_extension(scts), critical=False
+            ).sign(self.ca_key, hashes.SHA256())
+
+        # Each log signs over the leaf's TBSCertificate without its SCT
+        # list, which doesn't depend on the SCTs themselves.
+        unsigned = issue([_sct(log_key) for log_key in self.log_keys])
+        sct_list = unsigned.extensions.get_extension_for_class(
+            x509.PrecertificateSignedCertificateTimestamps
+        ).value
+        scts = []
+        for log_key, sct in zip(self.log_keys, sct_list):
+            data = unsigned.precertificate_signed_data(self.ca, sct)
+            signature = log_key.sign(data, ec.ECDSA(hashes.SHA256()))
+            scts.append(_sct(log_key, signature))
+        return issue(scts)
+
+    def _ocsp_response(
+        self,
+        leaf,
+        *,
+        cert_status=ocsp.OCSPCertStatus.GOOD,
+        next_update=datetime.datetime(2025, 6, 7),
+        untrusted_responder=False,
+    ):
+        revoked = cert_status == ocsp.OCSPCertStatus.REVOKED
+        responder, responder_key = self.ca, self.ca_key
+        if untrusted_responder:
+            responder_key = ec.generate_private_key(ec.SECP256R1())
+            responder = _issue(
+                "ca", responder_key, "ca", responder_key, ca=True
+            )
+        return (
+            ocsp.OCSPResponseBuilder()
+            .add_response(
+                cert=leaf,
+                issuer=self.ca,
+                algorithm=hashes.SHA1(),
+                cert_status=cert_status,
+                this_update=datetime.datetime(2025, 5, 31),
+                next_update=next_update,
+                revocation_time=self.now if revoked else None,
+                revocation_reason=None,
+            )
+            .responder_id(ocsp.OCSPResponderEncoding.HASH, responder)
+            .sign(responder_key, hashes.SHA256())
+        )
+
+    def _verify(self, leaf, *, subject="cryptography.io", **kwargs):
+        verifier = (
+            PolicyBuilder()
+            .store(Store([self.ca]))
+            .time(self.now)
+            .build_server_verifier(DNSName(subject))
+        )
+        kwargs.setdefault(
+            "ct_log_keys",
+            {_log_id(key): key.public_key() for key in self.log_keys},
+        )
+        return verify_tls_server(verifier, leaf, [], **kwargs)
+
+    def test_valid(self):
+        leaf = self._leaf(must_staple=True)
+        chain = self._verify(
+            leaf,
+            ocsp_response=self._ocsp_response(leaf),
+            minimum_scts=2,
+        )
+        assert chain == [leaf, self.ca]
+
+    def test_chain(self):
+        leaf = self._leaf()
+        with pytest.raises(TLSVerificationError) as exc_info:
+            self._verify(leaf, subject="wrong.cryptography.io")
+        assert exc_info.value.stage == TLSVerificationStage.CHAIN
+        assert isinstance(exc_info.value, VerificationError)
+
+    def test_must_staple(self):
+        leaf = self._leaf(must_staple=True)
+        with pytest.raises(TLSVerificationError, match="Must-Staple") as e:
+            self._verify(leaf)
+        assert e.value.stage == TLSVerificationStage.MUST_STAPLE
+
+        # Without the TLS feature, a stapled response is optional.
+        leaf = self._leaf()
+        assert self._verify(leaf) == [leaf, self.ca]
+
+    @pytest.mark.parametrize(
+        ("kwargs", "message"),
+        [
+            ({"cert_status": ocsp.OCSPCertStatus.REVOKED}, "revoked"),
+            ({"cert_status": ocsp.OCSPCertStatus.UNKNOWN}, "unknown"),
+            ({"next_update": datetime.datetime(2025, 5, 31)}, "not current"),
+            ({"untrusted_responder": True}, "could not be verified"),
+        ],
+    )
+    def test_ocsp(self, kwargs, message):
+        leaf = self._leaf(must_staple=True)
+        with pytest.raises(TLSVerificationError, match=message) as e:
+            self._verify(
+                leaf, ocsp_response=self._ocsp_response(leaf, **kwargs)
+            )
+        assert e.value.stage == TLSVerificationStage.OCSP
+
+    def test_ocsp_for_other_certificate(self):
+        leaf = self._leaf()
+        response = self._ocsp_response(self._leaf())
+        with pytest.raises(TLSVerificationError) as e:
+            self._verify(leaf, ocsp_response=response)
+        assert e.value.stage == TLSVerificationStage.OCSP
+
+    def test_sct_minimum(self):
+        leaf = self._leaf()
+        assert self._verify(leaf, minimum_scts=2) == [leaf, self.ca]
+        with pytest.raises(
+            TLSVerificationError, match=r"from 2 log\(s\), but 3"
+        ) as e:
+            self._verify(leaf, minimum_scts=3)
+        assert e.value.stage == TLSVerificationStage.SCT
+
+    def test_sct_unknown_log(self):
+        leaf = self._leaf()
+        log_keys = {_log_id(self.log_keys[0]): self.log_keys[0].public_key()}
+        self._verify(leaf, ct_log_keys=log_keys, minimum_scts=1)
+        with pytest.raises(TLSVerificationError) as e:
+            self._verify(leaf, ct_log_keys=log_keys, minimum_scts=2)
+        assert e.value.stage == TLSVerificationStage.SCT
+        with pytest.raises(TLSVerificationError) as e:
+            self._verify(leaf, ct_log_keys=None, minimum_scts=1)
+        assert e.value.stage == TLSVerificationStage.SCT
+
+    def test_sct_wrong_log_key(self):
+        leaf = self._leaf()
+        log_keys = {_log_id(self.log_keys[0]): self.log_keys[1].public_key()}
+        with pytest.raises(TLSVerificationError) as e:
+            self._verify(leaf, ct_log_keys=log_keys, minimum_scts=1)
+        assert e.value.stage == TLSVerificationStage.SCT
+
+
+class TestVerifyOCSPResponse:
+    # The responder's certificate is checked at the time the response was
+    # produced, which is when it's signed.
+    now = datetime.datetime.now(datetime.timezone.utc).replace(tzinfo=None)
+    root_key = ec.generate_private_key(ec.SECP256R1())
+    root = _issue("root", root_key, "root", root_key, ca=True, now=now)
+    ca_key = ec.generate_private_key(ec.SECP256R1())
+    ca = _issue("ca", ca_key, "root", root_key, ca=True, now=now)
+    leaf = _issue(
+        "leaf",
+        ec.generate_private_key(ec.SECP256R1()),
+        "ca",
+        ca_key,
+        ca=False,
+        now=now,
+    )
+
+    def _responder(
+        self, eku=(ExtendedKeyUsageOID.OCSP_SIGNING,), issuer="ca"
+    ):
+        key = ec.generate_private_key(ec.SECP256R1())
+        issuer_key = self.ca_key if issuer == "ca" else self.root_key
+        cert = (
+            _cert_builder(
+                "responder", key, issuer, issuer_key, ca=False, now=self.now
+            )
+            .add_extension(_SAN, critical=False)
+            .add_extension(x509.ExtendedKeyUsage(list(eku)), critical=False)
+            .sign(issuer_key, hashes.SHA256())
+        )
+        return cert, key
+
+    def _response(
+        self,
+        responder,
+        responder_key,
+        *,
+        certs=None,
+        encoding=ocsp.OCSPResponderEncoding.HASH,
+    ):
+        builder = (
+            ocsp.OCSPResponseBuilder()
+            .add_response(
+                cert=self.leaf,
+                issuer=self.ca,
+                algorithm=hashes.SHA1(),
+                cert_status=ocsp.OCSPCertStatus.GOOD,
+                this_update=self.now,
+                next_update=None,
+                revocation_time=None,
+                revocation_reason=None,
+            )
+            .responder_id(encoding, responder)
+        )
+        if certs is None:
+            certs = [] if responder is self.ca else [responder]
+        if certs:
+            builder = builder.certificates(certs)
+        return builder.sign(responder_key, hashes.SHA256())
+
+    def test_delegated_responder(self):
+        responder, responder_key = self._responder()
+        response = self._response(responder, responder_key)
+        single_resp = verify_ocsp_response(
+            response, self.leaf, self.ca, Store([self.root])
+        )
+        assert single_resp.serial_number == self.leaf.serial_number
+        assert single_resp.certificate_status == ocsp.OCSPCertStatus.GOOD
+
+    def test_issuer_responder(self):
+        response = self._response(self.ca, self.ca_key)
+        single_resp = verify_ocsp_response(
+            response, self.leaf, self.ca, Store([self.root])
+        )
+        assert single_resp.serial_number == self.leaf.serial_number
+
+    @pytest.mark.parametrize("delegated", [True, False])
+    def test_eku_restricted_issuer(self, delegated):
+        # The issuer is restricted to TLS EKUs, as CABF requires of TLS
+        # intermediates. Only a delegated responder needs OCSPSigning, so
+        # this doesn't stop the issuer from authorizing one.
+        issuer = (
+            _cert_builder(
+                "ca", self.ca_key, "root", self.root_key, ca=True, now=self.now
+            )
+            .add_extension(
+                x509.ExtendedKeyUsage(
+                    [
+                        ExtendedKeyUsageOID.SERVER_AUTH,
+                        ExtendedKeyUsageOID.CLIENT_AUTH,
+                    ]
+                ),
+                critical=False,
+            )
+            .sign(self.root_key, hashes.SHA256())
+        )
+        if delegated:
+            response = self._response(*self._responder())
+        else:
+            response = self._response(self.ca, self.ca_key)
+        single_resp = verify_ocsp_response(
+            response, self.leaf, issuer, Store([self.root])
+        )
+        assert single_resp.serial_number == self.leaf.serial_number
+
+    def test_untrusted_root(self):
+        other_key = ec.generate_private_key(ec.SECP256R1())
+        other_root = _issue("root", other_key, "root", other_key, ca=True)
+        responder, responder_key = self._responder()
+        response = self._response(responder, responder_key)
+        with pytest.raises(OCSPVerificationError) as e:
+            verify_ocsp_response(
+                response, self.leaf, self.ca, Store([other_root])
+            )
+        assert e.value.stage == OCSPVerificationStage.CHAIN
+        assert isinstance(e.value, VerificationError)
+
+    def test_responder_not_issued_by_issuer(self):
+        responder, responder_key = self._responder(issuer="root")
+        response = self._response(responder, responder_key)
+        with pytest.raises(OCSPVerificationError, match="not issued") as e:
+            verify_ocsp_response(
+                response, self.leaf, self.ca, Store([self.root])
+            )
+        assert e.value.stage == OCSPVerificationStage.CHAIN
+
+    def test_eku(self):
+        responder, responder_key = self._responder(
+            eku=[ExtendedKeyUsageOID.SERVER_AUTH]
+        )
+        response = self._response(responder, responder_key)
+        with pytest.raises(OCSPVerificationError) as e:
+            verify_ocsp_response(
+                response, self.leaf, self.ca, Store([self.root])
+            )
+        assert e.value.stage == OCSPVerificationStage.EKU
+
+    def test_signature(self):
+        # The response names the included responder, but is signed by a
+        # different key.
+        responder, _ = self._responder()
+        signer, signer_key = self._responder()
+        response = self._response(
+            signer,
+            signer_key,
+            certs=[responder],
+            encoding=ocsp.OCSPResponderEncoding.NAME,
+        )
+        with pytest.raises(OCSPVerificationError, match="invalid") as e:
+            verify_ocsp_response(
+                response, self.leaf, self.ca, Store([self.root])
+            )
+        assert e.value.stage == OCSPVerificationStage.SIGNATURE
+
+    def test_responder_not_included(self):
+        responder, responder_key = self._responder()
+        response = self._response(responder, responder_key, certs=[])
+        with pytest.raises(OCSPVerificationError) as e:
+            verify_ocsp_response(
+                response, self.leaf, self.ca, Store([self.root])
+            )
+        assert e.value.stage == OCSPVerificationStage.SIGNATURE
+
+    def test_other_certificate(self):
+        response = self._response(self.ca, self.ca_key)
+        with pytest.raises(ValueError, match="No SingleResponse"):
+            verify_ocsp_response(
+                response, self.ca, self.root, Store([self.root])
+            )
+
 
 SUPPORTED_EXTENSION_TYPES = (
     x509.AuthorityInformationAccess,